@@ -0,0 +1,44 @@
+//! Verifying a full Sum6 epoch with `EpochVerifier` versus independent `verify_kes` calls
+
+use cardano_crypto::kes::{EpochVerifier, KesAlgorithm, Period, Sum6Kes};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn epoch_verify(c: &mut Criterion) {
+    let mut sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[42u8; 32]).unwrap();
+    let vk = Sum6Kes::derive_verification_key(&sk).unwrap();
+    let mut signatures = Vec::new();
+    for period in 0..Sum6Kes::total_periods() {
+        signatures.push(Sum6Kes::sign_kes(&(), period, b"block header", &sk).unwrap());
+        if let Some(next) = Sum6Kes::update_kes(&(), sk, period).unwrap() {
+            sk = next;
+        } else {
+            break;
+        }
+    }
+    let entries: Vec<(Period, &[u8], _)> = signatures
+        .iter()
+        .enumerate()
+        .map(|(period, sig)| (period as Period, &b"block header"[..], sig))
+        .collect();
+
+    let mut group = c.benchmark_group("sum6_verify_64_periods");
+    group.bench_function("verify_kes_loop", |b| {
+        b.iter(|| {
+            for &(period, message, sig) in &entries {
+                Sum6Kes::verify_kes(&(), &vk, period, black_box(message), sig).unwrap();
+            }
+        })
+    });
+    group.bench_function("epoch_verifier", |b| {
+        b.iter(|| {
+            let mut verifier = EpochVerifier::<Sum6Kes>::new(&(), &vk);
+            verifier
+                .verify_sequence(entries.iter().map(|&(p, m, s)| (p, black_box(m), s)))
+                .unwrap();
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, epoch_verify);
+criterion_main!(benches);
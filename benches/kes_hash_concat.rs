@@ -0,0 +1,85 @@
+//! Sum6 verification with incremental `hash_concat` versus concat-then-hash
+//!
+//! `NaiveBlake2b256` only implements `hash`, so it inherits the default
+//! `hash_concat` that copies both halves into a fresh buffer first. The
+//! allocation count per `verify_kes` is printed before timing.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cardano_crypto::kes::{KesAlgorithm, KesHashAlgorithm, Sum6Kes, Sum6KesWith};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+#[derive(Clone)]
+struct NaiveBlake2b256;
+
+impl KesHashAlgorithm for NaiveBlake2b256 {
+    const OUTPUT_SIZE: usize = 32;
+    const ALGORITHM_NAME: &'static str = "Blake2b-256 (concat then hash)";
+
+    fn hash(data: &[u8]) -> Vec<u8> {
+        cardano_crypto::kes::Blake2b256::hash(data)
+    }
+}
+
+type NaiveSum6Kes = Sum6KesWith<NaiveBlake2b256>;
+
+fn allocations_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn verify(c: &mut Criterion) {
+    let seed = [42u8; 32];
+    let message = b"block header body";
+
+    let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+    let vk = Sum6Kes::derive_verification_key(&sk).unwrap();
+    let sig = Sum6Kes::sign_kes(&(), 0, message, &sk).unwrap();
+
+    let naive_sk = NaiveSum6Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+    let naive_vk = NaiveSum6Kes::derive_verification_key(&naive_sk).unwrap();
+    let naive_sig = NaiveSum6Kes::sign_kes(&(), 0, message, &naive_sk).unwrap();
+    assert_eq!(vk, naive_vk);
+
+    let incremental = allocations_during(|| {
+        Sum6Kes::verify_kes(&(), &vk, 0, message, &sig).unwrap();
+    });
+    let naive = allocations_during(|| {
+        NaiveSum6Kes::verify_kes(&(), &naive_vk, 0, message, &naive_sig).unwrap();
+    });
+    println!("sum6 verify_kes allocations: concat_then_hash={naive} incremental={incremental}");
+
+    let mut group = c.benchmark_group("sum6_verify_kes");
+    group.bench_function("concat_then_hash", |b| {
+        b.iter(|| {
+            NaiveSum6Kes::verify_kes(&(), &naive_vk, 0, black_box(message), &naive_sig).unwrap()
+        })
+    });
+    group.bench_function("incremental", |b| {
+        b.iter(|| Sum6Kes::verify_kes(&(), &vk, 0, black_box(message), &sig).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, verify);
+criterion_main!(benches);
@@ -0,0 +1,24 @@
+//! Sum6 key generation with and without a separate `derive_verification_key`
+
+use cardano_crypto::kes::{KesAlgorithm, Sum6Kes};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn keygen(c: &mut Criterion) {
+    let seed = [42u8; 32];
+
+    let mut group = c.benchmark_group("sum6_keygen");
+    group.bench_function("gen_then_derive", |b| {
+        b.iter(|| {
+            let sk = Sum6Kes::gen_key_kes_from_seed_bytes(black_box(&seed)).unwrap();
+            let vk = Sum6Kes::derive_verification_key(&sk).unwrap();
+            black_box((sk, vk))
+        })
+    });
+    group.bench_function("gen_key_and_vk", |b| {
+        b.iter(|| black_box(Sum6Kes::gen_key_and_vk_kes_from_seed_bytes(black_box(&seed)).unwrap()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, keygen);
+criterion_main!(benches);
@@ -0,0 +1,124 @@
+//! Key generation, one `update_kes` step, `sign_kes` and `verify_kes` for
+//! Sum0–Sum7 and CompactSum0–CompactSum7
+//!
+//! Each benchmark reports one operation per iteration, so criterion's
+//! throughput line reads as operations per second. Criterion does not count
+//! allocations, so a counting allocator measures one run of each operation
+//! and prints the counts before the timings.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cardano_crypto::kes::{
+    CompactSum0Kes, CompactSum1Kes, CompactSum2Kes, CompactSum3Kes, CompactSum4Kes, CompactSum5Kes,
+    CompactSum6Kes, CompactSum7Kes, KesAlgorithm, Sum0Kes, Sum1Kes, Sum2Kes, Sum3Kes, Sum4Kes,
+    Sum5Kes, Sum6Kes, Sum7Kes,
+};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const SEED: [u8; 32] = [42u8; 32];
+const MESSAGE: &[u8] = b"block header body hash";
+
+/// Allocations made by one call of `f`, including those of its result
+fn allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let result = f();
+    let count = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    drop(black_box(result));
+    count
+}
+
+fn report_allocations<K: KesAlgorithm<Context = ()>>(name: &str) {
+    let keygen = allocations(|| K::gen_key_kes_from_seed_bytes(&SEED).unwrap());
+    let sk = K::gen_key_kes_from_seed_bytes(&SEED).unwrap();
+    let vk = K::derive_verification_key(&sk).unwrap();
+    let sign = allocations(|| K::sign_kes(&(), 0, MESSAGE, &sk).unwrap());
+    let sig = K::sign_kes(&(), 0, MESSAGE, &sk).unwrap();
+    let verify = allocations(|| K::verify_kes(&(), &vk, 0, MESSAGE, &sig).unwrap());
+    let update = allocations(|| K::update_kes(&(), sk, 0).unwrap());
+    println!(
+        "{name:<15} allocations: keygen {keygen:>5}  update {update:>5}  sign {sign:>3}  verify {verify:>3}"
+    );
+}
+
+fn bench_scheme<K: KesAlgorithm<Context = ()>>(c: &mut Criterion, name: &str) {
+    report_allocations::<K>(name);
+
+    let sk = K::gen_key_kes_from_seed_bytes(&SEED).unwrap();
+    let vk = K::derive_verification_key(&sk).unwrap();
+    let sig = K::sign_kes(&(), 0, MESSAGE, &sk).unwrap();
+
+    let mut group = c.benchmark_group(name);
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("gen_key_kes", |b| {
+        b.iter(|| black_box(K::gen_key_kes_from_seed_bytes(black_box(&SEED)).unwrap()))
+    });
+    // Period 0 to 1; schemes with a single period expire instead
+    group.bench_function("update_kes", |b| {
+        b.iter_batched(
+            || K::gen_key_kes_from_seed_bytes(&SEED).unwrap(),
+            |sk| black_box(K::update_kes(&(), sk, 0).unwrap()),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("sign_kes", |b| {
+        b.iter(|| black_box(K::sign_kes(&(), 0, black_box(MESSAGE), &sk).unwrap()))
+    });
+    group.bench_function("verify_kes", |b| {
+        b.iter(|| K::verify_kes(&(), &vk, 0, black_box(MESSAGE), &sig).unwrap())
+    });
+    group.finish();
+}
+
+fn sum(c: &mut Criterion) {
+    bench_scheme::<Sum0Kes>(c, "sum0");
+    bench_scheme::<Sum1Kes>(c, "sum1");
+    bench_scheme::<Sum2Kes>(c, "sum2");
+    bench_scheme::<Sum3Kes>(c, "sum3");
+    bench_scheme::<Sum4Kes>(c, "sum4");
+    bench_scheme::<Sum5Kes>(c, "sum5");
+    bench_scheme::<Sum6Kes>(c, "sum6");
+    bench_scheme::<Sum7Kes>(c, "sum7");
+}
+
+fn compact_sum(c: &mut Criterion) {
+    bench_scheme::<CompactSum0Kes>(c, "compact_sum0");
+    bench_scheme::<CompactSum1Kes>(c, "compact_sum1");
+    bench_scheme::<CompactSum2Kes>(c, "compact_sum2");
+    bench_scheme::<CompactSum3Kes>(c, "compact_sum3");
+    bench_scheme::<CompactSum4Kes>(c, "compact_sum4");
+    bench_scheme::<CompactSum5Kes>(c, "compact_sum5");
+    bench_scheme::<CompactSum6Kes>(c, "compact_sum6");
+    bench_scheme::<CompactSum7Kes>(c, "compact_sum7");
+}
+
+criterion_group!(benches, sum, compact_sum);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+//! Batch signing at a single period versus independent `sign_kes` calls
+
+use cardano_crypto::kes::{KesAlgorithm, Sum6Kes};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const BATCH: usize = 16;
+
+fn sign_batch(c: &mut Criterion) {
+    let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[42u8; 32]).unwrap();
+    let payloads: Vec<Vec<u8>> = (0..BATCH)
+        .map(|i| format!("message-{i}").into_bytes())
+        .collect();
+    let messages: Vec<&[u8]> = payloads.iter().map(Vec::as_slice).collect();
+
+    let mut group = c.benchmark_group("sum6_sign_16_messages");
+    group.bench_function("sign_kes_loop", |b| {
+        b.iter(|| {
+            for message in &messages {
+                black_box(Sum6Kes::sign_kes(&(), 0, message, &sk).unwrap());
+            }
+        })
+    });
+    group.bench_function("sign_batch_at_period", |b| {
+        b.iter(|| black_box(Sum6Kes::sign_batch_at_period(&(), 0, &messages, &sk).unwrap()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, sign_batch);
+criterion_main!(benches);
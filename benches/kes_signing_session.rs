@@ -0,0 +1,36 @@
+//! 1000 signatures at one period through a session versus `sign_kes`
+
+use cardano_crypto::kes::{KesAlgorithm, KesSigningSession, Sum6Kes};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const SIGNATURES: usize = 1000;
+
+fn same_period_signs(c: &mut Criterion) {
+    let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[42u8; 32]).unwrap();
+    let sk = Sum6Kes::update_kes_to(&(), sk, 0, 37).unwrap().unwrap();
+    let messages: Vec<Vec<u8>> = (0..SIGNATURES)
+        .map(|i| format!("tx-{i}").into_bytes())
+        .collect();
+
+    let mut group = c.benchmark_group("sum6_1000_signs_same_period");
+    group.bench_function("sign_kes", |b| {
+        b.iter(|| {
+            for message in &messages {
+                black_box(Sum6Kes::sign_kes(&(), 37, message, &sk).unwrap());
+            }
+        })
+    });
+
+    let session = KesSigningSession::<Sum6Kes>::new(&(), sk).unwrap();
+    group.bench_function("session", |b| {
+        b.iter(|| {
+            for message in &messages {
+                black_box(session.sign(message));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, same_period_signs);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+//! Draft-13 `verify_each` versus a loop of `verify` calls
+
+use cardano_crypto::vrf::VrfDraft13;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const BATCH: usize = 64;
+
+fn verify_each(c: &mut Criterion) {
+    let (sk, pk) = VrfDraft13::keypair_from_seed(&[42u8; 32]);
+    let messages: Vec<Vec<u8>> = (0..BATCH)
+        .map(|i| format!("slot-{i}").into_bytes())
+        .collect();
+    let proofs: Vec<_> = messages
+        .iter()
+        .map(|message| VrfDraft13::prove(&sk, message).unwrap())
+        .collect();
+    let items: Vec<_> = messages
+        .iter()
+        .zip(&proofs)
+        .map(|(message, proof)| (&pk, proof, message.as_slice()))
+        .collect();
+
+    let mut group = c.benchmark_group("draft13_verify_64_proofs");
+    group.bench_function("verify_loop", |b| {
+        b.iter(|| {
+            for (pk, proof, message) in &items {
+                black_box(VrfDraft13::verify(pk, proof, message).unwrap());
+            }
+        })
+    });
+    group.bench_function("verify_each", |b| {
+        b.iter(|| black_box(VrfDraft13::verify_each(&items).unwrap()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, verify_each);
+criterion_main!(benches);
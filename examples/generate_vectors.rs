@@ -0,0 +1,140 @@
+//! KES Test Vector Generator
+//!
+//! Prints the verification key, and for every period the evolved signing key
+//! and a signature, for one scheme and seed. The output is meant to be diffed
+//! against vectors produced by cardano-base for the same inputs.
+//!
+//! ```text
+//! cargo run --example generate_vectors -- <scheme> <seed-hex> [message-hex]
+//! ```
+//!
+//! `<scheme>` is `Sum0`..`Sum7` or `CompactSum0`..`CompactSum7` (Blake2b-256
+//! over Ed25519, as in cardano-node). `<seed-hex>` is the 32-byte seed and
+//! `[message-hex]` the message signed at every period, empty if omitted.
+//!
+//! # Output schema
+//!
+//! One JSON object on stdout; object keys may appear in any order. All byte
+//! strings are lowercase hex without a `0x` prefix, in the raw serialization
+//! of the scheme:
+//!
+//! ```text
+//! {
+//!   "scheme": string,            // the <scheme> argument, e.g. "Sum6"
+//!   "algorithm": string,         // KesAlgorithm::ALGORITHM_NAME
+//!   "seed": hex,                 // the seed argument
+//!   "message": hex,              // the message argument
+//!   "total_periods": integer,    // KesAlgorithm::total_periods()
+//!   "verification_key": hex,     // raw_serialize_verification_key_kes
+//!   "periods": [                 // one entry per period, in order from 0
+//!     {
+//!       "period": integer,
+//!       "signing_key": hex,      // raw_serialize_signing_key_kes at this period
+//!       "signature": hex         // raw_serialize_signature_kes of sign_kes(period, message)
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! The signing key at period `t` is the key from `<seed-hex>` evolved with
+//! `update_kes` once per period from 0. Signing-key bytes are secret material;
+//! only generate vectors from throwaway seeds.
+
+use std::process::ExitCode;
+
+use cardano_crypto::kes::{
+    CompactSum0Kes, CompactSum1Kes, CompactSum2Kes, CompactSum3Kes, CompactSum4Kes, CompactSum5Kes,
+    CompactSum6Kes, CompactSum7Kes, KesScheme, Sum0Kes, Sum1Kes, Sum2Kes, Sum3Kes, Sum4Kes,
+    Sum5Kes, Sum6Kes, Sum7Kes, UnsoundKesAlgorithm,
+};
+use serde_json::{json, Value};
+
+const USAGE: &str =
+    "usage: generate_vectors <Sum0..Sum7|CompactSum0..CompactSum7> <seed-hex> [message-hex]";
+
+/// Parse `SumN` or `CompactSumN`
+fn parse_scheme(name: &str) -> Option<KesScheme> {
+    let (compact, depth) = match name.strip_prefix("CompactSum") {
+        Some(depth) => (true, depth),
+        None => (false, name.strip_prefix("Sum")?),
+    };
+    KesScheme::from_depth(depth.parse().ok()?, compact)
+}
+
+/// Vectors for `K` as a JSON value following the schema in the module docs
+fn vectors<K>(scheme: &str, seed: &[u8], message: &[u8]) -> Result<Value, String>
+where
+    K: UnsoundKesAlgorithm<Context = ()>,
+{
+    let mut sk = K::gen_key_kes_from_seed_bytes(seed).map_err(|err| err.to_string())?;
+    let vk = K::derive_verification_key(&sk).map_err(|err| err.to_string())?;
+
+    let mut periods = Vec::new();
+    for period in 0..K::total_periods() {
+        let signature = K::sign_kes(&(), period, message, &sk).map_err(|err| err.to_string())?;
+        periods.push(json!({
+            "period": period,
+            "signing_key": hex::encode(&*K::raw_serialize_signing_key_kes(&sk)),
+            "signature": hex::encode(K::raw_serialize_signature_kes(&signature)),
+        }));
+        match K::update_kes(&(), sk, period).map_err(|err| err.to_string())? {
+            Some(next) => sk = next,
+            None => break,
+        }
+    }
+
+    Ok(json!({
+        "scheme": scheme,
+        "algorithm": K::ALGORITHM_NAME,
+        "seed": hex::encode(seed),
+        "message": hex::encode(message),
+        "total_periods": K::total_periods(),
+        "verification_key": hex::encode(K::raw_serialize_verification_key_kes(&vk)),
+        "periods": periods,
+    }))
+}
+
+fn run(args: &[String]) -> Result<Value, String> {
+    let (name, seed, message) = match args {
+        [name, seed] => (name, seed, ""),
+        [name, seed, message] => (name, seed, message.as_str()),
+        _ => return Err(USAGE.to_string()),
+    };
+    let scheme = parse_scheme(name).ok_or_else(|| format!("unknown scheme {name:?}\n{USAGE}"))?;
+    let seed = hex::decode(seed).map_err(|err| format!("seed: {err}"))?;
+    let message = hex::decode(message).map_err(|err| format!("message: {err}"))?;
+
+    let generate = match scheme {
+        KesScheme::Sum0 => vectors::<Sum0Kes>,
+        KesScheme::Sum1 => vectors::<Sum1Kes>,
+        KesScheme::Sum2 => vectors::<Sum2Kes>,
+        KesScheme::Sum3 => vectors::<Sum3Kes>,
+        KesScheme::Sum4 => vectors::<Sum4Kes>,
+        KesScheme::Sum5 => vectors::<Sum5Kes>,
+        KesScheme::Sum6 => vectors::<Sum6Kes>,
+        KesScheme::Sum7 => vectors::<Sum7Kes>,
+        KesScheme::CompactSum0 => vectors::<CompactSum0Kes>,
+        KesScheme::CompactSum1 => vectors::<CompactSum1Kes>,
+        KesScheme::CompactSum2 => vectors::<CompactSum2Kes>,
+        KesScheme::CompactSum3 => vectors::<CompactSum3Kes>,
+        KesScheme::CompactSum4 => vectors::<CompactSum4Kes>,
+        KesScheme::CompactSum5 => vectors::<CompactSum5Kes>,
+        KesScheme::CompactSum6 => vectors::<CompactSum6Kes>,
+        KesScheme::CompactSum7 => vectors::<CompactSum7Kes>,
+    };
+    generate(name, &seed, &message)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(vectors) => {
+            println!("{vectors:#}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
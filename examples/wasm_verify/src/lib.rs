@@ -0,0 +1,66 @@
+//! In-browser KES Verification
+//!
+//! Exposes Sum6 verification (the scheme cardano-node uses) to JavaScript
+//! through `wasm-bindgen`, for wallets and explorers checking block header
+//! signatures client-side.
+//!
+//! ```text
+//! cd examples/wasm_verify
+//! cargo build --release --target wasm32-unknown-unknown
+//! wasm-bindgen --target web --out-dir pkg \
+//!     target/wasm32-unknown-unknown/release/cardano_crypto_wasm_verify.wasm
+//! ```
+//!
+//! ```js
+//! import init, { verify_sum6 } from "./pkg/cardano_crypto_wasm_verify.js";
+//!
+//! await init();
+//! const ok = verify_sum6(vkHex, signatureHex, period, new TextEncoder().encode("header"));
+//! ```
+//!
+//! Verification needs neither `std` nor randomness, so this crate depends on
+//! `cardano-crypto` with `default-features = false, features = ["kes"]`. There is no entropy source on that target without extra
+//! JavaScript glue, so key generation in the browser takes an explicit seed
+//! (see [`sum6_verification_key_from_seed`]); the `rand` based
+//! `gen_key_kes` is not used here.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use cardano_crypto::kes::{KesAlgorithm, Sum6Kes};
+use wasm_bindgen::prelude::*;
+
+/// Verify a hex-encoded Sum6 signature over `message` at `period`
+///
+/// Returns `Ok(false)` when the signature does not verify, and an error when
+/// either hex string does not decode to a key or signature.
+#[wasm_bindgen]
+pub fn verify_sum6(
+    verification_key_hex: &str,
+    signature_hex: &str,
+    period: u32,
+    message: &[u8],
+) -> Result<bool, String> {
+    let vk_bytes =
+        hex::decode(verification_key_hex).map_err(|err| format!("verification key: {err}"))?;
+    let vk = Sum6Kes::verification_key_from_bytes(&vk_bytes).map_err(|err| err.to_string())?;
+    let sig_bytes = hex::decode(signature_hex).map_err(|err| format!("signature: {err}"))?;
+    let signature = Sum6Kes::raw_deserialize_signature_kes(&sig_bytes)
+        .ok_or_else(|| "signature: invalid encoding".to_string())?;
+
+    Ok(Sum6Kes::verify_kes(&(), &vk, period.into(), message, &signature).is_ok())
+}
+
+/// Hex-encoded Sum6 verification key for a hex-encoded 32-byte seed
+#[wasm_bindgen]
+pub fn sum6_verification_key_from_seed(seed_hex: &str) -> Result<String, String> {
+    let seed = hex::decode(seed_hex).map_err(|err| format!("seed: {err}"))?;
+    let vk = Sum6Kes::verification_key_from_seed(&seed).map_err(|err| err.to_string())?;
+    Ok(hex::encode(Sum6Kes::raw_serialize_verification_key_kes(
+        &vk,
+    )))
+}
@@ -31,6 +31,9 @@
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+#[cfg(feature = "kes")]
+use crate::kes::KesAlgorithm;
+
 /// CBOR serialization errors
 ///
 /// # Example
@@ -268,6 +271,65 @@ pub fn decode_signature(cbor: &[u8]) -> Result<Vec<u8>, CborError> {
     decode_bytes(cbor)
 }
 
+/// Encode a KES verification key as cardano-node does in operational certificates
+///
+/// The key's raw serialization is wrapped in a CBOR byte string, so a Sum6Kes
+/// key becomes `0x58 0x20` followed by its 32 bytes.
+///
+/// # Example
+///
+/// ```
+/// use cardano_crypto::cbor::{decode_kes_verification_key, encode_kes_verification_key};
+/// use cardano_crypto::kes::{KesAlgorithm, Sum6Kes};
+///
+/// let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[7u8; 32]).unwrap();
+/// let vk = Sum6Kes::derive_verification_key(&sk).unwrap();
+///
+/// let cbor = encode_kes_verification_key::<Sum6Kes>(&vk);
+/// assert_eq!(&cbor[..2], &[0x58, 0x20]);
+/// assert_eq!(decode_kes_verification_key::<Sum6Kes>(&cbor).unwrap(), vk);
+/// ```
+#[cfg(all(feature = "alloc", feature = "kes"))]
+pub fn encode_kes_verification_key<K: KesAlgorithm>(key: &K::VerificationKey) -> Vec<u8> {
+    encode_verification_key(&K::raw_serialize_verification_key_kes(key))
+}
+
+/// Decode a KES verification key from its CBOR byte-string encoding
+///
+/// Fails with [`CborError::InvalidLength`] if the byte string is not exactly
+/// [`KesAlgorithm::VERIFICATION_KEY_SIZE`] bytes.
+#[cfg(all(feature = "alloc", feature = "kes"))]
+pub fn decode_kes_verification_key<K: KesAlgorithm>(
+    cbor: &[u8],
+) -> Result<K::VerificationKey, CborError> {
+    let raw = decode_verification_key(cbor)?;
+    if raw.len() != K::VERIFICATION_KEY_SIZE {
+        return Err(CborError::InvalidLength);
+    }
+    K::raw_deserialize_verification_key_kes(&raw).ok_or(CborError::DeserializationFailed)
+}
+
+/// Encode a KES signature as a CBOR byte string
+///
+/// This is the encoding used for the KES signature in a block header.
+#[cfg(all(feature = "alloc", feature = "kes"))]
+pub fn encode_kes_signature<K: KesAlgorithm>(signature: &K::Signature) -> Vec<u8> {
+    encode_signature(&K::raw_serialize_signature_kes(signature))
+}
+
+/// Decode a KES signature from its CBOR byte-string encoding
+///
+/// Fails with [`CborError::InvalidLength`] if the byte string is not exactly
+/// [`KesAlgorithm::SIGNATURE_SIZE`] bytes.
+#[cfg(all(feature = "alloc", feature = "kes"))]
+pub fn decode_kes_signature<K: KesAlgorithm>(cbor: &[u8]) -> Result<K::Signature, CborError> {
+    let raw = decode_signature(cbor)?;
+    if raw.len() != K::SIGNATURE_SIZE {
+        return Err(CborError::InvalidLength);
+    }
+    K::raw_deserialize_signature_kes(&raw).ok_or(CborError::DeserializationFailed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -359,4 +421,60 @@ mod tests {
         let result = decode_bytes(&[]);
         assert!(matches!(result, Err(CborError::InvalidEncoding)));
     }
+
+    #[cfg(feature = "kes")]
+    #[test]
+    fn test_kes_cbor_matches_node_encoding() {
+        use crate::kes::{KesAlgorithm, Sum6Kes};
+
+        let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[0x5a; 32]).unwrap();
+        let vk = Sum6Kes::derive_verification_key(&sk).unwrap();
+        let sig = Sum6Kes::sign_kes(&(), 0, b"header body", &sk).unwrap();
+
+        // 32-byte key: major type 2 with a one-byte length
+        let vk_cbor = encode_kes_verification_key::<Sum6Kes>(&vk);
+        let mut expected = vec![0x58, 0x20];
+        expected.extend_from_slice(&vk);
+        assert_eq!(vk_cbor, expected);
+        assert_eq!(
+            decode_kes_verification_key::<Sum6Kes>(&vk_cbor).unwrap(),
+            vk
+        );
+
+        // The hot key as an operational certificate carries it, in the
+        // `[[kes_vk, counter, period, sigma], cold_vk]` of
+        // `tests/test_vectors/node.cert`. That file comes from a model of
+        // cardano-cli's format written independently of this crate, not from
+        // a testnet.
+        let node_cert = include_str!("../../tests/test_vectors/node.cert");
+        let cert_hex = node_cert
+            .split("\"cborHex\": \"")
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap();
+        let cert = crate::common::hex::decode(cert_hex).unwrap();
+        assert_eq!(&cert[..2], &[0x82, 0x84]);
+        assert_eq!(&cert[2..2 + vk_cbor.len()], vk_cbor.as_slice());
+        assert_eq!(
+            decode_kes_verification_key::<Sum6Kes>(&cert[2..2 + vk_cbor.len()]).unwrap(),
+            vk
+        );
+
+        // 448-byte signature: major type 2 with a two-byte length
+        let sig_cbor = encode_kes_signature::<Sum6Kes>(&sig);
+        assert_eq!(&sig_cbor[..3], &[0x59, 0x01, 0xc0]);
+        assert_eq!(sig_cbor.len(), 3 + Sum6Kes::SIGNATURE_SIZE);
+        let decoded = decode_kes_signature::<Sum6Kes>(&sig_cbor).unwrap();
+        Sum6Kes::verify_kes(&(), &vk, 0, b"header body", &decoded).unwrap();
+
+        // Right encoding, wrong length
+        assert_eq!(
+            decode_kes_verification_key::<Sum6Kes>(&encode_bytes(&vk[1..])),
+            Err(CborError::InvalidLength)
+        );
+        assert!(matches!(
+            decode_kes_signature::<Sum6Kes>(&encode_bytes(&[0u8; 64])),
+            Err(CborError::InvalidLength)
+        ));
+    }
 }
@@ -0,0 +1,71 @@
+//! Serde helpers for types with a fixed raw byte encoding
+//!
+//! Keys and signatures serialize through their `raw_serialize_*` encoding:
+//! as a byte string in binary formats, and as a lowercase hex string in
+//! human-readable ones (JSON, TOML, ...). Deserialization accepts either form
+//! and rejects inputs whose decoded length differs from the expected size.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::Serializer;
+
+/// Serialize raw bytes as hex (human-readable formats) or a byte string
+pub(crate) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&super::hex::encode(bytes))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Deserialize exactly `expected` raw bytes written by [`serialize`]
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    expected: usize,
+) -> Result<Vec<u8>, D::Error> {
+    let visitor = BytesVisitor { expected };
+    let bytes = if deserializer.is_human_readable() {
+        deserializer.deserialize_str(visitor)?
+    } else {
+        deserializer.deserialize_bytes(visitor)?
+    };
+    if bytes.len() != expected {
+        return Err(de::Error::invalid_length(bytes.len(), &visitor));
+    }
+    Ok(bytes)
+}
+
+#[derive(Clone, Copy)]
+struct BytesVisitor {
+    expected: usize,
+}
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bytes as a byte string or hex string", self.expected)
+    }
+
+    fn visit_str<E: de::Error>(self, hex: &str) -> Result<Vec<u8>, E> {
+        super::hex::decode(hex).map_err(|_| E::invalid_value(de::Unexpected::Str(hex), &self))
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(bytes.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(bytes)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        let mut bytes = Vec::with_capacity(self.expected);
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
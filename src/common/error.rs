@@ -21,8 +21,13 @@ pub type Result<T> = core::result::Result<T, CryptoError>;
 pub type CryptoResult<T> = core::result::Result<T, CryptoError>;
 
 /// Common cryptographic error types
+///
+/// Non-exhaustive, so new variants can be added without breaking downstream
+/// matches. [`as_kes_error`](Self::as_kes_error) reaches a wrapped
+/// [`KesError`](crate::kes::KesError) without a match.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "thiserror", derive(thiserror::Error))]
+#[non_exhaustive]
 pub enum CryptoError {
     /// Invalid VRF proof structure or verification failed
     #[cfg_attr(feature = "thiserror", error("Invalid VRF proof"))]
@@ -92,11 +97,25 @@ pub enum CryptoError {
     #[cfg_attr(feature = "thiserror", error("Cryptographic operation failed"))]
     CryptoFailure,
 
+    /// Malformed hex string (odd length or non-hex digit)
+    #[cfg_attr(feature = "thiserror", error("Invalid hex encoding"))]
+    InvalidHex,
+
     /// KES-specific error
     #[cfg(feature = "kes")]
     #[cfg_attr(feature = "thiserror", error("KES error: {0}"))]
     KesError(crate::kes::KesError),
 
+    /// VRF-specific error
+    #[cfg(feature = "vrf")]
+    #[cfg_attr(feature = "thiserror", error("VRF error: {0}"))]
+    VrfError(crate::vrf::VrfError),
+
+    /// Reading or writing a file failed
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "thiserror", error("I/O error: {0}"))]
+    Io(std::io::ErrorKind),
+
     /// Other error with description
     #[cfg_attr(feature = "thiserror", error("{0}"))]
     Other(&'static str),
@@ -123,13 +142,53 @@ impl fmt::Display for CryptoError {
             CryptoError::SerializationError => write!(f, "Serialization error"),
             CryptoError::DeserializationError => write!(f, "Deserialization error"),
             CryptoError::CryptoFailure => write!(f, "Cryptographic operation failed"),
+            CryptoError::InvalidHex => write!(f, "Invalid hex encoding"),
             #[cfg(feature = "kes")]
             CryptoError::KesError(e) => write!(f, "KES error: {}", e),
+            #[cfg(feature = "vrf")]
+            CryptoError::VrfError(e) => write!(f, "VRF error: {}", e),
+            #[cfg(feature = "std")]
+            CryptoError::Io(kind) => write!(f, "I/O error: {}", kind),
             CryptoError::Other(msg) => write!(f, "{}", msg),
         }
     }
 }
 
+impl CryptoError {
+    /// The wrapped KES error, if this is [`CryptoError::KesError`]
+    ///
+    /// ```rust
+    /// use cardano_crypto::common::error::CryptoError;
+    /// use cardano_crypto::kes::KesError;
+    ///
+    /// let err = CryptoError::from(KesError::KeyExpired);
+    /// assert_eq!(err.as_kes_error(), Some(&KesError::KeyExpired));
+    /// assert_eq!(CryptoError::InvalidInput.as_kes_error(), None);
+    /// ```
+    #[cfg(feature = "kes")]
+    #[must_use]
+    pub fn as_kes_error(&self) -> Option<&crate::kes::KesError> {
+        match self {
+            CryptoError::KesError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "kes")]
+impl From<crate::kes::KesError> for CryptoError {
+    fn from(err: crate::kes::KesError) -> Self {
+        CryptoError::KesError(err)
+    }
+}
+
+#[cfg(feature = "vrf")]
+impl From<crate::vrf::VrfError> for CryptoError {
+    fn from(err: crate::vrf::VrfError) -> Self {
+        CryptoError::VrfError(err)
+    }
+}
+
 #[cfg(all(not(feature = "thiserror"), feature = "std"))]
 impl std::error::Error for CryptoError {}
 
@@ -0,0 +1,90 @@
+//! Minimal hex codec for keys and signatures
+//!
+//! Kept in-crate so that hex helpers do not pull in an extra dependency.
+//! Encoding produces lowercase digits; decoding is case-insensitive and
+//! accepts an optional `0x`/`0X` prefix, as found in cardano-cli output and
+//! copied JSON.
+
+#[cfg(any(feature = "kes", feature = "serde"))]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(any(feature = "kes", feature = "serde"))]
+use super::error::{CryptoError, Result};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Encode bytes as lowercase hex
+#[cfg(any(feature = "kes", feature = "serde"))]
+pub(crate) fn encode(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        hex.push(HEX_DIGITS[usize::from(byte >> 4)] as char);
+        hex.push(HEX_DIGITS[usize::from(byte & 0x0f)] as char);
+    }
+    hex
+}
+
+/// Write bytes as lowercase hex, for `Display` impls
+pub(crate) fn fmt(bytes: &[u8], f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    use core::fmt::Write;
+    for byte in bytes {
+        f.write_char(HEX_DIGITS[usize::from(byte >> 4)] as char)?;
+        f.write_char(HEX_DIGITS[usize::from(byte & 0x0f)] as char)?;
+    }
+    Ok(())
+}
+
+/// Decode a hex string, ignoring an optional `0x` prefix
+///
+/// Returns [`CryptoError::InvalidHex`] for odd-length input or non-hex digits.
+#[cfg(any(feature = "kes", feature = "serde"))]
+pub(crate) fn decode(hex: &str) -> Result<Vec<u8>> {
+    let digits = hex
+        .strip_prefix("0x")
+        .or_else(|| hex.strip_prefix("0X"))
+        .unwrap_or(hex)
+        .as_bytes();
+    if !digits.len().is_multiple_of(2) {
+        return Err(CryptoError::InvalidHex);
+    }
+    digits
+        .chunks(2)
+        .map(|pair| match (digit_value(pair[0]), digit_value(pair[1])) {
+            (Some(high), Some(low)) => Ok((high << 4) | low),
+            _ => Err(CryptoError::InvalidHex),
+        })
+        .collect()
+}
+
+#[cfg(any(feature = "kes", feature = "serde"))]
+fn digit_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(all(test, any(feature = "kes", feature = "serde")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_roundtrip_with_prefix_and_mixed_case() {
+        let bytes = [0x00, 0xab, 0xcd, 0xef, 0x12];
+        assert_eq!(encode(&bytes), "00abcdef12");
+        assert_eq!(decode("00abcdef12").unwrap(), bytes);
+        assert_eq!(decode("0x00ABcDeF12").unwrap(), bytes);
+        assert_eq!(decode("0X00abcdef12").unwrap(), bytes);
+        assert_eq!(decode("").unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn hex_rejects_odd_length_and_non_hex() {
+        assert_eq!(decode("abc"), Err(CryptoError::InvalidHex));
+        assert_eq!(decode("0xabc"), Err(CryptoError::InvalidHex));
+        assert_eq!(decode("zz"), Err(CryptoError::InvalidHex));
+        assert_eq!(decode("0x0x"), Err(CryptoError::InvalidHex));
+    }
+}
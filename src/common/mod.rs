@@ -26,6 +26,15 @@
 
 pub mod error;
 
+#[cfg(all(feature = "serde", feature = "dsign"))]
+pub(crate) mod bytes_serde;
+
+#[cfg(feature = "dsign")]
+pub(crate) mod hex;
+
+#[cfg(any(feature = "kes", feature = "secure-mem"))]
+pub(crate) mod secure_mem;
+
 #[cfg(feature = "vrf")]
 pub mod curve;
 
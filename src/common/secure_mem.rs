@@ -0,0 +1,260 @@
+//! Secret buffers that are kept out of swap
+//!
+//! [`SecretBytes`] holds long-lived signing secrets: Ed25519 leaf keys and
+//! the right-subtree seeds of Sum KES keys. It wipes its contents on drop.
+//!
+//! With the `secure-mem` feature each buffer gets its own page-aligned
+//! allocation, which is `mlock`ed so the kernel never writes it to swap and,
+//! on Linux, marked `MADV_DONTDUMP` so it is left out of core dumps. The
+//! contents are wiped before the pages are unlocked and freed. Buffers get
+//! whole pages of their own because `munlock` is not reference counted:
+//! unlocking one buffer must not unlock a neighbour.
+//!
+//! Locking is best effort. It needs a Unix target, and `mlock` fails once the
+//! process exceeds `RLIMIT_MEMLOCK` (commonly 64 KiB for unprivileged
+//! processes on older systems, 8 MiB on current ones; a Sum7 key uses eight
+//! pages). A buffer that could not be locked is still wiped on drop. Values
+//! are copied through the stack on their way into a buffer, and those
+//! transient copies are not locked.
+
+use core::ops::{Deref, DerefMut};
+
+use zeroize::Zeroize;
+
+/// Heap buffer for secret bytes, wiped on drop and page-locked under `secure-mem`
+pub(crate) struct SecretBytes(imp::Buffer);
+
+impl SecretBytes {
+    /// Copy `bytes` into a new secret buffer
+    pub(crate) fn from_slice(bytes: &[u8]) -> Self {
+        let mut buffer = imp::Buffer::zeroed(bytes.len());
+        buffer.as_mut_slice().copy_from_slice(bytes);
+        Self(buffer)
+    }
+
+    /// Whether the buffer's pages are locked in memory
+    #[allow(dead_code)]
+    pub(crate) fn is_locked(&self) -> bool {
+        self.0.is_locked()
+    }
+}
+
+impl Deref for SecretBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl DerefMut for SecretBytes {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut_slice()
+    }
+}
+
+impl Clone for SecretBytes {
+    fn clone(&self) -> Self {
+        Self::from_slice(self)
+    }
+}
+
+impl PartialEq for SecretBytes {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for SecretBytes {}
+
+impl Zeroize for SecretBytes {
+    fn zeroize(&mut self) {
+        self.0.as_mut_slice().zeroize();
+    }
+}
+
+impl Drop for SecretBytes {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl zeroize::ZeroizeOnDrop for SecretBytes {}
+
+impl core::fmt::Debug for SecretBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SecretBytes(<{} bytes>)", self.len())
+    }
+}
+
+#[cfg(not(feature = "secure-mem"))]
+mod imp {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    pub(super) struct Buffer(Vec<u8>);
+
+    impl Buffer {
+        pub(super) fn zeroed(len: usize) -> Self {
+            Self(vec![0; len])
+        }
+
+        pub(super) fn is_locked(&self) -> bool {
+            false
+        }
+
+        pub(super) fn as_slice(&self) -> &[u8] {
+            &self.0
+        }
+
+        pub(super) fn as_mut_slice(&mut self) -> &mut [u8] {
+            &mut self.0
+        }
+    }
+}
+
+#[cfg(feature = "secure-mem")]
+mod imp {
+    use alloc::alloc::{alloc_zeroed, dealloc, handle_alloc_error, Layout};
+    use core::ptr::NonNull;
+
+    /// Page-aligned allocation covering whole pages, locked when possible
+    pub(super) struct Buffer {
+        ptr: NonNull<u8>,
+        len: usize,
+        layout: Layout,
+        locked: bool,
+    }
+
+    // The buffer uniquely owns its allocation, like a `Box<[u8]>`
+    unsafe impl Send for Buffer {}
+    unsafe impl Sync for Buffer {}
+
+    impl Buffer {
+        pub(super) fn zeroed(len: usize) -> Self {
+            let page = page_size();
+            let size = len.max(1).div_ceil(page) * page;
+            let layout = Layout::from_size_align(size, page).expect("page-sized layout");
+            // SAFETY: `layout` has a non-zero size
+            let ptr = NonNull::new(unsafe { alloc_zeroed(layout) })
+                .unwrap_or_else(|| handle_alloc_error(layout));
+            let locked = lock(ptr, size);
+            Self {
+                ptr,
+                len,
+                layout,
+                locked,
+            }
+        }
+
+        pub(super) fn is_locked(&self) -> bool {
+            self.locked
+        }
+
+        pub(super) fn as_slice(&self) -> &[u8] {
+            // SAFETY: `ptr` is valid for `layout.size() >= len` initialized bytes
+            unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+        }
+
+        pub(super) fn as_mut_slice(&mut self) -> &mut [u8] {
+            // SAFETY: as above, and `&mut self` guarantees exclusive access
+            unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+        }
+    }
+
+    impl Drop for Buffer {
+        fn drop(&mut self) {
+            // `SecretBytes` has already wiped the contents
+            if self.locked {
+                unlock(self.ptr, self.layout.size());
+            }
+            // SAFETY: allocated in `zeroed` with this layout
+            unsafe { dealloc(self.ptr.as_ptr(), self.layout) };
+        }
+    }
+
+    #[cfg(unix)]
+    fn page_size() -> usize {
+        // SAFETY: sysconf has no preconditions
+        match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+            size if size > 0 => size as usize,
+            _ => 4096,
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn page_size() -> usize {
+        4096
+    }
+
+    #[cfg(unix)]
+    fn lock(ptr: NonNull<u8>, size: usize) -> bool {
+        // SAFETY: the range is a live allocation owned by the caller
+        unsafe {
+            #[cfg(target_os = "linux")]
+            libc::madvise(ptr.as_ptr().cast(), size, libc::MADV_DONTDUMP);
+            libc::mlock(ptr.as_ptr().cast(), size) == 0
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn lock(_ptr: NonNull<u8>, _size: usize) -> bool {
+        false
+    }
+
+    #[cfg(unix)]
+    fn unlock(ptr: NonNull<u8>, size: usize) {
+        // SAFETY: the range was locked by `lock` and is still allocated
+        unsafe {
+            libc::munlock(ptr.as_ptr().cast(), size);
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn unlock(_ptr: NonNull<u8>, _size: usize) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn holds_and_wipes_bytes() {
+        let mut secret = SecretBytes::from_slice(&[7u8; 32]);
+        assert_eq!(&*secret, &[7u8; 32]);
+        assert_eq!(secret.clone(), secret);
+        assert_eq!(format!("{secret:?}"), "SecretBytes(<32 bytes>)");
+
+        secret[0] = 1;
+        assert_ne!(secret.clone(), SecretBytes::from_slice(&[7u8; 32]));
+
+        secret.zeroize();
+        assert_eq!(&*secret, &[0u8; 32]);
+        assert!(SecretBytes::from_slice(&[]).is_empty());
+    }
+
+    #[cfg(all(feature = "secure-mem", unix))]
+    #[test]
+    fn locks_pages_when_the_limit_allows() {
+        let mut limit = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        // SAFETY: `limit` is a valid out-pointer
+        assert_eq!(
+            unsafe { libc::getrlimit(libc::RLIMIT_MEMLOCK, &mut limit) },
+            0
+        );
+
+        let secrets: alloc::vec::Vec<_> = (0..4u8)
+            .map(|i| SecretBytes::from_slice(&[i; 64]))
+            .collect();
+        // Other tests lock pages concurrently, so only a roomy limit is conclusive
+        if limit.rlim_cur == libc::RLIM_INFINITY || limit.rlim_cur >= 8 << 20 {
+            assert!(secrets.iter().all(SecretBytes::is_locked));
+        }
+        for (i, secret) in secrets.iter().enumerate() {
+            assert_eq!(&**secret, &[i as u8; 64]);
+        }
+    }
+}
@@ -35,12 +35,18 @@ use crate::common::error::Result;
 /// - Implement constant-time operations where applicable
 /// - Properly zeroize secret key material in `forget_signing_key`
 ///
+/// This is the trait the KES base case
+/// ([`SingleKes`](crate::kes::SingleKes)) is generic over, so an alternative
+/// base signature only needs an implementation of it to be used under the
+/// `Sum`/`CompactSum` tree constructions.
+///
 /// # Examples
 ///
-/// ```ignore
+/// ```rust
 /// use cardano_crypto::common::traits::DsignAlgorithm;
 /// use cardano_crypto::dsign::Ed25519;
 ///
+/// # fn main() -> cardano_crypto::common::Result<()> {
 /// // Generate a key from a seed
 /// let seed = [42u8; 32];
 /// let signing_key = Ed25519::gen_key_from_seed(&seed)?;
@@ -52,6 +58,8 @@ use crate::common::error::Result;
 /// let message = b"important message";
 /// let signature = Ed25519::sign(message, &signing_key)?;
 /// Ed25519::verify(message, &signature, &verification_key)?;
+/// # Ok(())
+/// # }
 /// ```
 pub trait DsignAlgorithm {
     /// Verification key type
@@ -117,6 +125,26 @@ pub trait DsignAlgorithm {
     /// Digital signature binding the message to the signing key
     fn sign(message: &[u8], signing_key: &Self::SigningKey) -> Result<Self::Signature>;
 
+    /// Sign several messages with the same signing key
+    ///
+    /// Produces exactly the signatures [`sign`](Self::sign) would, in order.
+    /// Implementations can override this to expand the secret key once for
+    /// the whole batch instead of once per message.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first signing error encountered.
+    #[cfg(feature = "alloc")]
+    fn sign_batch(
+        messages: &[&[u8]],
+        signing_key: &Self::SigningKey,
+    ) -> Result<Vec<Self::Signature>> {
+        messages
+            .iter()
+            .map(|message| Self::sign(message, signing_key))
+            .collect()
+    }
+
     /// Verify a signature against a message and verification key
     ///
     /// Checks that the signature is cryptographically valid for the given
@@ -191,6 +219,32 @@ pub trait DsignAlgorithm {
     /// Returns error if bytes are invalid or malformed
     fn deserialize_signature(bytes: &[u8]) -> Result<Self::Signature>;
 
+    /// Write a verification key into `out`
+    ///
+    /// The default copies [`serialize_verification_key`](Self::serialize_verification_key);
+    /// implementations override it to write without allocating.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `out` is exactly [`VERIFICATION_KEY_SIZE`](Self::VERIFICATION_KEY_SIZE) bytes.
+    #[cfg(feature = "alloc")]
+    fn serialize_verification_key_into(key: &Self::VerificationKey, out: &mut [u8]) {
+        out.copy_from_slice(&Self::serialize_verification_key(key));
+    }
+
+    /// Write a signature into `out`
+    ///
+    /// The default copies [`serialize_signature`](Self::serialize_signature);
+    /// implementations override it to write without allocating.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `out` is exactly [`SIGNATURE_SIZE`](Self::SIGNATURE_SIZE) bytes.
+    #[cfg(feature = "alloc")]
+    fn serialize_signature_into(signature: &Self::Signature, out: &mut [u8]) {
+        out.copy_from_slice(&Self::serialize_signature(signature));
+    }
+
     /// Securely erase and forget a signing key
     ///
     /// Zeroizes the secret key material to prevent it from remaining in memory.
@@ -200,6 +254,52 @@ pub trait DsignAlgorithm {
     ///
     /// * `signing_key` - Signing key to securely erase
     fn forget_signing_key(signing_key: Self::SigningKey);
+
+    /// Securely erase a signing key that has to stay where it is
+    ///
+    /// For a key the caller still owns, such as a KES leaf key expiring in an
+    /// in-place update. The key is unusable afterwards and should be dropped.
+    /// The default leaves it untouched; implementations whose keys can be
+    /// zeroed in place override it, as `Ed25519` does.
+    fn forget_signing_key_in_place(signing_key: &mut Self::SigningKey) {
+        let _ = signing_key;
+    }
+}
+
+/// Serialization of DSIGN signing keys
+///
+/// Exporting secret key material is only needed for persistence (e.g. storing
+/// a KES key between node restarts), so it is kept out of [`DsignAlgorithm`]
+/// and must be opted into explicitly.
+///
+/// # Security
+///
+/// The serialized bytes are as sensitive as the key itself. They are returned
+/// in a [`zeroize::Zeroizing`] buffer so they are wiped when dropped.
+pub trait UnsoundDsignAlgorithm: DsignAlgorithm {
+    /// Serialize a signing key to its raw byte representation
+    #[cfg(feature = "alloc")]
+    fn raw_serialize_signing_key(signing_key: &Self::SigningKey) -> zeroize::Zeroizing<Vec<u8>>;
+
+    /// Write a signing key into `out`
+    ///
+    /// The default copies [`raw_serialize_signing_key`](Self::raw_serialize_signing_key);
+    /// implementations override it to write without allocating.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `out` is exactly [`SIGNING_KEY_SIZE`](DsignAlgorithm::SIGNING_KEY_SIZE) bytes.
+    #[cfg(feature = "alloc")]
+    fn raw_serialize_signing_key_into(signing_key: &Self::SigningKey, out: &mut [u8]) {
+        out.copy_from_slice(&Self::raw_serialize_signing_key(signing_key));
+    }
+
+    /// Deserialize a signing key from its raw byte representation
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the bytes have the wrong length or are inconsistent
+    fn raw_deserialize_signing_key(bytes: &[u8]) -> Result<Self::SigningKey>;
 }
 
 /// Trait for types that can be signed or proven over
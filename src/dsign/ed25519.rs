@@ -56,11 +56,45 @@ use ed25519_dalek::{
 };
 use ed25519_dalek::{Signer, Verifier};
 
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use curve25519_dalek::scalar::Scalar;
+
 const SEED_SIZE: usize = 32;
 const VERIFICATION_KEY_SIZE: usize = 32;
 const SIGNATURE_SIZE: usize = 64;
 const SECRET_COMPOUND_SIZE: usize = 64;
 
+/// Encoding checks libsodium makes before verifying, and so cardano-node
+///
+/// `crypto_sign_verify_detached` rejects a non-canonical `S`, a small-order
+/// `R`, and a verification key that is a non-canonical encoding (`y >= p`)
+/// or a point of small order. ed25519-dalek's `verify` accepts the latter
+/// three, and with them signatures such as `R = A = identity, S = 0` that
+/// verify for every message. Anything the node rejects must be rejected
+/// here too, or the two would disagree on which blocks are valid.
+fn libsodium_accepts(verification_key: &[u8; VERIFICATION_KEY_SIZE], signature: &[u8]) -> bool {
+    let (r, s) = signature.split_at(SIGNATURE_SIZE / 2);
+    let mut s_bytes = [0u8; 32];
+    s_bytes.copy_from_slice(s);
+    bool::from(Scalar::from_canonical_bytes(s_bytes).is_some())
+        && !has_small_order(r)
+        && is_canonical_point(verification_key)
+        && !has_small_order(verification_key)
+}
+
+/// Whether the encoded `y` is below `p = 2^255 - 19`, ignoring the sign bit
+fn is_canonical_point(bytes: &[u8; 32]) -> bool {
+    !(bytes[31] & 0x7f == 0x7f && bytes[1..31].iter().all(|&b| b == 0xff) && bytes[0] >= 0xed)
+}
+
+/// Whether `bytes` decode, canonically or not, to a point of order dividing 8
+fn has_small_order(bytes: &[u8]) -> bool {
+    CompressedEdwardsY::from_slice(bytes)
+        .ok()
+        .and_then(|point| point.decompress())
+        .is_some_and(|point| point.is_small_order())
+}
+
 /// Ed25519 verification (public) key
 ///
 /// A 32-byte compressed Edwards curve point representing the public verification key.
@@ -98,9 +132,74 @@ const SECRET_COMPOUND_SIZE: usize = 64;
 /// - Cannot be used to forge signatures (one-way derivation from signing key)
 /// - Safe to transmit over untrusted networks
 /// - Should be validated before use to ensure it's a valid curve point
-#[derive(Clone, PartialEq, Eq)]
+///
+/// Equality is constant-time, so comparing a registered key against a
+/// locally derived one does not leak where they differ. `Hash` feeds the
+/// hasher the 32 raw bytes, exactly as hashing [`as_bytes`](Self::as_bytes)
+/// would, so keys can index a `HashMap` and hash the same on every run
+/// given a fixed-key hasher.
+#[derive(Clone, Eq)]
 pub struct Ed25519VerificationKey([u8; VERIFICATION_KEY_SIZE]);
 
+impl subtle::ConstantTimeEq for Ed25519VerificationKey {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(&self.0[..], &other.0[..])
+    }
+}
+
+impl crate::common::traits::ConstantTimeEq for Ed25519VerificationKey {
+    fn ct_eq(&self, other: &Self) -> bool {
+        subtle::ConstantTimeEq::ct_eq(self, other).into()
+    }
+}
+
+impl PartialEq for Ed25519VerificationKey {
+    fn eq(&self, other: &Self) -> bool {
+        crate::common::traits::ConstantTimeEq::ct_eq(self, other)
+    }
+}
+
+/// Lowercase hex of the 32 key bytes
+impl core::fmt::Display for Ed25519VerificationKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::common::hex::fmt(&self.0, f)
+    }
+}
+
+impl core::hash::Hash for Ed25519VerificationKey {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0[..].hash(state);
+    }
+}
+
+/// Parse and validate like [`Ed25519VerificationKey::from_bytes`]
+///
+/// Fails with [`CryptoError::InvalidKeyLength`] for a slice that is not 32
+/// bytes and [`CryptoError::InvalidPublicKey`] if it is not a curve point.
+impl TryFrom<&[u8]> for Ed25519VerificationKey {
+    type Error = CryptoError;
+
+    fn try_from(bytes: &[u8]) -> core::result::Result<Self, Self::Error> {
+        let bytes: [u8; VERIFICATION_KEY_SIZE] = bytes
+            .try_into()
+            .map_err(|_| CryptoError::InvalidKeyLength)?;
+        Self::try_from(bytes)
+    }
+}
+
+/// Validate 32 bytes as a verification key
+///
+/// This is `TryFrom` rather than `From` because not every 32-byte string is a
+/// point on the curve; it fails with [`CryptoError::InvalidPublicKey`].
+impl TryFrom<[u8; VERIFICATION_KEY_SIZE]> for Ed25519VerificationKey {
+    type Error = CryptoError;
+
+    fn try_from(bytes: [u8; VERIFICATION_KEY_SIZE]) -> core::result::Result<Self, Self::Error> {
+        DalekVerifyingKey::from_bytes(&bytes).map_err(|_| CryptoError::InvalidPublicKey)?;
+        Ok(Self(bytes))
+    }
+}
+
 impl core::fmt::Debug for Ed25519VerificationKey {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Ed25519VerificationKey(<{} bytes>)", self.0.len())
@@ -194,7 +293,7 @@ impl Ed25519VerificationKey {
 /// ⚠️ **CRITICAL**: This key must be kept absolutely secret!
 /// - Never transmit over untrusted networks
 /// - Store encrypted at rest
-/// - Zeroize from memory after use
+/// - Zeroized automatically when dropped
 /// - Anyone with this key can forge signatures
 ///
 /// # Usage
@@ -216,7 +315,27 @@ impl Ed25519VerificationKey {
 /// assert_eq!(signing_key.compound_bytes().len(), 64);
 /// ```
 #[derive(Clone, PartialEq, Eq)]
-pub struct Ed25519SigningKey([u8; SECRET_COMPOUND_SIZE]);
+pub struct Ed25519SigningKey(CompoundBytes);
+
+/// Storage for the compound key; page-locked under the `secure-mem` feature
+#[cfg(not(feature = "secure-mem"))]
+type CompoundBytes = [u8; SECRET_COMPOUND_SIZE];
+#[cfg(feature = "secure-mem")]
+type CompoundBytes = crate::common::secure_mem::SecretBytes;
+
+impl zeroize::Zeroize for Ed25519SigningKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for Ed25519SigningKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+impl zeroize::ZeroizeOnDrop for Ed25519SigningKey {}
 
 impl core::fmt::Debug for Ed25519SigningKey {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -262,6 +381,12 @@ impl Ed25519SigningKey {
         compound[..SEED_SIZE].copy_from_slice(&seed_array);
         compound[SEED_SIZE..].copy_from_slice(&verifying_key.to_bytes());
 
+        #[cfg(feature = "secure-mem")]
+        let compound = {
+            let locked = CompoundBytes::from_slice(&compound);
+            zeroize::Zeroize::zeroize(&mut compound);
+            locked
+        };
         Self(compound)
     }
 
@@ -304,7 +429,7 @@ impl Ed25519SigningKey {
     }
 
     /// Get the dalek signing key for actual signing operations
-    fn signing_key(&self) -> DalekSigningKey {
+    pub(crate) fn signing_key(&self) -> DalekSigningKey {
         let seed = self.seed_bytes();
         DalekSigningKey::from_bytes(&seed)
     }
@@ -323,7 +448,9 @@ impl Ed25519SigningKey {
     /// ```
     #[must_use]
     pub fn compound_bytes(&self) -> &[u8; SECRET_COMPOUND_SIZE] {
-        &self.0
+        self.0[..]
+            .try_into()
+            .expect("compound key is SECRET_COMPOUND_SIZE bytes")
     }
 }
 
@@ -376,6 +503,13 @@ impl core::fmt::Debug for Ed25519Signature {
     }
 }
 
+/// Lowercase hex of the 64 signature bytes
+impl core::fmt::Display for Ed25519Signature {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::common::hex::fmt(&self.0, f)
+    }
+}
+
 impl Ed25519Signature {
     /// Create from dalek signature
     ///
@@ -416,6 +550,49 @@ impl Ed25519Signature {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Ed25519VerificationKey {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        crate::common::bytes_serde::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Ed25519VerificationKey {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        let bytes = crate::common::bytes_serde::deserialize(deserializer, VERIFICATION_KEY_SIZE)?;
+        Self::from_bytes(&bytes)
+            .ok_or_else(|| serde::de::Error::custom("invalid Ed25519 verification key"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Ed25519Signature {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        crate::common::bytes_serde::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Ed25519Signature {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        let bytes = crate::common::bytes_serde::deserialize(deserializer, SIGNATURE_SIZE)?;
+        let mut signature = [0u8; SIGNATURE_SIZE];
+        signature.copy_from_slice(&bytes);
+        Ok(Self(signature))
+    }
+}
+
 /// Ed25519 digital signature algorithm
 ///
 /// Standard Ed25519 as used in Cardano transactions and stake pool operations.
@@ -489,6 +666,9 @@ impl super::DsignAlgorithm for Ed25519 {
         message: &[u8],
         signature: &Self::Signature,
     ) -> Result<()> {
+        if !libsodium_accepts(verification_key.as_bytes(), signature.as_bytes()) {
+            return Err(CryptoError::VerificationFailed);
+        }
         let verifying_key = DalekVerifyingKey::from_bytes(verification_key.as_bytes())
             .map_err(|_| CryptoError::InvalidPublicKey)?;
 
@@ -591,6 +771,30 @@ impl CommonDsignAlgorithm for Ed25519 {
         Ok(Ed25519Signature::from_dalek(&signature))
     }
 
+    /// Sign a batch of messages, expanding the secret key only once
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cardano_crypto::dsign::Ed25519;
+    /// use cardano_crypto::common::traits::DsignAlgorithm;
+    ///
+    /// let sk = Ed25519::gen_key_from_seed(&[11u8; 32]).unwrap();
+    /// let sigs = Ed25519::sign_batch(&[b"block", b"vote"], &sk).unwrap();
+    /// assert_eq!(sigs[1], Ed25519::sign(b"vote", &sk).unwrap());
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sign_batch(
+        messages: &[&[u8]],
+        signing_key: &Self::SigningKey,
+    ) -> Result<alloc::vec::Vec<Self::Signature>> {
+        let signing_key_dalek = signing_key.signing_key();
+        Ok(messages
+            .iter()
+            .map(|message| Ed25519Signature::from_dalek(&signing_key_dalek.sign(message)))
+            .collect())
+    }
+
     /// Verify signature (parameter order: message, sig, key)
     ///
     /// # Example
@@ -610,6 +814,9 @@ impl CommonDsignAlgorithm for Ed25519 {
         signature: &Self::Signature,
         verification_key: &Self::VerificationKey,
     ) -> Result<()> {
+        if !libsodium_accepts(verification_key.as_bytes(), signature.as_bytes()) {
+            return Err(CommonCryptoError::VerificationFailed);
+        }
         let verifying_key = DalekVerifyingKey::from_bytes(verification_key.as_bytes())
             .map_err(|_| CommonCryptoError::InvalidPublicKey)?;
 
@@ -701,11 +908,65 @@ impl CommonDsignAlgorithm for Ed25519 {
         Ok(Ed25519Signature(array))
     }
 
+    #[cfg(feature = "alloc")]
+    fn serialize_verification_key_into(key: &Self::VerificationKey, out: &mut [u8]) {
+        out.copy_from_slice(key.as_bytes());
+    }
+
+    #[cfg(feature = "alloc")]
+    fn serialize_signature_into(signature: &Self::Signature, out: &mut [u8]) {
+        out.copy_from_slice(signature.as_bytes());
+    }
+
     fn forget_signing_key(mut signing_key: Self::SigningKey) {
         // Securely zeroize the signing key to prevent it from remaining in memory
         use zeroize::Zeroize;
         signing_key.0.zeroize();
     }
+
+    fn forget_signing_key_in_place(signing_key: &mut Self::SigningKey) {
+        use zeroize::Zeroize;
+        signing_key.0.zeroize();
+    }
+}
+
+impl crate::common::traits::UnsoundDsignAlgorithm for Ed25519 {
+    /// Serialize the 64-byte compound signing key (seed followed by public key)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cardano_crypto::dsign::Ed25519;
+    /// use cardano_crypto::common::traits::{DsignAlgorithm, UnsoundDsignAlgorithm};
+    ///
+    /// let sk = Ed25519::gen_key_from_seed(&[5u8; 32]).unwrap();
+    /// let bytes = Ed25519::raw_serialize_signing_key(&sk);
+    /// let restored = Ed25519::raw_deserialize_signing_key(&bytes).unwrap();
+    /// assert_eq!(sk, restored);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn raw_serialize_signing_key(
+        signing_key: &Self::SigningKey,
+    ) -> zeroize::Zeroizing<alloc::vec::Vec<u8>> {
+        zeroize::Zeroizing::new(signing_key.0.to_vec())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn raw_serialize_signing_key_into(signing_key: &Self::SigningKey, out: &mut [u8]) {
+        out.copy_from_slice(&signing_key.0);
+    }
+
+    fn raw_deserialize_signing_key(bytes: &[u8]) -> Result<Self::SigningKey> {
+        if bytes.len() != SECRET_COMPOUND_SIZE {
+            return Err(CommonCryptoError::InvalidKeyLength);
+        }
+        // Rebuild from the seed and make sure the stored public half matches
+        let signing_key = Ed25519SigningKey::from_seed_bytes(&bytes[..SEED_SIZE]);
+        if signing_key.0[SEED_SIZE..] != bytes[SEED_SIZE..] {
+            return Err(CommonCryptoError::InvalidSecretKey);
+        }
+        Ok(signing_key)
+    }
 }
 
 #[cfg(test)]
@@ -713,6 +974,49 @@ mod tests {
     use super::*;
     use crate::dsign::DsignAlgorithm;
 
+    #[test]
+    fn verification_key_try_from_bytes() {
+        let vk =
+            <Ed25519 as DsignAlgorithm>::derive_verification_key(&Ed25519::gen_key(&[3u8; 32]));
+        let bytes = *vk.as_bytes();
+
+        assert_eq!(Ed25519VerificationKey::try_from(bytes), Ok(vk.clone()));
+        assert_eq!(Ed25519VerificationKey::try_from(&bytes[..]), Ok(vk));
+        assert_eq!(
+            Ed25519VerificationKey::try_from(&bytes[..31]),
+            Err(CryptoError::InvalidKeyLength)
+        );
+        assert_eq!(
+            Ed25519VerificationKey::try_from([bytes.as_slice(), &[0]].concat().as_slice()),
+            Err(CryptoError::InvalidKeyLength)
+        );
+
+        // y = 2 has no matching x on the curve
+        let mut off_curve = [0u8; 32];
+        off_curve[0] = 2;
+        assert_eq!(
+            Ed25519VerificationKey::try_from(off_curve),
+            Err(CryptoError::InvalidPublicKey)
+        );
+    }
+
+    #[test]
+    fn verification_key_hashes_its_raw_bytes() {
+        use core::hash::{Hash, Hasher};
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+            // DefaultHasher::new uses fixed keys, so this is stable across runs
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let signing_key = Ed25519::gen_key(&[8u8; 32]);
+        let vk = <Ed25519 as DsignAlgorithm>::derive_verification_key(&signing_key);
+        assert_eq!(hash_of(&vk), hash_of(vk.as_bytes().as_slice()));
+    }
+
     #[test]
     fn test_key_generation_deterministic() {
         let seed = [7u8; 32];
@@ -808,6 +1112,110 @@ mod tests {
         );
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_verification_key_constant_time_eq() {
+        use crate::common::traits::ConstantTimeEq;
+
+        let vk1 = <Ed25519 as crate::dsign::DsignAlgorithm>::derive_verification_key(
+            &Ed25519::gen_key(&[1u8; 32]),
+        );
+        let vk1_again = <Ed25519 as crate::dsign::DsignAlgorithm>::derive_verification_key(
+            &Ed25519::gen_key(&[1u8; 32]),
+        );
+        let vk2 = <Ed25519 as crate::dsign::DsignAlgorithm>::derive_verification_key(
+            &Ed25519::gen_key(&[2u8; 32]),
+        );
+
+        assert!(vk1.ct_eq(&vk1_again));
+        assert!(!vk1.ct_eq(&vk2));
+        assert_eq!(vk1, vk1_again);
+        assert_ne!(vk1, vk2);
+    }
+
+    /// `R = identity, S = 0` under a small-order key satisfies the
+    /// cofactorless equation for every message
+    fn identity_forgery(verification_key: [u8; 32]) -> ([u8; 32], [u8; 64]) {
+        let mut signature = [0u8; 64];
+        signature[0] = 1;
+        (verification_key, signature)
+    }
+
+    fn verifies(verification_key: &[u8; 32], signature: &[u8; 64], message: &[u8]) -> bool {
+        <Ed25519 as crate::dsign::DsignAlgorithm>::verify(
+            &Ed25519VerificationKey(*verification_key),
+            message,
+            &Ed25519Signature(*signature),
+        )
+        .is_ok()
+    }
+
+    #[test]
+    fn rejects_encodings_libsodium_rejects() {
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        // y = p + 1, a non-canonical encoding of the identity
+        let mut non_canonical_identity = [0xffu8; 32];
+        non_canonical_identity[0] = 0xee;
+        non_canonical_identity[31] = 0x7f;
+        // y = 0, a point of order 4
+        let order_four = [0u8; 32];
+
+        for key in [identity, non_canonical_identity, order_four] {
+            let (key, signature) = identity_forgery(key);
+            assert!(!verifies(&key, &signature, b"any message"));
+        }
+
+        // ed25519-dalek on its own accepts the identity forgery
+        let (key, signature) = identity_forgery(identity);
+        let dalek_key = DalekVerifyingKey::from_bytes(&key).unwrap();
+        assert!(dalek_key
+            .verify(b"any message", &DalekSignature::from_bytes(&signature))
+            .is_ok());
+    }
+
+    #[test]
+    fn rejects_non_canonical_s() {
+        // Group order L, little-endian
+        const L: [u8; 32] = [
+            0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9,
+            0xde, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x10,
+        ];
+        let signing_key = Ed25519::gen_key(&[0x53u8; 32]);
+        let verification_key =
+            <Ed25519 as crate::dsign::DsignAlgorithm>::derive_verification_key(&signing_key);
+        let mut signature =
+            <Ed25519 as crate::dsign::DsignAlgorithm>::sign(&signing_key, b"block").0;
+        assert!(verifies(verification_key.as_bytes(), &signature, b"block"));
+
+        // S + L is the same scalar, but not its canonical encoding
+        let mut carry = 0u16;
+        for (byte, l) in signature[32..].iter_mut().zip(L) {
+            let sum = u16::from(*byte) + u16::from(l) + carry;
+            *byte = sum as u8;
+            carry = sum >> 8;
+        }
+        assert!(!verifies(verification_key.as_bytes(), &signature, b"block"));
+    }
+
+    // Under `secure-mem` the bytes live in a buffer that is freed on drop,
+    // so they cannot be read back afterwards
+    #[cfg(not(feature = "secure-mem"))]
+    #[test]
+    fn test_signing_key_zeroized_on_drop() {
+        let mut key = core::mem::ManuallyDrop::new(Ed25519::gen_key(&[7u8; 32]));
+        let bytes = key.compound_bytes().as_ptr();
+        assert_ne!(key.compound_bytes(), &[0u8; SECRET_COMPOUND_SIZE]);
+
+        // SAFETY: the storage is owned by the ManuallyDrop and never freed, so
+        // the bytes remain readable after the destructor has run.
+        unsafe {
+            core::mem::ManuallyDrop::drop(&mut key);
+            let after = core::slice::from_raw_parts(bytes, SECRET_COMPOUND_SIZE);
+            assert!(after.iter().all(|&b| b == 0));
+        }
+    }
 }
 
 // Helper for hex encoding in tests
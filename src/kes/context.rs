@@ -0,0 +1,283 @@
+//! Domain-separated KES signatures
+//!
+//! The schemes in this crate take `()` as their [`KesAlgorithm::Context`], so
+//! a signature over some bytes verifies wherever those bytes are accepted.
+//! [`ContextKes`] wraps one of them with a byte-string context, such as a
+//! network magic or a protocol tag, that is bound into every signature: a
+//! signature made under one context does not verify under another.
+//!
+//! The inner scheme signs `len(context) || context || message`, with the
+//! length as 8 big-endian bytes so that no two `(context, message)` pairs
+//! frame to the same bytes. Keys are those of the inner scheme; only
+//! signatures are affected.
+//!
+//! # Example
+//!
+//! ```rust
+//! use cardano_crypto::kes::{ContextKes, KesAlgorithm, Sum2Kes};
+//!
+//! # fn main() -> cardano_crypto::common::Result<()> {
+//! type Kes = ContextKes<Sum2Kes>;
+//! let mainnet = 764_824_073u32.to_be_bytes().to_vec();
+//! let preprod = 1u32.to_be_bytes().to_vec();
+//!
+//! let sk = Kes::gen_key_kes_from_seed_bytes(&[4u8; 32])?;
+//! let vk = Kes::derive_verification_key(&sk)?;
+//! let sig = Kes::sign_kes(&mainnet, 0, b"header", &sk)?;
+//!
+//! Kes::verify_kes(&mainnet, &vk, 0, b"header", &sig)?;
+//! assert!(Kes::verify_kes(&preprod, &vk, 0, b"header", &sig).is_err());
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use zeroize::Zeroizing;
+
+use crate::common::error::Result;
+use crate::kes::{BranchCache, KesAlgorithm, KesError, Period, SeedExpansion, UnsoundKesAlgorithm};
+
+/// A KES scheme whose signatures are bound to a byte-string context
+///
+/// `K` is any scheme with a `()` context. The context is passed wherever
+/// `K` takes `&()`, and must be the same at signing and verification.
+#[derive(Debug)]
+pub struct ContextKes<K>(PhantomData<K>);
+
+/// `len(context) || context || message`, the bytes `K` signs
+fn frame(context: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut framed = Vec::with_capacity(8 + context.len() + message.len());
+    framed.extend_from_slice(&(context.len() as u64).to_be_bytes());
+    framed.extend_from_slice(context);
+    framed.extend_from_slice(message);
+    framed
+}
+
+impl<K> KesAlgorithm for ContextKes<K>
+where
+    K: KesAlgorithm<Context = ()>,
+{
+    type VerificationKey = K::VerificationKey;
+    type SigningKey = K::SigningKey;
+    type Signature = K::Signature;
+    type Context = Vec<u8>;
+
+    const ALGORITHM_NAME: &'static str = K::ALGORITHM_NAME;
+    const TREE_HASH: Option<(&'static str, SeedExpansion)> = K::TREE_HASH;
+    const SEED_SIZE: usize = K::SEED_SIZE;
+    const VERIFICATION_KEY_SIZE: usize = K::VERIFICATION_KEY_SIZE;
+    const SIGNING_KEY_SIZE: usize = K::SIGNING_KEY_SIZE;
+    const SIGNATURE_SIZE: usize = K::SIGNATURE_SIZE;
+    const TREE_DEPTH: u32 = K::TREE_DEPTH;
+
+    fn total_periods() -> Period {
+        K::total_periods()
+    }
+
+    fn gen_key_kes_from_seed_bytes(seed: &[u8]) -> Result<Self::SigningKey> {
+        K::gen_key_kes_from_seed_bytes(seed)
+    }
+
+    fn derive_verification_key(signing_key: &Self::SigningKey) -> Result<Self::VerificationKey> {
+        K::derive_verification_key(signing_key)
+    }
+
+    fn verification_key_from_seed(seed: &[u8]) -> Result<Self::VerificationKey> {
+        K::verification_key_from_seed(seed)
+    }
+
+    fn sign_kes(
+        context: &Self::Context,
+        period: Period,
+        message: &[u8],
+        signing_key: &Self::SigningKey,
+    ) -> Result<Self::Signature> {
+        K::sign_kes(&(), period, &frame(context, message), signing_key)
+    }
+
+    fn sign_kes_zeroizing(
+        context: &Self::Context,
+        period: Period,
+        message: Zeroizing<Vec<u8>>,
+        signing_key: &Self::SigningKey,
+    ) -> Result<Self::Signature> {
+        let framed = Zeroizing::new(frame(context, &message));
+        drop(message);
+        K::sign_kes(&(), period, &framed, signing_key)
+    }
+
+    fn sign_batch_at_period(
+        context: &Self::Context,
+        period: Period,
+        messages: &[&[u8]],
+        signing_key: &Self::SigningKey,
+    ) -> Result<Vec<Self::Signature>> {
+        let framed: Vec<Vec<u8>> = messages
+            .iter()
+            .map(|message| frame(context, message))
+            .collect();
+        let framed: Vec<&[u8]> = framed.iter().map(Vec::as_slice).collect();
+        K::sign_batch_at_period(&(), period, &framed, signing_key)
+    }
+
+    fn verify_kes(
+        context: &Self::Context,
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<()> {
+        K::verify_kes(
+            &(),
+            verification_key,
+            period,
+            &frame(context, message),
+            signature,
+        )
+    }
+
+    fn verify_kes_cached(
+        context: &Self::Context,
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        message: &[u8],
+        signature: &Self::Signature,
+        cache: &mut BranchCache,
+    ) -> Result<()> {
+        K::verify_kes_cached(
+            &(),
+            verification_key,
+            period,
+            &frame(context, message),
+            signature,
+            cache,
+        )
+    }
+
+    fn current_period_kes(signing_key: &Self::SigningKey) -> Period {
+        K::current_period_kes(signing_key)
+    }
+
+    fn update_kes(
+        _context: &Self::Context,
+        signing_key: Self::SigningKey,
+        period: Period,
+    ) -> Result<Option<Self::SigningKey>> {
+        K::update_kes(&(), signing_key, period)
+    }
+
+    fn update_kes_in_place(
+        _context: &Self::Context,
+        signing_key: &mut Self::SigningKey,
+        period: Period,
+    ) -> Result<bool> {
+        K::update_kes_in_place(&(), signing_key, period)
+    }
+
+    #[cfg(feature = "constant-time")]
+    fn pad_update_kes() {
+        K::pad_update_kes();
+    }
+
+    fn update_kes_to(
+        _context: &Self::Context,
+        signing_key: Self::SigningKey,
+        from: Period,
+        to: Period,
+    ) -> Result<Option<Self::SigningKey>> {
+        K::update_kes_to(&(), signing_key, from, to)
+    }
+
+    fn raw_serialize_verification_key_kes(key: &Self::VerificationKey) -> Vec<u8> {
+        K::raw_serialize_verification_key_kes(key)
+    }
+
+    fn raw_deserialize_verification_key_kes(bytes: &[u8]) -> Option<Self::VerificationKey> {
+        K::raw_deserialize_verification_key_kes(bytes)
+    }
+
+    fn raw_serialize_signature_kes(signature: &Self::Signature) -> Vec<u8> {
+        K::raw_serialize_signature_kes(signature)
+    }
+
+    fn raw_deserialize_signature_kes(bytes: &[u8]) -> Option<Self::Signature> {
+        K::raw_deserialize_signature_kes(bytes)
+    }
+
+    fn raw_serialize_verification_key_kes_into(
+        key: &Self::VerificationKey,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        K::raw_serialize_verification_key_kes_into(key, out)
+    }
+
+    fn raw_serialize_signature_kes_into(
+        signature: &Self::Signature,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        K::raw_serialize_signature_kes_into(signature, out)
+    }
+
+    fn forget_signing_key_kes(signing_key: Self::SigningKey) {
+        K::forget_signing_key_kes(signing_key);
+    }
+}
+
+impl<K> UnsoundKesAlgorithm for ContextKes<K>
+where
+    K: UnsoundKesAlgorithm<Context = ()>,
+{
+    fn raw_serialize_signing_key_kes(
+        signing_key: &Self::SigningKey,
+    ) -> zeroize::Zeroizing<Vec<u8>> {
+        K::raw_serialize_signing_key_kes(signing_key)
+    }
+
+    fn raw_serialize_signing_key_kes_into(
+        signing_key: &Self::SigningKey,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        K::raw_serialize_signing_key_kes_into(signing_key, out)
+    }
+
+    fn raw_deserialize_signing_key_kes(bytes: &[u8]) -> Option<Self::SigningKey> {
+        K::raw_deserialize_signing_key_kes(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kes::{CompactSum3Kes, Sum3Kes};
+
+    typed_tests! {
+        fn signature_under_one_context_fails_under_another<K>() for {
+            sum3: Sum3Kes,
+            compact_sum3: CompactSum3Kes,
+        } {
+            let a = b"network-a".to_vec();
+            let b = b"network-b".to_vec();
+            let sk = ContextKes::<K>::gen_key_kes_from_seed_bytes(&[0x41u8; 32]).unwrap();
+            let vk = ContextKes::<K>::derive_verification_key(&sk).unwrap();
+            let sig = ContextKes::<K>::sign_kes(&a, 0, b"header", &sk).unwrap();
+
+            ContextKes::<K>::verify_kes(&a, &vk, 0, b"header", &sig).unwrap();
+            assert!(ContextKes::<K>::verify_kes(&b, &vk, 0, b"header", &sig).is_err());
+            assert!(K::verify_kes(&(), &vk, 0, b"header", &sig).is_err());
+
+            // The length prefix keeps a context/message split from being moved
+            let shifted =
+                ContextKes::<K>::sign_kes(&b"network".to_vec(), 0, b"-aheader", &sk).unwrap();
+            assert!(ContextKes::<K>::verify_kes(&a, &vk, 0, b"header", &shifted).is_err());
+
+            let batch = ContextKes::<K>::sign_batch_at_period(&a, 0, &[b"header"], &sk).unwrap();
+            assert_eq!(
+                ContextKes::<K>::raw_serialize_signature_kes(&batch[0]),
+                ContextKes::<K>::raw_serialize_signature_kes(&sig)
+            );
+            ContextKes::<K>::forget_signing_key_kes(sk);
+        }
+    }
+}
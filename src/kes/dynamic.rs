@@ -0,0 +1,298 @@
+//! Runtime selection of the Cardano KES schemes
+//!
+//! Tools reading operational certificates or block headers learn the KES
+//! depth from data, not from the type system. [`KesScheme`] names each
+//! Blake2b-256 `SumNKes`/`CompactSumNKes` alias, and [`verify_dynamic`]
+//! dispatches raw bytes to the matching monomorphized implementation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use cardano_crypto::kes::{verify_dynamic, KesAlgorithm, KesScheme, Sum6Kes};
+//!
+//! # fn main() -> cardano_crypto::common::Result<()> {
+//! let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[7u8; 32])?;
+//! let vk = Sum6Kes::raw_serialize_verification_key_kes(&Sum6Kes::derive_verification_key(&sk)?);
+//! let sig = Sum6Kes::raw_serialize_signature_kes(&Sum6Kes::sign_kes(&(), 0, b"header", &sk)?);
+//!
+//! let scheme = KesScheme::from_depth(6, false).unwrap();
+//! verify_dynamic(scheme, &vk, 0, b"header", &sig)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::common::error::{CryptoError, Result};
+use crate::kes::{
+    CompactSum0Kes, CompactSum1Kes, CompactSum2Kes, CompactSum3Kes, CompactSum4Kes, CompactSum5Kes,
+    CompactSum6Kes, CompactSum7Kes, KesAlgorithm, KesError, Period, Sum0Kes, Sum1Kes, Sum2Kes,
+    Sum3Kes, Sum4Kes, Sum5Kes, Sum6Kes, Sum7Kes,
+};
+
+/// A Cardano KES scheme chosen at runtime
+///
+/// `SumN` is [`SumNKes`](crate::kes::Sum6Kes) and `CompactSumN` is
+/// [`CompactSumNKes`](crate::kes::CompactSum6Kes), both over Ed25519 and
+/// Blake2b-256. Cardano mainnet uses `Sum6`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KesScheme {
+    /// [`Sum0Kes`], a single Ed25519 period
+    Sum0,
+    /// [`Sum1Kes`]
+    Sum1,
+    /// [`Sum2Kes`]
+    Sum2,
+    /// [`Sum3Kes`]
+    Sum3,
+    /// [`Sum4Kes`]
+    Sum4,
+    /// [`Sum5Kes`]
+    Sum5,
+    /// [`Sum6Kes`], used by Cardano mainnet
+    Sum6,
+    /// [`Sum7Kes`]
+    Sum7,
+    /// [`CompactSum0Kes`]
+    CompactSum0,
+    /// [`CompactSum1Kes`]
+    CompactSum1,
+    /// [`CompactSum2Kes`]
+    CompactSum2,
+    /// [`CompactSum3Kes`]
+    CompactSum3,
+    /// [`CompactSum4Kes`]
+    CompactSum4,
+    /// [`CompactSum5Kes`]
+    CompactSum5,
+    /// [`CompactSum6Kes`]
+    CompactSum6,
+    /// [`CompactSum7Kes`]
+    CompactSum7,
+}
+
+impl KesScheme {
+    const SUM: [Self; 8] = [
+        Self::Sum0,
+        Self::Sum1,
+        Self::Sum2,
+        Self::Sum3,
+        Self::Sum4,
+        Self::Sum5,
+        Self::Sum6,
+        Self::Sum7,
+    ];
+
+    const COMPACT_SUM: [Self; 8] = [
+        Self::CompactSum0,
+        Self::CompactSum1,
+        Self::CompactSum2,
+        Self::CompactSum3,
+        Self::CompactSum4,
+        Self::CompactSum5,
+        Self::CompactSum6,
+        Self::CompactSum7,
+    ];
+
+    /// Look up the scheme for a tree depth, or `None` past depth 7
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::KesScheme;
+    ///
+    /// assert_eq!(KesScheme::from_depth(6, true), Some(KesScheme::CompactSum6));
+    /// assert_eq!(KesScheme::from_depth(8, false), None);
+    /// ```
+    #[must_use]
+    pub const fn from_depth(depth: u32, compact: bool) -> Option<Self> {
+        let schemes = if compact {
+            &Self::COMPACT_SUM
+        } else {
+            &Self::SUM
+        };
+        if (depth as usize) < schemes.len() {
+            Some(schemes[depth as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Tree depth, the `N` in `SumNKes`
+    #[must_use]
+    pub const fn depth(self) -> u32 {
+        match self {
+            Self::Sum0 | Self::CompactSum0 => 0,
+            Self::Sum1 | Self::CompactSum1 => 1,
+            Self::Sum2 | Self::CompactSum2 => 2,
+            Self::Sum3 | Self::CompactSum3 => 3,
+            Self::Sum4 | Self::CompactSum4 => 4,
+            Self::Sum5 | Self::CompactSum5 => 5,
+            Self::Sum6 | Self::CompactSum6 => 6,
+            Self::Sum7 | Self::CompactSum7 => 7,
+        }
+    }
+
+    /// Whether this is a `CompactSum` scheme
+    #[must_use]
+    pub const fn is_compact(self) -> bool {
+        matches!(
+            self,
+            Self::CompactSum0
+                | Self::CompactSum1
+                | Self::CompactSum2
+                | Self::CompactSum3
+                | Self::CompactSum4
+                | Self::CompactSum5
+                | Self::CompactSum6
+                | Self::CompactSum7
+        )
+    }
+
+    /// Number of periods a key of this scheme covers, `2^depth`
+    #[must_use]
+    pub const fn total_periods(self) -> Period {
+        1 << self.depth()
+    }
+}
+
+/// Verify a raw KES signature under a scheme chosen at runtime
+///
+/// `verification_key` and `signature` are the raw serializations, as found
+/// in operational certificates and block headers.
+///
+/// # Errors
+///
+/// - [`KesError::PeriodOutOfRange`] if `period` is past the scheme's last period
+/// - [`KesError::WrongLength`] if either encoding has the wrong size for `scheme`
+/// - [`CryptoError::DeserializationError`] if the key bytes are not a valid key
+/// - [`KesError::MalformedSignature`] if the signature bytes do not decode
+/// - Whatever [`verify_kes`](KesAlgorithm::verify_kes) returns for a bad signature
+pub fn verify_dynamic(
+    scheme: KesScheme,
+    verification_key: &[u8],
+    period: Period,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<()> {
+    if period >= scheme.total_periods() {
+        return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+            period,
+            max_period: scheme.total_periods() - 1,
+        }));
+    }
+    let verify = match scheme {
+        KesScheme::Sum0 => verify_raw::<Sum0Kes>,
+        KesScheme::Sum1 => verify_raw::<Sum1Kes>,
+        KesScheme::Sum2 => verify_raw::<Sum2Kes>,
+        KesScheme::Sum3 => verify_raw::<Sum3Kes>,
+        KesScheme::Sum4 => verify_raw::<Sum4Kes>,
+        KesScheme::Sum5 => verify_raw::<Sum5Kes>,
+        KesScheme::Sum6 => verify_raw::<Sum6Kes>,
+        KesScheme::Sum7 => verify_raw::<Sum7Kes>,
+        KesScheme::CompactSum0 => verify_raw::<CompactSum0Kes>,
+        KesScheme::CompactSum1 => verify_raw::<CompactSum1Kes>,
+        KesScheme::CompactSum2 => verify_raw::<CompactSum2Kes>,
+        KesScheme::CompactSum3 => verify_raw::<CompactSum3Kes>,
+        KesScheme::CompactSum4 => verify_raw::<CompactSum4Kes>,
+        KesScheme::CompactSum5 => verify_raw::<CompactSum5Kes>,
+        KesScheme::CompactSum6 => verify_raw::<CompactSum6Kes>,
+        KesScheme::CompactSum7 => verify_raw::<CompactSum7Kes>,
+    };
+    verify(verification_key, period, message, signature)
+}
+
+fn verify_raw<K: KesAlgorithm<Context = ()>>(
+    verification_key: &[u8],
+    period: Period,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<()> {
+    check_length(verification_key, K::VERIFICATION_KEY_SIZE)?;
+    check_length(signature, K::SIGNATURE_SIZE)?;
+    let verification_key = K::raw_deserialize_verification_key_kes(verification_key)
+        .ok_or(CryptoError::DeserializationError)?;
+    let signature = K::raw_deserialize_signature_kes(signature)
+        .ok_or(CryptoError::KesError(KesError::MalformedSignature))?;
+    K::verify_kes(&(), &verification_key, period, message, &signature)
+}
+
+fn check_length(bytes: &[u8], expected: usize) -> Result<()> {
+    if bytes.len() != expected {
+        return Err(CryptoError::KesError(KesError::WrongLength {
+            expected,
+            actual: bytes.len(),
+        }));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum6_roundtrips_through_dynamic_path() {
+        let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[71u8; 32]).unwrap();
+        let sk = Sum6Kes::update_kes_to(&(), sk, 0, 37).unwrap().unwrap();
+        let vk = Sum6Kes::raw_serialize_verification_key_kes(
+            &Sum6Kes::derive_verification_key(&sk).unwrap(),
+        );
+        let sig = Sum6Kes::raw_serialize_signature_kes(
+            &Sum6Kes::sign_kes(&(), 37, b"header", &sk).unwrap(),
+        );
+
+        verify_dynamic(KesScheme::Sum6, &vk, 37, b"header", &sig).unwrap();
+        assert_eq!(
+            verify_dynamic(KesScheme::Sum6, &vk, 36, b"header", &sig),
+            Err(CryptoError::KesError(KesError::BadEd25519Signature))
+        );
+        assert_eq!(
+            verify_dynamic(KesScheme::Sum6, &vk, 37, b"forged", &sig),
+            Err(CryptoError::KesError(KesError::BadEd25519Signature))
+        );
+        let mut other_vk = vk.clone();
+        other_vk[0] ^= 1;
+        assert_eq!(
+            verify_dynamic(KesScheme::Sum6, &other_vk, 37, b"header", &sig),
+            Err(CryptoError::KesError(KesError::BadMerklePath))
+        );
+        // Right length, but the first leaf key is y = 2, which is not on the curve
+        let mut malformed = sig.clone();
+        malformed[64..96].fill(0);
+        malformed[64] = 2;
+        assert_eq!(
+            verify_dynamic(KesScheme::Sum6, &vk, 37, b"header", &malformed),
+            Err(CryptoError::KesError(KesError::MalformedSignature))
+        );
+        assert_eq!(
+            verify_dynamic(KesScheme::Sum6, &vk, 64, b"header", &sig),
+            Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+                period: 64,
+                max_period: 63,
+            }))
+        );
+        // A Sum6 signature is too long for CompactSum6
+        assert_eq!(
+            verify_dynamic(KesScheme::CompactSum6, &vk, 37, b"header", &sig),
+            Err(CryptoError::KesError(KesError::WrongLength {
+                expected: CompactSum6Kes::SIGNATURE_SIZE,
+                actual: Sum6Kes::SIGNATURE_SIZE,
+            }))
+        );
+    }
+
+    #[test]
+    fn scheme_matches_static_types() {
+        for compact in [false, true] {
+            for depth in 0..8 {
+                let scheme = KesScheme::from_depth(depth, compact).unwrap();
+                assert_eq!(scheme.depth(), depth);
+                assert_eq!(scheme.is_compact(), compact);
+            }
+        }
+        assert_eq!(KesScheme::Sum6.total_periods(), Sum6Kes::total_periods());
+        assert_eq!(
+            KesScheme::CompactSum0.total_periods(),
+            CompactSum0Kes::total_periods()
+        );
+    }
+}
@@ -0,0 +1,457 @@
+//! cardano-cli text envelopes for KES keys
+//!
+//! `cardano-cli node key-gen-KES` writes keys as JSON:
+//!
+//! ```text
+//! {
+//!     "type": "KesVerificationKey_ed25519_kes_2^6",
+//!     "description": "KES Verification Key",
+//!     "cborHex": "5820..."
+//! }
+//! ```
+//!
+//! The `2^N` suffix gives the Sum tree depth, and `cborHex` is the raw key
+//! wrapped in a CBOR byte string. [`from_text_envelope`] reads a verification
+//! key envelope of any depth up to 7 and [`to_text_envelope`] writes one;
+//! [`signing_key_from_text_envelope`] reads the matching `KesSigningKey_...`
+//! envelope into a typed key.
+//!
+//! # Example
+//!
+//! ```rust
+//! use cardano_crypto::kes::envelope::from_text_envelope;
+//! use cardano_crypto::kes::KesScheme;
+//!
+//! let json = r#"{
+//!     "type": "KesVerificationKey_ed25519_kes_2^6",
+//!     "description": "KES Verification Key",
+//!     "cborHex": "58200000000000000000000000000000000000000000000000000000000000000000"
+//! }"#;
+//! let (scheme, vk) = from_text_envelope(json).unwrap();
+//! assert_eq!(scheme, KesScheme::Sum6);
+//! assert_eq!(vk.len(), 32);
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use zeroize::Zeroizing;
+
+use crate::cbor;
+use crate::common::hex;
+use crate::dsign::ed25519::Ed25519SigningKey;
+use crate::kes::{KesAlgorithm, KesError, KesScheme, UnsoundKesAlgorithm};
+
+const VERIFICATION_KEY_TYPE: &str = "KesVerificationKey_ed25519_kes_2^";
+const SIGNING_KEY_TYPE: &str = "KesSigningKey_ed25519_kes_2^";
+const VERIFICATION_KEY_DESCRIPTION: &str = "KES Verification Key";
+
+/// cardano-node keeps only the seed of the Ed25519 leaf signing key
+const LEAF_SEED_SIZE: usize = 32;
+/// The leaf public key this crate stores after the seed
+const LEAF_PUBLIC_KEY_SIZE: usize = 32;
+
+/// Read a KES verification key from a cardano-cli text envelope
+///
+/// Returns the Sum scheme named by the `type` tag and the raw verification
+/// key, ready for [`verify_dynamic`](crate::kes::verify_dynamic).
+///
+/// # Errors
+///
+/// - [`KesError::InvalidTextEnvelope`] if the JSON, hex or CBOR is malformed,
+///   or the key bytes do not decode
+/// - [`KesError::UnsupportedKeyType`] if `type` is not a KES verification key
+///   of depth 0 to 7
+/// - [`KesError::WrongLength`] if the key is not 32 bytes
+pub fn from_text_envelope(json: &str) -> Result<(KesScheme, Vec<u8>), KesError> {
+    let envelope = Envelope::parse(json)?;
+    let scheme = envelope.scheme(VERIFICATION_KEY_TYPE)?;
+    let raw = envelope.key_bytes()?;
+    check_scheme_verification_key(scheme, &raw)?;
+    Ok((scheme, raw.to_vec()))
+}
+
+/// Write a KES verification key as a cardano-cli text envelope
+///
+/// Produces the same bytes as `cardano-cli node key-gen-KES` writes to its
+/// `.vkey` file: four-space indented JSON with `type`, `description` and
+/// `cborHex`, and a trailing newline.
+///
+/// # Errors
+///
+/// - [`KesError::UnsupportedKeyType`] for `CompactSum` schemes, which have
+///   no cardano-cli type tag
+/// - [`KesError::WrongLength`] if `verification_key` is not 32 bytes
+/// - [`KesError::InvalidTextEnvelope`] if a `Sum0` key is not a valid
+///   Ed25519 public key
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::kes::envelope::{from_text_envelope, to_text_envelope};
+/// use cardano_crypto::kes::{KesAlgorithm, KesScheme, Sum6Kes};
+///
+/// let vk = Sum6Kes::verification_key_from_seed(&[4u8; 32]).unwrap();
+/// let json = to_text_envelope(KesScheme::Sum6, &vk).unwrap();
+/// assert!(json.starts_with("{\n    \"type\": \"KesVerificationKey_ed25519_kes_2^6\""));
+/// assert_eq!(from_text_envelope(&json).unwrap(), (KesScheme::Sum6, vk));
+/// ```
+pub fn to_text_envelope(scheme: KesScheme, verification_key: &[u8]) -> Result<String, KesError> {
+    if scheme.is_compact() {
+        return Err(KesError::UnsupportedKeyType);
+    }
+    check_scheme_verification_key(scheme, verification_key)?;
+    Ok(format!(
+        "{{\n    \"type\": \"{VERIFICATION_KEY_TYPE}{}\",\n    \"description\": \"{VERIFICATION_KEY_DESCRIPTION}\",\n    \"cborHex\": \"{}\"\n}}\n",
+        scheme.depth(),
+        hex::encode(&cbor::encode_bytes(verification_key)),
+    ))
+}
+
+/// Read a KES signing key from a cardano-cli text envelope
+///
+/// `K` must be a Sum scheme over Ed25519 of the depth in the `type` tag.
+/// cardano-node stores the Ed25519 leaf as its 32-byte seed, so a Sum6 key
+/// is 608 bytes; the leaf is expanded to the seed-and-public-key form
+/// [`UnsoundKesAlgorithm::raw_deserialize_signing_key_kes`] expects.
+/// Intermediate copies of the key bytes are wiped before returning.
+///
+/// # Errors
+///
+/// - [`KesError::InvalidTextEnvelope`] if the JSON, hex or CBOR is malformed,
+///   or the key bytes do not decode
+/// - [`KesError::UnsupportedKeyType`] if `type` is not a KES signing key of
+///   depth `K::TREE_DEPTH`
+/// - [`KesError::WrongLength`] if the key is not `K::SIGNING_KEY_SIZE - 32`
+///   bytes, the cardano-node size
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::kes::envelope::signing_key_from_text_envelope;
+/// use cardano_crypto::kes::{KesAlgorithm, Sum1Kes, UnsoundKesAlgorithm};
+///
+/// let sk = Sum1Kes::gen_key_kes_from_seed_bytes(&[5u8; 32]).unwrap();
+/// // cardano-node drops the leaf's public key, bytes 32..64 of this layout
+/// let mut raw = Sum1Kes::raw_serialize_signing_key_kes(&sk).to_vec();
+/// raw.drain(32..64);
+/// let json = format!(
+///     r#"{{"type": "KesSigningKey_ed25519_kes_2^1", "description": "", "cborHex": "5880{}"}}"#,
+///     raw.iter().map(|b| format!("{b:02x}")).collect::<String>()
+/// );
+///
+/// let loaded = signing_key_from_text_envelope::<Sum1Kes>(&json).unwrap();
+/// assert_eq!(
+///     Sum1Kes::derive_verification_key(&loaded).unwrap(),
+///     Sum1Kes::derive_verification_key(&sk).unwrap(),
+/// );
+/// ```
+pub fn signing_key_from_text_envelope<K: UnsoundKesAlgorithm>(
+    json: &str,
+) -> Result<K::SigningKey, KesError> {
+    let envelope = Envelope::parse(json)?;
+    if envelope.scheme(SIGNING_KEY_TYPE)?.depth() != K::TREE_DEPTH {
+        return Err(KesError::UnsupportedKeyType);
+    }
+    let node_raw = envelope.key_bytes()?;
+    let expected = K::SIGNING_KEY_SIZE - LEAF_PUBLIC_KEY_SIZE;
+    if node_raw.len() != expected {
+        return Err(KesError::WrongLength {
+            expected,
+            actual: node_raw.len(),
+        });
+    }
+
+    let (leaf_seed, rest) = node_raw.split_at(LEAF_SEED_SIZE);
+    let leaf = Ed25519SigningKey::from_seed_bytes(leaf_seed);
+    let mut raw = Zeroizing::new(Vec::with_capacity(K::SIGNING_KEY_SIZE));
+    raw.extend_from_slice(leaf.compound_bytes());
+    raw.extend_from_slice(rest);
+    K::raw_deserialize_signing_key_kes(&raw).ok_or(KesError::InvalidTextEnvelope)
+}
+
+fn check_scheme_verification_key(scheme: KesScheme, raw: &[u8]) -> Result<(), KesError> {
+    // Sum roots are 32-byte hashes; only the Sum0 leaf must be a curve point
+    match scheme {
+        KesScheme::Sum0 => check_verification_key::<crate::kes::Sum0Kes>(raw),
+        _ => check_verification_key::<crate::kes::Sum1Kes>(raw),
+    }
+}
+
+fn check_verification_key<K: KesAlgorithm>(raw: &[u8]) -> Result<(), KesError> {
+    if raw.len() != K::VERIFICATION_KEY_SIZE {
+        return Err(KesError::WrongLength {
+            expected: K::VERIFICATION_KEY_SIZE,
+            actual: raw.len(),
+        });
+    }
+    K::raw_deserialize_verification_key_kes(raw)
+        .map(|_| ())
+        .ok_or(KesError::InvalidTextEnvelope)
+}
+
+/// The `type` and `cborHex` fields of a text envelope
+struct Envelope {
+    key_type: String,
+    cbor_hex: Zeroizing<String>,
+}
+
+impl Envelope {
+    /// Parse a flat JSON object whose values are all strings
+    fn parse(json: &str) -> Result<Self, KesError> {
+        let mut parser = Parser {
+            bytes: json.as_bytes(),
+            pos: 0,
+        };
+        let mut key_type = None;
+        let mut cbor_hex = None;
+
+        parser.expect(b'{')?;
+        if !parser.eat(b'}') {
+            loop {
+                let name = parser.string()?;
+                parser.expect(b':')?;
+                let value = Zeroizing::new(parser.string()?);
+                match name.as_str() {
+                    "type" => key_type = Some(value.as_str().into()),
+                    "cborHex" => cbor_hex = Some(value),
+                    _ => {}
+                }
+                if parser.eat(b'}') {
+                    break;
+                }
+                parser.expect(b',')?;
+            }
+        }
+        parser.skip_whitespace();
+        if parser.pos != parser.bytes.len() {
+            return Err(KesError::InvalidTextEnvelope);
+        }
+
+        Ok(Self {
+            key_type: key_type.ok_or(KesError::InvalidTextEnvelope)?,
+            cbor_hex: cbor_hex.ok_or(KesError::InvalidTextEnvelope)?,
+        })
+    }
+
+    /// The Sum scheme named by a `<prefix><depth>` type tag
+    fn scheme(&self, prefix: &str) -> Result<KesScheme, KesError> {
+        self.key_type
+            .strip_prefix(prefix)
+            .filter(|depth| depth.len() == 1)
+            .and_then(|depth| depth.parse().ok())
+            .and_then(|depth| KesScheme::from_depth(depth, false))
+            .ok_or(KesError::UnsupportedKeyType)
+    }
+
+    /// Decode `cborHex` into the raw key bytes
+    fn key_bytes(&self) -> Result<Zeroizing<Vec<u8>>, KesError> {
+        let cbor =
+            Zeroizing::new(hex::decode(&self.cbor_hex).map_err(|_| KesError::InvalidTextEnvelope)?);
+        cbor::decode_bytes(&cbor)
+            .map(Zeroizing::new)
+            .map_err(|_| KesError::InvalidTextEnvelope)
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.bytes.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), KesError> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(KesError::InvalidTextEnvelope)
+        }
+    }
+
+    fn string(&mut self) -> Result<String, KesError> {
+        self.expect(b'"')?;
+        // Sized up front so a secret `cborHex` is never left behind by a reallocation
+        let mut out = Vec::with_capacity(self.bytes.len() - self.pos);
+        loop {
+            let byte = *self
+                .bytes
+                .get(self.pos)
+                .ok_or(KesError::InvalidTextEnvelope)?;
+            self.pos += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let escaped = *self
+                        .bytes
+                        .get(self.pos)
+                        .ok_or(KesError::InvalidTextEnvelope)?;
+                    self.pos += 1;
+                    let unescaped = match escaped {
+                        b'"' | b'\\' | b'/' => escaped,
+                        b'b' => 0x08,
+                        b'f' => 0x0c,
+                        b'n' => b'\n',
+                        b'r' => b'\r',
+                        b't' => b'\t',
+                        b'u' => {
+                            self.unicode_escape(&mut out)?;
+                            continue;
+                        }
+                        _ => return Err(KesError::InvalidTextEnvelope),
+                    };
+                    out.push(unescaped);
+                }
+                0x00..=0x1f => return Err(KesError::InvalidTextEnvelope),
+                _ => out.push(byte),
+            }
+        }
+        String::from_utf8(out).map_err(|_| KesError::InvalidTextEnvelope)
+    }
+
+    /// Append the character of a `\uXXXX` escape; surrogate pairs are rejected
+    fn unicode_escape(&mut self, out: &mut Vec<u8>) -> Result<(), KesError> {
+        let digits = self
+            .bytes
+            .get(self.pos..self.pos + 4)
+            .ok_or(KesError::InvalidTextEnvelope)?;
+        self.pos += 4;
+        let code = core::str::from_utf8(digits)
+            .ok()
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .and_then(char::from_u32)
+            .ok_or(KesError::InvalidTextEnvelope)?;
+        let mut buf = [0u8; 4];
+        out.extend_from_slice(code.encode_utf8(&mut buf).as_bytes());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kes::{verify_dynamic, Sum6Kes};
+
+    fn envelope(key_type: &str, raw: &[u8]) -> String {
+        format!(
+            "{{\n    \"type\": \"{key_type}\",\n    \"description\": \"KES \\\"Key\\\" \\u00e9\",\n    \"cborHex\": \"{}\"\n}}\n",
+            hex::encode(&cbor::encode_bytes(raw))
+        )
+    }
+
+    #[test]
+    fn sum6_envelopes_roundtrip() {
+        let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[81u8; 32]).unwrap();
+        let vk = Sum6Kes::derive_verification_key(&sk).unwrap();
+
+        let json = envelope("KesVerificationKey_ed25519_kes_2^6", &vk);
+        let (scheme, loaded_vk) = from_text_envelope(&json).unwrap();
+        assert_eq!(scheme, KesScheme::Sum6);
+        assert_eq!(loaded_vk, vk);
+
+        let mut node_sk = Sum6Kes::raw_serialize_signing_key_kes(&sk).to_vec();
+        node_sk.drain(32..64);
+        assert_eq!(node_sk.len(), 608);
+        let json = envelope("KesSigningKey_ed25519_kes_2^6", &node_sk);
+        let loaded_sk = signing_key_from_text_envelope::<Sum6Kes>(&json).unwrap();
+        let sig = Sum6Kes::sign_kes(&(), 0, b"opcert", &loaded_sk).unwrap();
+        verify_dynamic(
+            scheme,
+            &loaded_vk,
+            0,
+            b"opcert",
+            &Sum6Kes::raw_serialize_signature_kes(&sig),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn to_text_envelope_tags_depth_and_rejects_compact() {
+        let vk = Sum6Kes::verification_key_from_seed(&[82u8; 32]).unwrap();
+        for depth in 1..8 {
+            let scheme = KesScheme::from_depth(depth, false).unwrap();
+            let json = to_text_envelope(scheme, &vk).unwrap();
+            assert!(json.contains(&format!("\"KesVerificationKey_ed25519_kes_2^{depth}\"")));
+            assert_eq!(from_text_envelope(&json).unwrap(), (scheme, vk.clone()));
+        }
+        assert_eq!(
+            to_text_envelope(KesScheme::CompactSum6, &vk),
+            Err(KesError::UnsupportedKeyType)
+        );
+        assert_eq!(
+            to_text_envelope(KesScheme::Sum6, &vk[1..]),
+            Err(KesError::WrongLength {
+                expected: 32,
+                actual: 31
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_bad_envelopes() {
+        let vk = [7u8; 32];
+        assert_eq!(
+            from_text_envelope(&envelope("KesVerificationKey_ed25519_kes_2^8", &vk)),
+            Err(KesError::UnsupportedKeyType)
+        );
+        assert_eq!(
+            from_text_envelope(&envelope("VrfVerificationKey_PraosVRF", &vk)),
+            Err(KesError::UnsupportedKeyType)
+        );
+        assert_eq!(
+            from_text_envelope(&envelope("KesVerificationKey_ed25519_kes_2^6", &vk[1..])),
+            Err(KesError::WrongLength {
+                expected: 32,
+                actual: 31
+            })
+        );
+        assert_eq!(
+            signing_key_from_text_envelope::<Sum6Kes>(&envelope(
+                "KesSigningKey_ed25519_kes_2^5",
+                &[0u8; 480]
+            ))
+            .map(|_| ()),
+            Err(KesError::UnsupportedKeyType)
+        );
+        // A verification key envelope is not a signing key
+        assert_eq!(
+            signing_key_from_text_envelope::<Sum6Kes>(&envelope(
+                "KesVerificationKey_ed25519_kes_2^6",
+                &vk
+            ))
+            .map(|_| ()),
+            Err(KesError::UnsupportedKeyType)
+        );
+
+        for json in [
+            "",
+            "{",
+            r#"{"type": "KesVerificationKey_ed25519_kes_2^6"}"#,
+            r#"{"type": "KesVerificationKey_ed25519_kes_2^6", "cborHex": "58zz"}"#,
+            r#"{"type": "KesVerificationKey_ed25519_kes_2^6", "cborHex": 5820}"#,
+            r#"{"type": "KesVerificationKey_ed25519_kes_2^6", "cborHex": "5820"} trailing"#,
+        ] {
+            assert_eq!(
+                from_text_envelope(json),
+                Err(KesError::InvalidTextEnvelope),
+                "{json}"
+            );
+        }
+    }
+}
@@ -0,0 +1,363 @@
+//! Epoch-level verification summaries
+//!
+//! A chain analyser usually wants more than a yes/no answer when checking the
+//! signatures an operator produced over an epoch: which periods were covered,
+//! whether any period was signed twice, and whether periods were skipped.
+//! [`EpochReport`] collects that information on top of
+//! [`KesAlgorithm::verify_kes`](crate::kes::KesAlgorithm::verify_kes).
+//!
+//! [`EpochVerifier`] checks a pool's signatures one after another and remembers
+//! the Sum tree nodes it has already hashed, since consecutive periods share
+//! most of their Merkle path.
+//!
+//! # Example
+//!
+//! ```rust
+//! use cardano_crypto::kes::{KesAlgorithm, Sum2Kes};
+//!
+//! # fn main() -> cardano_crypto::common::Result<()> {
+//! let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[1u8; 32])?;
+//! let vk = Sum2Kes::derive_verification_key(&sk)?;
+//! let sig = Sum2Kes::sign_kes(&(), 0, b"block", &sk)?;
+//!
+//! let report = Sum2Kes::verify_epoch(&(), &vk, &[(0, &b"block"[..], &sig)]);
+//! assert_eq!(report.valid, 1);
+//! assert!(report.is_clean());
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+
+use crate::common::error::Result;
+use crate::kes::{KesAlgorithm, Period};
+
+/// Summary of verifying a set of signatures from one verification key
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EpochReport {
+    /// Number of entries whose signature verified
+    pub valid: usize,
+    /// Number of entries whose signature failed to verify
+    pub invalid: usize,
+    /// Periods with at least one valid signature
+    pub covered: BTreeSet<Period>,
+    /// Periods that appeared in more than one entry
+    pub duplicates: BTreeSet<Period>,
+    /// Indices (into the input entries) of the signatures that failed
+    pub invalid_indices: Vec<usize>,
+}
+
+impl EpochReport {
+    /// Periods missing between the first and last covered period
+    #[must_use]
+    pub fn gaps(&self) -> Vec<Period> {
+        let (Some(&first), Some(&last)) = (self.covered.first(), self.covered.last()) else {
+            return Vec::new();
+        };
+        (first..=last)
+            .filter(|period| !self.covered.contains(period))
+            .collect()
+    }
+
+    /// Whether any period between the first and last covered period is missing
+    #[must_use]
+    pub fn has_gaps(&self) -> bool {
+        let (Some(&first), Some(&last)) = (self.covered.first(), self.covered.last()) else {
+            return false;
+        };
+        (last - first + 1) as usize != self.covered.len()
+    }
+
+    /// Whether any period was claimed by more than one entry
+    #[must_use]
+    pub fn has_duplicates(&self) -> bool {
+        !self.duplicates.is_empty()
+    }
+
+    /// True when every signature verified, no period repeats and there are no gaps
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.invalid == 0 && !self.has_duplicates() && !self.has_gaps()
+    }
+}
+
+/// Sum tree nodes already checked by an [`EpochVerifier`]
+///
+/// Maps a parent verification key to the `vk0 || vk1` pair it was found to
+/// be the hash of, so later signatures carrying the same pair skip the hash.
+/// The entries only hold for one scheme's hash, which is why a cache is only
+/// ever created by an [`EpochVerifier`] for a single scheme.
+#[derive(Debug, Clone, Default)]
+pub struct BranchCache {
+    nodes: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl BranchCache {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of tree nodes remembered
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether no tree node has been remembered yet
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Whether `parent` is already known to be the hash of `vk0 || vk1`
+    pub(crate) fn contains(&self, parent: &[u8], vk0: &[u8], vk1: &[u8]) -> bool {
+        self.nodes.get(parent).is_some_and(|children| {
+            children.len() == vk0.len() + vk1.len()
+                && children[..vk0.len()] == *vk0
+                && children[vk0.len()..] == *vk1
+        })
+    }
+
+    /// Record that `parent` is the hash of `vk0 || vk1`
+    pub(crate) fn insert(&mut self, parent: &[u8], vk0: &[u8], vk1: &[u8]) {
+        let mut children = Vec::with_capacity(vk0.len() + vk1.len());
+        children.extend_from_slice(vk0);
+        children.extend_from_slice(vk1);
+        self.nodes.insert(parent.to_vec(), children);
+    }
+}
+
+/// Verifies a sequence of signatures from one verification key, sharing work
+///
+/// Each Sum level hashes the pair of subtree keys carried by the signature;
+/// for a run of consecutive periods those pairs repeat, so the verifier
+/// hashes each distinct node once. Leaf signatures are always checked.
+/// CompactSum signatures rebuild their path bottom-up and are verified as
+/// with [`verify_kes`](KesAlgorithm::verify_kes).
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::kes::{EpochVerifier, KesAlgorithm, Sum3Kes};
+///
+/// # fn main() -> cardano_crypto::common::Result<()> {
+/// let mut sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[2u8; 32])?;
+/// let vk = Sum3Kes::derive_verification_key(&sk)?;
+/// let mut signatures = Vec::new();
+/// for period in 0..3 {
+///     signatures.push(Sum3Kes::sign_kes(&(), period, b"block", &sk)?);
+///     sk = Sum3Kes::update_kes(&(), sk, period)?.unwrap();
+/// }
+///
+/// let mut verifier = EpochVerifier::<Sum3Kes>::new(&(), &vk);
+/// let entries = signatures
+///     .iter()
+///     .enumerate()
+///     .map(|(period, sig)| (period as u64, &b"block"[..], sig));
+/// assert_eq!(verifier.verify_sequence(entries), Ok(()));
+/// assert_eq!(
+///     verifier.verify_sequence([(1, &b"forged"[..], &signatures[1])]),
+///     Err(1)
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub struct EpochVerifier<'a, K: KesAlgorithm> {
+    context: &'a K::Context,
+    verification_key: &'a K::VerificationKey,
+    cache: BranchCache,
+}
+
+impl<K: KesAlgorithm> core::fmt::Debug for EpochVerifier<'_, K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EpochVerifier")
+            .field("algorithm", &K::ALGORITHM_NAME)
+            .field("cache", &self.cache)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, K: KesAlgorithm> EpochVerifier<'a, K> {
+    /// Start verifying signatures made under `verification_key`
+    pub fn new(context: &'a K::Context, verification_key: &'a K::VerificationKey) -> Self {
+        Self {
+            context,
+            verification_key,
+            cache: BranchCache::new(),
+        }
+    }
+
+    /// Verify one signature, reusing the nodes checked so far
+    pub fn verify(
+        &mut self,
+        period: Period,
+        message: &[u8],
+        signature: &K::Signature,
+    ) -> Result<()> {
+        K::verify_kes_cached(
+            self.context,
+            self.verification_key,
+            period,
+            message,
+            signature,
+            &mut self.cache,
+        )
+    }
+
+    /// Verify entries in order, stopping at the first one that fails
+    ///
+    /// Returns the period of the first failing entry.
+    pub fn verify_sequence<'m, I>(&mut self, entries: I) -> core::result::Result<(), Period>
+    where
+        I: IntoIterator<Item = (Period, &'m [u8], &'m K::Signature)>,
+        K::Signature: 'm,
+    {
+        for (period, message, signature) in entries {
+            self.verify(period, message, signature)
+                .map_err(|_| period)?;
+        }
+        Ok(())
+    }
+
+    /// Tree nodes remembered so far
+    #[must_use]
+    pub fn cache(&self) -> &BranchCache {
+        &self.cache
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kes::{CompactSum3Kes, Sum3Kes, Sum6Kes};
+
+    #[test]
+    fn report_counts_invalid_and_duplicate_entries() {
+        let seed = [21u8; 32];
+        let mut sk = Sum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = Sum3Kes::derive_verification_key(&sk).unwrap();
+
+        let mut signatures = Vec::new();
+        for period in 0..4 {
+            let sig = Sum3Kes::sign_kes(&(), period, b"block", &sk).unwrap();
+            signatures.push(sig);
+            sk = Sum3Kes::update_kes(&(), sk, period).unwrap().unwrap();
+        }
+
+        let entries = [
+            (0, &b"block"[..], &signatures[0]),
+            (1, &b"block"[..], &signatures[1]),
+            // Wrong message for period 3: must not verify
+            (3, &b"forged"[..], &signatures[3]),
+            // Period 1 claimed again
+            (1, &b"block"[..], &signatures[1]),
+            // Skip period 2 so a gap appears before period 3
+            (3, &b"block"[..], &signatures[3]),
+        ];
+
+        let report = Sum3Kes::verify_epoch(&(), &vk, &entries);
+        assert_eq!(report.valid, 4);
+        assert_eq!(report.invalid, 1);
+        assert_eq!(report.invalid_indices, vec![2]);
+        assert_eq!(
+            report.covered.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 3]
+        );
+        assert_eq!(
+            report.duplicates.iter().copied().collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+        assert!(report.has_duplicates());
+        assert!(report.has_gaps());
+        assert_eq!(report.gaps(), vec![2]);
+        assert!(!report.is_clean());
+    }
+
+    #[test]
+    fn empty_report_is_clean() {
+        let seed = [22u8; 32];
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = Sum3Kes::derive_verification_key(&sk).unwrap();
+
+        let report = Sum3Kes::verify_epoch(&(), &vk, &[]);
+        assert_eq!(report.valid, 0);
+        assert!(report.gaps().is_empty());
+        assert!(report.is_clean());
+    }
+
+    fn sign_every_period<K: KesAlgorithm<Context = ()>>(
+        seed: &[u8],
+    ) -> (K::VerificationKey, Vec<K::Signature>) {
+        let mut sk = K::gen_key_kes_from_seed_bytes(seed).unwrap();
+        let vk = K::derive_verification_key(&sk).unwrap();
+        let mut signatures = Vec::new();
+        for period in 0..K::total_periods() {
+            signatures.push(K::sign_kes(&(), period, b"block", &sk).unwrap());
+            match K::update_kes(&(), sk, period).unwrap() {
+                Some(next) => sk = next,
+                None => break,
+            }
+        }
+        (vk, signatures)
+    }
+
+    #[test]
+    fn epoch_verifier_hashes_each_node_once() {
+        let (vk, signatures) = sign_every_period::<Sum3Kes>(&[23u8; 32]);
+        let mut verifier = EpochVerifier::<Sum3Kes>::new(&(), &vk);
+        let entries = signatures
+            .iter()
+            .enumerate()
+            .map(|(period, sig)| (period as Period, &b"block"[..], sig));
+        assert_eq!(verifier.verify_sequence(entries), Ok(()));
+        // A full depth-3 tree has 7 internal nodes
+        assert_eq!(verifier.cache().len(), 7);
+
+        assert!(verifier.verify(4, b"forged", &signatures[4]).is_err());
+        assert!(verifier.verify(5, b"block", &signatures[4]).is_err());
+        assert_eq!(
+            verifier.verify_sequence([
+                (6, &b"block"[..], &signatures[6]),
+                (7, &b"forged"[..], &signatures[7]),
+            ]),
+            Err(7)
+        );
+    }
+
+    #[test]
+    fn epoch_verifier_rejects_altered_subtree_key_after_caching() {
+        let (vk, signatures) = sign_every_period::<Sum3Kes>(&[24u8; 32]);
+        let mut verifier = EpochVerifier::<Sum3Kes>::new(&(), &vk);
+        verifier.verify(0, b"block", &signatures[0]).unwrap();
+
+        // Swap in the right subtree of a different key: the cached root pair
+        // no longer matches, so the hash is checked and fails
+        let (_, other) = sign_every_period::<Sum3Kes>(&[25u8; 32]);
+        let mut bytes = Sum3Kes::raw_serialize_signature_kes(&signatures[4]);
+        let other_bytes = Sum3Kes::raw_serialize_signature_kes(&other[4]);
+        let vk1_offset = bytes.len() - 32;
+        bytes[vk1_offset..].copy_from_slice(&other_bytes[vk1_offset..]);
+        let forged = Sum3Kes::raw_deserialize_signature_kes(&bytes).unwrap();
+        assert!(verifier.verify(4, b"block", &forged).is_err());
+        verifier.verify(4, b"block", &signatures[4]).unwrap();
+    }
+
+    #[test]
+    fn epoch_verifier_matches_verify_kes_for_compact_and_large_trees() {
+        let (vk, signatures) = sign_every_period::<CompactSum3Kes>(&[26u8; 32]);
+        let mut verifier = EpochVerifier::<CompactSum3Kes>::new(&(), &vk);
+        for (period, sig) in signatures.iter().enumerate() {
+            verifier.verify(period as Period, b"block", sig).unwrap();
+        }
+        assert!(verifier.cache().is_empty());
+
+        let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[27u8; 32]).unwrap();
+        let vk = Sum6Kes::derive_verification_key(&sk).unwrap();
+        let sig = Sum6Kes::sign_kes(&(), 0, b"block", &sk).unwrap();
+        let mut verifier = EpochVerifier::<Sum6Kes>::new(&(), &vk);
+        verifier.verify(0, b"block", &sig).unwrap();
+        verifier.verify(0, b"block", &sig).unwrap();
+        assert_eq!(verifier.cache().len(), 6);
+    }
+}
@@ -5,12 +5,40 @@
 //! - Hashing verification keys in Sum/CompactSum compositions
 //! - Seed expansion for subtree generation
 //!
-//! All implementations use Blake2b variants matching Cardano's conventions.
+//! Cardano uses Blake2b-256; SHA-256 and SHA-512 are provided for interop
+//! experiments and deployments outside Cardano.
+//!
+//! # Compatibility with Sum trees
+//!
+//! Each Sum level expands its seed into two seeds of the hash's
+//! `OUTPUT_SIZE`, and those must be exactly the seed size of the subtree
+//! scheme. Every tree in this crate ends in Ed25519 leaves with 32-byte
+//! seeds, so:
+//!
+//! | Hash                     | Output | `SumKes` / `CompactSumKes` over Ed25519 |
+//! |--------------------------|--------|-----------------------------------------|
+//! | [`Blake2b224`]           | 28     | no                                      |
+//! | [`Blake2b256`]           | 32     | yes (Cardano)                           |
+//! | [`Blake2b512`]           | 64     | no                                      |
+//! | [`Sha256`]               | 32     | yes                                     |
+//! | [`Sha512`]               | 64     | no                                      |
+//! | [`LegacySeedExpansion`]  | as `H` | as `H`                                  |
+//!
+//! Key generation for an incompatible combination fails with
+//! [`KesError::InvalidSeedExpansion`] before any subtree is built. The
+//! other hashes remain usable for hashing on their own.
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+use core::marker::PhantomData;
+
+use digest::consts::{U28, U32};
+use digest::Digest;
+use subtle::{Choice, ConditionallySelectable};
+
 use crate::hash::HashAlgorithm;
+use crate::kes::{output_prefix, KesError};
 
 /// Trait for hash algorithms used in KES schemes
 ///
@@ -23,9 +51,41 @@ pub trait KesHashAlgorithm: Clone + Send + Sync + 'static {
     /// The name of the hash algorithm (for debugging)
     const ALGORITHM_NAME: &'static str;
 
+    /// Domain separation used by [`expand_seed`](Self::expand_seed)
+    ///
+    /// [`SeedExpansion::Current`] for every built-in hash; wrap a hash in
+    /// [`LegacySeedExpansion`] to derive keys as cardano-crypto 1.0.x did.
+    const SEED_EXPANSION: SeedExpansion = SeedExpansion::Current;
+
     /// Hash arbitrary data and return a fixed-size output
     fn hash(data: &[u8]) -> Vec<u8>;
 
+    /// Hash `data` into the front of `out`
+    ///
+    /// Writes exactly [`OUTPUT_SIZE`](Self::OUTPUT_SIZE) bytes and returns
+    /// that count, leaving the rest of `out` untouched. The built-in
+    /// algorithms write the digest without allocating; the default copies
+    /// it from [`hash`](Self::hash).
+    ///
+    /// # Errors
+    ///
+    /// [`KesError::BufferTooSmall`] if `out` is shorter than `OUTPUT_SIZE`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{Blake2b256, KesHashAlgorithm};
+    ///
+    /// let mut out = [0u8; 32];
+    /// assert_eq!(Blake2b256::hash_into(b"data", &mut out), Ok(32));
+    /// assert_eq!(out.to_vec(), Blake2b256::hash(b"data"));
+    /// assert!(Blake2b256::hash_into(b"data", &mut out[..31]).is_err());
+    /// ```
+    fn hash_into(data: &[u8], out: &mut [u8]) -> Result<usize, KesError> {
+        output_prefix(out, Self::OUTPUT_SIZE)?.copy_from_slice(&Self::hash(data));
+        Ok(Self::OUTPUT_SIZE)
+    }
+
     /// Hash two pieces of data concatenated together
     ///
     /// Default implementation concatenates then hashes, but can be overridden for efficiency.
@@ -48,24 +108,137 @@ pub trait KesHashAlgorithm: Clone + Send + Sync + 'static {
         Self::hash(&combined)
     }
 
+    /// Hash two pieces of data concatenated together into `out`
+    ///
+    /// The built-in algorithms feed both parts to the hasher in turn and
+    /// write the digest straight into `out`, so nothing is allocated. The
+    /// default falls back to [`hash_concat`](Self::hash_concat).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out.len()` is not [`OUTPUT_SIZE`](Self::OUTPUT_SIZE).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{Blake2b256, KesHashAlgorithm};
+    ///
+    /// let mut out = [0u8; 32];
+    /// Blake2b256::hash_concat_into(b"part1", b"part2", &mut out);
+    /// assert_eq!(out.to_vec(), Blake2b256::hash_concat(b"part1", b"part2"));
+    /// ```
+    fn hash_concat_into(data1: &[u8], data2: &[u8], out: &mut [u8]) {
+        out.copy_from_slice(&Self::hash_concat(data1, data2));
+    }
+
     /// Expand a seed into two seeds using the hash algorithm
     ///
     /// This is used for seed expansion in Sum/CompactSum compositions.
-    /// Returns (left_seed, right_seed) for the two subtrees.
+    /// Returns (left_seed, right_seed) for the two subtrees, derived as
+    /// `H(0x01 || seed)` and `H(0x02 || seed)` like `expandHashWith` in
+    /// cardano-base, or with the prefixes of
+    /// [`SEED_EXPANSION`](Self::SEED_EXPANSION) if it is not
+    /// [`SeedExpansion::Current`].
     #[must_use]
     fn expand_seed(seed: &[u8]) -> (Vec<u8>, Vec<u8>) {
-        // Hash with different prefixes to derive independent seeds
-        let mut left_input = Vec::with_capacity(seed.len() + 1);
-        left_input.push(0x00);
-        left_input.extend_from_slice(seed);
-        let left_seed = Self::hash(&left_input);
+        let (left, right) = Self::SEED_EXPANSION.prefixes();
+        Self::expand_seed_with_prefix(seed, &[left], &[right])
+    }
+
+    /// Expand a seed into two seeds using custom domain-separation prefixes
+    ///
+    /// Returns `(H(left_prefix || seed), H(right_prefix || seed))`. Only
+    /// custom constructions need this; Cardano-compatible trees use
+    /// [`expand_seed`](Self::expand_seed).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{Blake2b256, KesHashAlgorithm};
+    ///
+    /// let seed = [5u8; 32];
+    /// assert_eq!(
+    ///     Blake2b256::expand_seed_with_prefix(&seed, &[0x01], &[0x02]),
+    ///     Blake2b256::expand_seed(&seed)
+    /// );
+    /// let (left, right) = Blake2b256::expand_seed_with_prefix(&seed, b"my-app/L", b"my-app/R");
+    /// assert_ne!(left, right);
+    /// ```
+    #[must_use]
+    fn expand_seed_with_prefix(
+        seed: &[u8],
+        left_prefix: &[u8],
+        right_prefix: &[u8],
+    ) -> (Vec<u8>, Vec<u8>) {
+        // The inputs hold a copy of the seed, so wipe them before freeing
+        let prefixed = |prefix: &[u8]| {
+            let mut input = zeroize::Zeroizing::new(Vec::with_capacity(prefix.len() + seed.len()));
+            input.extend_from_slice(prefix);
+            input.extend_from_slice(seed);
+            Self::hash(&input)
+        };
+        (prefixed(left_prefix), prefixed(right_prefix))
+    }
+}
+
+/// Domain separation of the two subtree seeds in Sum key generation
+///
+/// The Sum and CompactSum constructions derive the seeds of their left and
+/// right subtrees by hashing a one-byte prefix and the parent seed. Changing
+/// the prefixes changes every key below the root, so a key can only be
+/// regenerated from its seed with the expansion it was created with.
+///
+/// The expansion is selected through the hash parameter, so key generation
+/// and the subtree regeneration in `update_kes` always agree, including for
+/// keys restored from raw bytes:
+///
+/// ```rust
+/// use cardano_crypto::kes::{
+///     Blake2b256, KesAlgorithm, KesHashAlgorithm, LegacySeedExpansion, SeedExpansion,
+///     Sum6Kes, Sum6KesWith,
+/// };
+///
+/// type LegacySum6Kes = Sum6KesWith<LegacySeedExpansion<Blake2b256>>;
+///
+/// # fn main() -> cardano_crypto::common::Result<()> {
+/// assert_eq!(LegacySeedExpansion::<Blake2b256>::SEED_EXPANSION, SeedExpansion::Legacy);
+///
+/// let seed = [9u8; 32];
+/// let current = Sum6Kes::derive_verification_key(&Sum6Kes::gen_key_kes_from_seed_bytes(&seed)?)?;
+/// let legacy =
+///     LegacySum6Kes::derive_verification_key(&LegacySum6Kes::gen_key_kes_from_seed_bytes(&seed)?)?;
+/// assert_ne!(current, legacy);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SeedExpansion {
+    /// `H(0x01 || seed)` and `H(0x02 || seed)`, as `expandHashWith` in
+    /// cardano-base and `Seed::split` in kes-summed-ed25519
+    #[default]
+    Current,
+    /// `H(0x00 || seed)` and `H(0x01 || seed)`, the prefixes of
+    /// cardano-crypto 1.0.x; no cardano-base release derives keys this way
+    Legacy,
+}
 
-        let mut right_input = Vec::with_capacity(seed.len() + 1);
-        right_input.push(0x01);
-        right_input.extend_from_slice(seed);
-        let right_seed = Self::hash(&right_input);
+impl SeedExpansion {
+    /// Prefix bytes hashed in front of the seed for the left and right subtree
+    #[must_use]
+    pub const fn prefixes(self) -> (u8, u8) {
+        match self {
+            Self::Current => (0x01, 0x02),
+            Self::Legacy => (0x00, 0x01),
+        }
+    }
 
-        (left_seed, right_seed)
+    /// Expand `seed` into the left and right subtree seeds with the hash `H`
+    ///
+    /// Ignores `H`'s own [`SEED_EXPANSION`](KesHashAlgorithm::SEED_EXPANSION).
+    #[must_use]
+    pub fn expand<H: KesHashAlgorithm>(self, seed: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let (left, right) = self.prefixes();
+        H::expand_seed_with_prefix(seed, &[left], &[right])
     }
 }
 
@@ -80,6 +253,22 @@ impl KesHashAlgorithm for Blake2b224 {
     fn hash(data: &[u8]) -> Vec<u8> {
         crate::hash::Blake2b224::hash(data).to_vec()
     }
+
+    fn hash_concat(data1: &[u8], data2: &[u8]) -> Vec<u8> {
+        Blake2b224Digest::new()
+            .chain_update(data1)
+            .chain_update(data2)
+            .finalize()
+            .to_vec()
+    }
+
+    fn hash_into(data: &[u8], out: &mut [u8]) -> Result<usize, KesError> {
+        digest_into::<Blake2b224Digest>(data, out)
+    }
+
+    fn hash_concat_into(data1: &[u8], data2: &[u8], out: &mut [u8]) {
+        digest_concat_into::<Blake2b224Digest>(data1, data2, out);
+    }
 }
 
 /// Blake2b-256 for KES (32-byte output)
@@ -95,6 +284,22 @@ impl KesHashAlgorithm for Blake2b256 {
     fn hash(data: &[u8]) -> Vec<u8> {
         crate::hash::Blake2b256::hash(data).to_vec()
     }
+
+    fn hash_concat(data1: &[u8], data2: &[u8]) -> Vec<u8> {
+        Blake2b256Digest::new()
+            .chain_update(data1)
+            .chain_update(data2)
+            .finalize()
+            .to_vec()
+    }
+
+    fn hash_into(data: &[u8], out: &mut [u8]) -> Result<usize, KesError> {
+        digest_into::<Blake2b256Digest>(data, out)
+    }
+
+    fn hash_concat_into(data1: &[u8], data2: &[u8], out: &mut [u8]) {
+        digest_concat_into::<Blake2b256Digest>(data1, data2, out);
+    }
 }
 
 /// Blake2b-512 for KES (64-byte output)
@@ -108,6 +313,237 @@ impl KesHashAlgorithm for Blake2b512 {
     fn hash(data: &[u8]) -> Vec<u8> {
         crate::hash::Blake2b512::hash(data).to_vec()
     }
+
+    fn hash_concat(data1: &[u8], data2: &[u8]) -> Vec<u8> {
+        blake2::Blake2b512::new()
+            .chain_update(data1)
+            .chain_update(data2)
+            .finalize()
+            .to_vec()
+    }
+
+    fn hash_into(data: &[u8], out: &mut [u8]) -> Result<usize, KesError> {
+        digest_into::<blake2::Blake2b512>(data, out)
+    }
+
+    fn hash_concat_into(data1: &[u8], data2: &[u8], out: &mut [u8]) {
+        digest_concat_into::<blake2::Blake2b512>(data1, data2, out);
+    }
+}
+
+/// SHA-256 for KES (32-byte output)
+///
+/// Not used by Cardano; available for `SumKes<_, Sha256>` in other deployments.
+#[derive(Clone, Debug)]
+pub struct Sha256;
+
+impl KesHashAlgorithm for Sha256 {
+    const OUTPUT_SIZE: usize = 32;
+    const ALGORITHM_NAME: &'static str = "SHA-256";
+
+    fn hash(data: &[u8]) -> Vec<u8> {
+        crate::hash::sha256(data).to_vec()
+    }
+
+    fn hash_concat(data1: &[u8], data2: &[u8]) -> Vec<u8> {
+        sha2::Sha256::new()
+            .chain_update(data1)
+            .chain_update(data2)
+            .finalize()
+            .to_vec()
+    }
+
+    fn hash_into(data: &[u8], out: &mut [u8]) -> Result<usize, KesError> {
+        digest_into::<sha2::Sha256>(data, out)
+    }
+
+    fn hash_concat_into(data1: &[u8], data2: &[u8], out: &mut [u8]) {
+        digest_concat_into::<sha2::Sha256>(data1, data2, out);
+    }
+}
+
+/// SHA-512 for KES (64-byte output)
+///
+/// Not used by Cardano. Its 64-byte expanded seeds are longer than an
+/// Ed25519 leaf seed, so Ed25519-based Sum trees need a 32-byte hash.
+#[derive(Clone, Debug)]
+pub struct Sha512;
+
+impl KesHashAlgorithm for Sha512 {
+    const OUTPUT_SIZE: usize = 64;
+    const ALGORITHM_NAME: &'static str = "SHA-512";
+
+    fn hash(data: &[u8]) -> Vec<u8> {
+        crate::hash::sha512(data).to_vec()
+    }
+
+    fn hash_concat(data1: &[u8], data2: &[u8]) -> Vec<u8> {
+        sha2::Sha512::new()
+            .chain_update(data1)
+            .chain_update(data2)
+            .finalize()
+            .to_vec()
+    }
+
+    fn hash_into(data: &[u8], out: &mut [u8]) -> Result<usize, KesError> {
+        digest_into::<sha2::Sha512>(data, out)
+    }
+
+    fn hash_concat_into(data1: &[u8], data2: &[u8], out: &mut [u8]) {
+        digest_concat_into::<sha2::Sha512>(data1, data2, out);
+    }
+}
+
+/// A [`KesHashAlgorithm`] with a RustCrypto [`Digest`] hasher behind it
+///
+/// [`Digest`](Self::Digest) computes the same bytes as
+/// [`KesHashAlgorithm::hash`], so code generic over `digest::Digest`
+/// (version 0.10) can hash like a KES tree does. The KES constructions
+/// themselves only use [`KesHashAlgorithm`].
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::kes::{Blake2b256, KesDigest, KesHashAlgorithm};
+/// use digest::Digest;
+///
+/// let mut hasher = <Blake2b256 as KesDigest>::Digest::new();
+/// hasher.update(b"left");
+/// hasher.update(b"right");
+/// assert_eq!(hasher.finalize().to_vec(), Blake2b256::hash_concat(b"left", b"right"));
+/// ```
+pub trait KesDigest: KesHashAlgorithm {
+    /// Incremental hasher with [`OUTPUT_SIZE`](KesHashAlgorithm::OUTPUT_SIZE) bytes of output
+    type Digest: Digest + Clone + Default;
+}
+
+impl KesDigest for Blake2b224 {
+    type Digest = Blake2b224Digest;
+}
+
+impl KesDigest for Blake2b256 {
+    type Digest = Blake2b256Digest;
+}
+
+impl KesDigest for Blake2b512 {
+    type Digest = blake2::Blake2b512;
+}
+
+impl KesDigest for Sha256 {
+    type Digest = sha2::Sha256;
+}
+
+impl KesDigest for Sha512 {
+    type Digest = sha2::Sha512;
+}
+
+/// Seed expansion never goes through the hasher, so `H`'s is used unchanged
+impl<H: KesDigest> KesDigest for LegacySeedExpansion<H> {
+    type Digest = H::Digest;
+}
+
+/// The hash `H` with [`SeedExpansion::Legacy`] seed expansion
+///
+/// Hashes exactly like `H`, so verification keys and signatures have the
+/// same format; only the subtree seeds, and with them every derived key,
+/// differ. Use it as the hash of a Sum or CompactSum alias, e.g.
+/// `Sum6KesWith<LegacySeedExpansion<Blake2b256>>`, to regenerate keys
+/// created by cardano-crypto 1.0.x. Such keys are not the ones cardano-node
+/// derives from the same seed.
+pub struct LegacySeedExpansion<H = Blake2b256>(PhantomData<fn() -> H>);
+
+impl<H> Clone for LegacySeedExpansion<H> {
+    fn clone(&self) -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<H: KesHashAlgorithm> core::fmt::Debug for LegacySeedExpansion<H> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "LegacySeedExpansion<{}>", H::ALGORITHM_NAME)
+    }
+}
+
+impl<H: KesHashAlgorithm> KesHashAlgorithm for LegacySeedExpansion<H> {
+    const OUTPUT_SIZE: usize = H::OUTPUT_SIZE;
+    const ALGORITHM_NAME: &'static str = H::ALGORITHM_NAME;
+    const SEED_EXPANSION: SeedExpansion = SeedExpansion::Legacy;
+
+    fn hash(data: &[u8]) -> Vec<u8> {
+        H::hash(data)
+    }
+
+    fn hash_concat(data1: &[u8], data2: &[u8]) -> Vec<u8> {
+        H::hash_concat(data1, data2)
+    }
+
+    fn hash_into(data: &[u8], out: &mut [u8]) -> Result<usize, KesError> {
+        H::hash_into(data, out)
+    }
+
+    fn hash_concat_into(data1: &[u8], data2: &[u8], out: &mut [u8]) {
+        H::hash_concat_into(data1, data2, out);
+    }
+}
+
+type Blake2b224Digest = blake2::Blake2b<U28>;
+type Blake2b256Digest = blake2::Blake2b<U32>;
+
+/// Hash `data` with a fresh `D` into the front of `out`
+fn digest_into<D: Digest>(data: &[u8], out: &mut [u8]) -> Result<usize, KesError> {
+    let size = <D as Digest>::output_size();
+    output_prefix(out, size)?.copy_from_slice(&D::digest(data));
+    Ok(size)
+}
+
+/// Feed both parts to a fresh `D` and write the digest into `out`
+fn digest_concat_into<D: Digest>(data1: &[u8], data2: &[u8], out: &mut [u8]) {
+    out.copy_from_slice(&D::new().chain_update(data1).chain_update(data2).finalize());
+}
+
+/// Order an active and an inactive child key as `(left, right)`
+///
+/// Swaps the two byte strings in constant time when `active_is_right` is set,
+/// so the order they are hashed in does not show up as a branch on the
+/// period. Both are serializations of the same subtree key type and have the
+/// same length; should they not, the public lengths are swapped as a whole.
+pub(crate) fn order_children(
+    mut active: Vec<u8>,
+    mut other: Vec<u8>,
+    active_is_right: Choice,
+) -> (Vec<u8>, Vec<u8>) {
+    if active.len() == other.len() {
+        for (a, b) in active.iter_mut().zip(other.iter_mut()) {
+            u8::conditional_swap(a, b, active_is_right);
+        }
+        (active, other)
+    } else if bool::from(active_is_right) {
+        (other, active)
+    } else {
+        (active, other)
+    }
+}
+
+/// Check `H(data1 || data2) == expected` in constant time without allocating
+///
+/// Digests of up to 64 bytes are computed on the stack; larger ones fall
+/// back to [`KesHashAlgorithm::hash_concat`].
+pub(crate) fn hash_concat_matches<H: KesHashAlgorithm>(
+    data1: &[u8],
+    data2: &[u8],
+    expected: &[u8],
+) -> bool {
+    if expected.len() != H::OUTPUT_SIZE {
+        return false;
+    }
+    let mut buf = [0u8; 64];
+    match buf.get_mut(..H::OUTPUT_SIZE) {
+        Some(out) => {
+            H::hash_concat_into(data1, data2, out);
+            crate::hash::constant_time_eq(out, expected)
+        }
+        None => crate::hash::constant_time_eq(&H::hash_concat(data1, data2), expected),
+    }
 }
 
 #[cfg(test)]
@@ -204,4 +640,140 @@ mod tests {
         assert_ne!(hash224.len(), hash256.len());
         assert_ne!(hash256.len(), hash512.len());
     }
+
+    #[test]
+    fn sha2_seed_expansion() {
+        let seed = b"master-seed-for-kes-tree";
+
+        let (left, right) = Sha256::expand_seed(seed);
+        assert_eq!(left.len(), Sha256::OUTPUT_SIZE);
+        assert_eq!(right.len(), Sha256::OUTPUT_SIZE);
+        assert_ne!(left, right);
+        assert_ne!(left, Blake2b256::expand_seed(seed).0);
+
+        let (left, right) = Sha512::expand_seed(seed);
+        assert_eq!(left.len(), Sha512::OUTPUT_SIZE);
+        assert_eq!(right.len(), Sha512::OUTPUT_SIZE);
+        assert_ne!(left, right);
+        assert_eq!((left, right), Sha512::expand_seed(seed));
+    }
+
+    #[test]
+    fn legacy_expansion_uses_prefixes_zero_and_one() {
+        let seed = [0x42u8; 32];
+
+        let (left, right) = LegacySeedExpansion::<Blake2b256>::expand_seed(&seed);
+        assert_eq!(left, Blake2b256::hash_concat(&[0x00], &seed));
+        assert_eq!(right, Blake2b256::hash_concat(&[0x01], &seed));
+        assert_eq!(
+            (left, right),
+            SeedExpansion::Legacy.expand::<Blake2b256>(&seed)
+        );
+        assert_eq!(
+            SeedExpansion::Current.expand::<Blake2b256>(&seed),
+            Blake2b256::expand_seed(&seed)
+        );
+
+        // Hashing itself is unchanged
+        assert_eq!(
+            LegacySeedExpansion::<Blake2b256>::hash_concat(b"a", b"b"),
+            Blake2b256::hash_concat(b"a", b"b")
+        );
+    }
+
+    #[test]
+    fn expand_seed_defaults_to_prefixes_one_and_two() {
+        let seed = [0x42u8; 32];
+
+        let mut left_input = vec![0x01];
+        left_input.extend_from_slice(&seed);
+        let mut right_input = vec![0x02];
+        right_input.extend_from_slice(&seed);
+
+        let (left, right) = Blake2b256::expand_seed(&seed);
+        assert_eq!(left, Blake2b256::hash(&left_input));
+        assert_eq!(right, Blake2b256::hash(&right_input));
+
+        let (custom_left, custom_right) =
+            Blake2b256::expand_seed_with_prefix(&seed, b"left", b"right");
+        assert_ne!(custom_left, left);
+        assert_ne!(custom_right, right);
+        assert_eq!(custom_left, Blake2b256::hash_concat(b"left", &seed));
+    }
+
+    #[test]
+    fn expand_seed_matches_cardano_base() {
+        // Blake2b-256 of 0x01 / 0x02 followed by the seed, computed with
+        // Python's hashlib rather than this crate
+        let seed = [0x42u8; 32];
+        let (left, right) = Blake2b256::expand_seed(&seed);
+        assert_eq!(
+            crate::common::hex::encode(&left),
+            "323619c4c3d055b64ac10a12c87523c70bf911e5609eafca5e7c834b7d8a0a9d"
+        );
+        assert_eq!(
+            crate::common::hex::encode(&right),
+            "cbaf2ba4ea3e8d37a75e7ccd5f6a692174e25174db34de9e0344393c53b3da28"
+        );
+    }
+
+    typed_tests! {
+        fn digest_api_matches_kes_hash<H>() for {
+            blake2b224: Blake2b224,
+            blake2b256: Blake2b256,
+            blake2b512: Blake2b512,
+            sha256: Sha256,
+            sha512: Sha512,
+            legacy_blake2b256: LegacySeedExpansion<Blake2b256>,
+        } {
+            type D = <H as KesDigest>::Digest;
+            let data = b"verification key bytes";
+            assert_eq!(<D as Digest>::output_size(), H::OUTPUT_SIZE);
+            assert_eq!(D::digest(data).to_vec(), H::hash(data));
+
+            let mut hasher = D::new();
+            hasher.update(&data[..5]);
+            let fork = hasher.clone();
+            hasher.update(&data[5..]);
+            assert_eq!(hasher.finalize().to_vec(), H::hash(data));
+            assert_eq!(fork.finalize().to_vec(), H::hash(&data[..5]));
+        }
+    }
+
+    typed_tests! {
+        fn incremental_hash_concat_matches_concatenation<H>() for {
+            blake2b224: Blake2b224,
+            blake2b256: Blake2b256,
+            blake2b512: Blake2b512,
+            sha256: Sha256,
+            sha512: Sha512,
+            legacy_blake2b256: LegacySeedExpansion<Blake2b256>,
+        } {
+            let (left, right) = (b"left verification key".as_slice(), [7u8; 40]);
+            let mut combined = left.to_vec();
+            combined.extend_from_slice(&right);
+            let expected = H::hash(&combined);
+
+            assert_eq!(H::hash_concat(left, &right), expected);
+            let mut out = vec![0u8; H::OUTPUT_SIZE];
+            H::hash_concat_into(left, &right, &mut out);
+            assert_eq!(out, expected);
+
+            let mut out = vec![0xffu8; H::OUTPUT_SIZE + 1];
+            assert_eq!(H::hash_into(&combined, &mut out), Ok(H::OUTPUT_SIZE));
+            assert_eq!(out[..H::OUTPUT_SIZE], expected[..]);
+            assert_eq!(out[H::OUTPUT_SIZE], 0xff);
+            assert_eq!(
+                H::hash_into(&combined, &mut out[..H::OUTPUT_SIZE - 1]),
+                Err(KesError::BufferTooSmall {
+                    required: H::OUTPUT_SIZE,
+                    available: H::OUTPUT_SIZE - 1,
+                })
+            );
+
+            assert!(hash_concat_matches::<H>(left, &right, &expected));
+            assert!(!hash_concat_matches::<H>(right.as_slice(), left, &expected));
+            assert!(!hash_concat_matches::<H>(left, &right, &expected[1..]));
+        }
+    }
 }
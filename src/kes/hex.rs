@@ -0,0 +1,145 @@
+//! Hex encoding of KES verification keys and signatures
+//!
+//! Operators usually handle KES verification keys as hex copied from
+//! cardano-cli output. [`KesHex`] wraps the raw serialization of every
+//! [`KesAlgorithm`] with a hex codec that accepts an optional `0x` prefix and
+//! either letter case.
+//!
+//! # Example
+//!
+//! ```rust
+//! use cardano_crypto::kes::{KesAlgorithm, KesHex, Sum6Kes};
+//!
+//! # fn main() -> cardano_crypto::common::Result<()> {
+//! let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[2u8; 32])?;
+//! let vk = Sum6Kes::derive_verification_key(&sk)?;
+//!
+//! let hex = Sum6Kes::verification_key_to_hex(&vk);
+//! assert_eq!(hex.len(), 64);
+//! let parsed = Sum6Kes::verification_key_from_hex(&format!("0x{}", hex.to_uppercase()))?;
+//! assert_eq!(parsed, vk);
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::string::String;
+
+use crate::common::error::{CryptoError, Result};
+use crate::common::hex;
+use crate::kes::{KesAlgorithm, KesError};
+
+/// Hex helpers for the public values of a KES scheme
+///
+/// Implemented for every [`KesAlgorithm`].
+pub trait KesHex: KesAlgorithm {
+    /// Encode a verification key as lowercase hex
+    fn verification_key_to_hex(key: &Self::VerificationKey) -> String {
+        hex::encode(&Self::raw_serialize_verification_key_kes(key))
+    }
+
+    /// Parse a verification key from hex
+    ///
+    /// # Errors
+    ///
+    /// - [`CryptoError::InvalidHex`] for odd-length or non-hex input
+    /// - [`KesError::WrongLength`] if the decoded key is not
+    ///   [`VERIFICATION_KEY_SIZE`](KesAlgorithm::VERIFICATION_KEY_SIZE) bytes
+    /// - [`CryptoError::DeserializationError`] if the bytes are not a valid key
+    fn verification_key_from_hex(hex: &str) -> Result<Self::VerificationKey> {
+        let bytes = decode_exact(hex, Self::VERIFICATION_KEY_SIZE)?;
+        Self::raw_deserialize_verification_key_kes(&bytes).ok_or(CryptoError::DeserializationError)
+    }
+
+    /// Encode a signature as lowercase hex
+    fn signature_to_hex(signature: &Self::Signature) -> String {
+        hex::encode(&Self::raw_serialize_signature_kes(signature))
+    }
+
+    /// Parse a signature from hex
+    ///
+    /// # Errors
+    ///
+    /// Same as [`verification_key_from_hex`](Self::verification_key_from_hex),
+    /// with the length checked against
+    /// [`SIGNATURE_SIZE`](KesAlgorithm::SIGNATURE_SIZE).
+    fn signature_from_hex(hex: &str) -> Result<Self::Signature> {
+        let bytes = decode_exact(hex, Self::SIGNATURE_SIZE)?;
+        Self::raw_deserialize_signature_kes(&bytes).ok_or(CryptoError::DeserializationError)
+    }
+}
+
+impl<K: KesAlgorithm> KesHex for K {}
+
+fn decode_exact(hex: &str, expected: usize) -> Result<alloc::vec::Vec<u8>> {
+    let bytes = hex::decode(hex)?;
+    if bytes.len() != expected {
+        return Err(CryptoError::KesError(KesError::WrongLength {
+            expected,
+            actual: bytes.len(),
+        }));
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kes::{CompactSum3Kes, SingleKes, Sum3Kes};
+
+    #[test]
+    fn signature_hex_roundtrip() {
+        let sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&[28u8; 32]).unwrap();
+        let vk = CompactSum3Kes::derive_verification_key(&sk).unwrap();
+        let sig = CompactSum3Kes::sign_kes(&(), 0, b"hex", &sk).unwrap();
+
+        let hex = CompactSum3Kes::signature_to_hex(&sig);
+        assert_eq!(hex.len(), 2 * CompactSum3Kes::SIGNATURE_SIZE);
+        let parsed = CompactSum3Kes::signature_from_hex(&hex.to_uppercase()).unwrap();
+        CompactSum3Kes::verify_kes(&(), &vk, 0, b"hex", &parsed).unwrap();
+    }
+
+    #[test]
+    fn display_matches_hex_helpers() {
+        let sk = <SingleKes>::gen_key_kes_from_seed_bytes(&[30u8; 32]).unwrap();
+        let vk = <SingleKes>::derive_verification_key(&sk).unwrap();
+        let sig = <SingleKes>::sign_kes(&(), 0, b"display", &sk).unwrap();
+        assert_eq!(vk.to_string(), <SingleKes>::verification_key_to_hex(&vk));
+        assert_eq!(sig.to_string(), <SingleKes>::signature_to_hex(&sig));
+
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[30u8; 32]).unwrap();
+        let sig = Sum3Kes::sign_kes(&(), 0, b"display", &sk).unwrap();
+        assert_eq!(sig.to_string(), Sum3Kes::signature_to_hex(&sig));
+
+        let sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&[30u8; 32]).unwrap();
+        let sig = CompactSum3Kes::sign_kes(&(), 0, b"display", &sk).unwrap();
+        assert_eq!(sig.to_string(), CompactSum3Kes::signature_to_hex(&sig));
+    }
+
+    #[test]
+    fn malformed_hex_is_rejected() {
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[29u8; 32]).unwrap();
+        let hex = Sum3Kes::verification_key_to_hex(&Sum3Kes::derive_verification_key(&sk).unwrap());
+
+        // Odd length
+        assert_eq!(
+            Sum3Kes::verification_key_from_hex(&hex[1..]),
+            Err(CryptoError::InvalidHex)
+        );
+        // Non-hex digit
+        let mut bad = hex.clone();
+        bad.replace_range(0..1, "g");
+        assert_eq!(
+            Sum3Kes::verification_key_from_hex(&bad),
+            Err(CryptoError::InvalidHex)
+        );
+        // Well-formed hex of the wrong length
+        assert_eq!(
+            Sum3Kes::verification_key_from_hex(&hex[2..]),
+            Err(CryptoError::KesError(KesError::WrongLength {
+                expected: 32,
+                actual: 31,
+            }))
+        );
+        assert!(Sum3Kes::signature_from_hex(&hex).is_err());
+    }
+}
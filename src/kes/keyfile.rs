@@ -0,0 +1,359 @@
+//! Versioned on-disk format for evolving KES signing keys
+//!
+//! A block producer must persist its signing key after every evolution so a
+//! restart resumes at the right period. [`KeyFile`] stores the key with enough
+//! framing to catch the usual accidents: a file for another scheme, a
+//! truncated or bit-flipped file, and a backup restored from an earlier
+//! period, which would resurrect secrets that forward security is meant to
+//! have destroyed.
+//!
+//! # Format
+//!
+//! ```text
+//! [0..8]     magic "CKESKEY\0"
+//! [8]        version (currently 1)
+//! [9]        algorithm tag length n
+//! [10..10+n] algorithm tag, e.g. "Sum6Kes/Blake2b-256"
+//! [..+8]     current period (u64 BE)
+//! [..-32]    raw signing key (see UnsoundKesAlgorithm)
+//! [-32..]    Blake2b-256 of everything before it
+//! ```
+//!
+//! The checksum detects corruption, not tampering, and the key is stored in
+//! the clear. Use `kes::sealed` (feature `encrypted-storage`)
+//! when the storage itself is not trusted. The tag names the scheme family
+//! and depth, then the tree hash, with "/legacy" appended for keys that
+//! expand seeds as cardano-crypto 1.0.x did, so a `SumNKesWith<H>` key is
+//! never read back as another hash's key.
+//!
+//! # Example
+//!
+//! ```rust
+//! use cardano_crypto::kes::keyfile::KeyFile;
+//! use cardano_crypto::kes::{KesAlgorithm, Sum2Kes};
+//!
+//! # fn main() -> cardano_crypto::common::Result<()> {
+//! let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[3u8; 32])?;
+//! let sk = Sum2Kes::update_kes(&(), sk, 0)?.unwrap();
+//!
+//! let bytes = KeyFile::<Sum2Kes>::new(sk).to_bytes()?;
+//!
+//! // Refuse anything older than the last period we know we reached
+//! let restored = KeyFile::<Sum2Kes>::from_bytes(&bytes, 1)?;
+//! assert_eq!(restored.period(), 1);
+//! assert!(KeyFile::<Sum2Kes>::from_bytes(&bytes, 2).is_err());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use alloc::vec::Vec;
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use zeroize::Zeroizing;
+
+use crate::common::error::{CryptoError, Result};
+use crate::kes::{algorithm_tag, KesError, Period, UnsoundKesAlgorithm};
+
+/// First eight bytes of every key file
+pub const KEY_FILE_MAGIC: [u8; 8] = *b"CKESKEY\0";
+
+/// Current key file format version
+pub const KEY_FILE_VERSION: u8 = 1;
+
+const PERIOD_SIZE: usize = 8;
+const CHECKSUM_SIZE: usize = 32;
+
+/// A signing key of the scheme `K` with its current period
+pub struct KeyFile<K: UnsoundKesAlgorithm> {
+    signing_key: K::SigningKey,
+    period: Period,
+}
+
+impl<K: UnsoundKesAlgorithm> KeyFile<K> {
+    /// Wrap a signing key, recording the period it currently signs for
+    pub fn new(signing_key: K::SigningKey) -> Self {
+        let period = K::current_period_kes(&signing_key);
+        Self {
+            signing_key,
+            period,
+        }
+    }
+
+    /// Period the stored key signs for
+    pub fn period(&self) -> Period {
+        self.period
+    }
+
+    /// The stored signing key
+    pub fn signing_key(&self) -> &K::SigningKey {
+        &self.signing_key
+    }
+
+    /// Unwrap the signing key
+    pub fn into_signing_key(self) -> K::SigningKey {
+        self.signing_key
+    }
+
+    /// Encode in the key file format
+    ///
+    /// The buffer holds the raw signing key and is wiped on drop.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KesError::UnsupportedKeyType`] if the algorithm tag is longer
+    /// than the format's 255 bytes.
+    pub fn to_bytes(&self) -> Result<Zeroizing<Vec<u8>>> {
+        let tag = algorithm_tag::<K>();
+        let tag = tag.as_bytes();
+        let tag_len = u8::try_from(tag.len())
+            .map_err(|_| CryptoError::KesError(KesError::UnsupportedKeyType))?;
+        let mut bytes = Zeroizing::new(Vec::with_capacity(
+            KEY_FILE_MAGIC.len()
+                + 2
+                + tag.len()
+                + PERIOD_SIZE
+                + K::SIGNING_KEY_SIZE
+                + CHECKSUM_SIZE,
+        ));
+        bytes.extend_from_slice(&KEY_FILE_MAGIC);
+        bytes.push(KEY_FILE_VERSION);
+        bytes.push(tag_len);
+        bytes.extend_from_slice(tag);
+        bytes.extend_from_slice(&self.period.to_be_bytes());
+        bytes.extend_from_slice(&K::raw_serialize_signing_key_kes(&self.signing_key));
+        let checksum = Blake2b::<U32>::digest(bytes.as_slice());
+        bytes.extend_from_slice(&checksum);
+        Ok(bytes)
+    }
+
+    /// Decode a key file, refusing keys for periods before `min_period`
+    ///
+    /// # Errors
+    ///
+    /// - [`KesError::InvalidEncoding`] for a bad magic, an unknown version, a
+    ///   truncated file, or a period that disagrees with the key's own state
+    /// - [`KesError::ChecksumMismatch`] if the contents were corrupted
+    /// - [`KesError::UnsupportedKeyType`] if the file holds another scheme's key
+    /// - [`KesError::PeriodBelowFloor`] if the key is older than `min_period`
+    pub fn from_bytes(bytes: &[u8], min_period: Period) -> Result<Self> {
+        let invalid = CryptoError::KesError(KesError::InvalidEncoding);
+
+        let body_len = bytes
+            .len()
+            .checked_sub(CHECKSUM_SIZE)
+            .ok_or(invalid.clone())?;
+        let (body, checksum) = bytes.split_at(body_len);
+        if body.len() < KEY_FILE_MAGIC.len() + 2
+            || body[..KEY_FILE_MAGIC.len()] != KEY_FILE_MAGIC
+            || body[KEY_FILE_MAGIC.len()] != KEY_FILE_VERSION
+        {
+            return Err(invalid);
+        }
+        if Blake2b::<U32>::digest(body).as_slice() != checksum {
+            return Err(CryptoError::KesError(KesError::ChecksumMismatch));
+        }
+
+        let rest = &body[KEY_FILE_MAGIC.len() + 1..];
+        let (tag_len, rest) = rest.split_first().ok_or(invalid.clone())?;
+        let tag = rest.get(..*tag_len as usize).ok_or(invalid.clone())?;
+        if tag != algorithm_tag::<K>().as_bytes() {
+            return Err(CryptoError::KesError(KesError::UnsupportedKeyType));
+        }
+        let rest = &rest[tag.len()..];
+        if rest.len() != PERIOD_SIZE + K::SIGNING_KEY_SIZE {
+            return Err(invalid);
+        }
+        let (period, raw_key) = rest.split_at(PERIOD_SIZE);
+        let period = Period::from_be_bytes(period.try_into().expect("split at PERIOD_SIZE"));
+        if period < min_period {
+            return Err(CryptoError::KesError(KesError::PeriodBelowFloor {
+                period,
+                floor: min_period,
+            }));
+        }
+
+        let signing_key = K::raw_deserialize_signing_key_kes(raw_key).ok_or(invalid.clone())?;
+        if K::current_period_kes(&signing_key) != period {
+            K::forget_signing_key_kes(signing_key);
+            return Err(invalid);
+        }
+        Ok(Self {
+            signing_key,
+            period,
+        })
+    }
+
+    /// Write the key file to `path`, replacing any existing file
+    ///
+    /// The file is written next to `path` and renamed over it once synced, so
+    /// a crash leaves either the old or the new key, never a partial one. On
+    /// Unix the file is created readable by its owner only.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CryptoError::Io`] if the file cannot be written, and
+    /// otherwise the errors of [`to_bytes`](Self::to_bytes).
+    pub fn save_to_path(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut temp = path.as_os_str().to_owned();
+        temp.push(".tmp");
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let bytes = self.to_bytes()?;
+        let written = options.open(&temp).and_then(|mut file| {
+            file.write_all(&bytes)?;
+            file.sync_all()
+        });
+        let result = written.and_then(|()| fs::rename(&temp, path));
+        if result.is_err() {
+            let _ = fs::remove_file(&temp);
+        }
+        result.map_err(|err| CryptoError::Io(err.kind()))
+    }
+
+    /// Read a key file from `path`, refusing keys for periods before `min_period`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CryptoError::Io`] if the file cannot be read, and otherwise
+    /// the errors of [`from_bytes`](Self::from_bytes).
+    pub fn load_from_path(path: impl AsRef<Path>, min_period: Period) -> Result<Self> {
+        let bytes = Zeroizing::new(fs::read(path).map_err(|err| CryptoError::Io(err.kind()))?);
+        Self::from_bytes(&bytes, min_period)
+    }
+}
+
+impl<K: UnsoundKesAlgorithm> core::fmt::Debug for KeyFile<K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KeyFile")
+            .field("algorithm", &K::ALGORITHM_NAME)
+            .field("period", &self.period)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kes::{
+        Blake2b256, CompactSum3Kes, KesAlgorithm, LegacySeedExpansion, Sha256, Sum3Kes, Sum3KesWith,
+    };
+
+    fn key_at(period: Period) -> <Sum3Kes as KesAlgorithm>::SigningKey {
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[71u8; 32]).unwrap();
+        Sum3Kes::update_kes_to(&(), sk, 0, period).unwrap().unwrap()
+    }
+
+    #[test]
+    fn round_trips_through_bytes_and_disk() {
+        let file = KeyFile::<Sum3Kes>::new(key_at(5));
+        let vk = Sum3Kes::derive_verification_key(file.signing_key()).unwrap();
+
+        let restored = KeyFile::<Sum3Kes>::from_bytes(&file.to_bytes().unwrap(), 5).unwrap();
+        assert_eq!(restored.period(), 5);
+        assert_eq!(
+            *Sum3Kes::raw_serialize_signing_key_kes(restored.signing_key()),
+            *Sum3Kes::raw_serialize_signing_key_kes(file.signing_key())
+        );
+
+        let dir = std::env::temp_dir().join(format!("cardano-kes-keyfile-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("kes.skey");
+        file.save_to_path(&path).unwrap();
+        // Saving again replaces the previous key
+        KeyFile::<Sum3Kes>::new(key_at(6))
+            .save_to_path(&path)
+            .unwrap();
+        let loaded = KeyFile::<Sum3Kes>::load_from_path(&path, 0).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(loaded.period(), 6);
+        let sk = loaded.into_signing_key();
+        let sig = Sum3Kes::sign_kes(&(), 6, b"restart", &sk).unwrap();
+        Sum3Kes::verify_kes(&(), &vk, 6, b"restart", &sig).unwrap();
+    }
+
+    #[test]
+    fn detects_corruption() {
+        let bytes = KeyFile::<Sum3Kes>::new(key_at(2)).to_bytes().unwrap();
+
+        assert_eq!(
+            KeyFile::<Sum3Kes>::from_bytes(&bytes[..bytes.len() - 1], 0).unwrap_err(),
+            CryptoError::KesError(KesError::ChecksumMismatch)
+        );
+        for index in [10, bytes.len() / 2, bytes.len() - 1] {
+            let mut corrupted = bytes.to_vec();
+            corrupted[index] ^= 0x01;
+            assert_eq!(
+                KeyFile::<Sum3Kes>::from_bytes(&corrupted, 0).unwrap_err(),
+                CryptoError::KesError(KesError::ChecksumMismatch),
+                "byte {index}"
+            );
+        }
+
+        let mut bad_magic = bytes.to_vec();
+        bad_magic[0] = b'X';
+        for malformed in [&bad_magic[..], &bytes[..20], &[]] {
+            assert_eq!(
+                KeyFile::<Sum3Kes>::from_bytes(malformed, 0).unwrap_err(),
+                CryptoError::KesError(KesError::InvalidEncoding)
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_other_scheme() {
+        let sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&[72u8; 32]).unwrap();
+        let bytes = KeyFile::<CompactSum3Kes>::new(sk).to_bytes().unwrap();
+
+        assert_eq!(
+            KeyFile::<Sum3Kes>::from_bytes(&bytes, 0).unwrap_err(),
+            CryptoError::KesError(KesError::UnsupportedKeyType)
+        );
+    }
+
+    #[test]
+    fn rejects_same_scheme_over_another_hash() {
+        let sk = Sum3KesWith::<Sha256>::gen_key_kes_from_seed_bytes(&[73u8; 32]).unwrap();
+        let sha = KeyFile::<Sum3KesWith<Sha256>>::new(sk).to_bytes().unwrap();
+        type LegacySum3Kes = Sum3KesWith<LegacySeedExpansion<Blake2b256>>;
+        let sk = LegacySum3Kes::gen_key_kes_from_seed_bytes(&[73u8; 32]).unwrap();
+        let legacy = KeyFile::<LegacySum3Kes>::new(sk).to_bytes().unwrap();
+
+        assert_eq!(&sha[10..][..usize::from(sha[9])], b"Sum3Kes/SHA-256");
+        assert_eq!(
+            &legacy[10..][..usize::from(legacy[9])],
+            b"Sum3Kes/Blake2b-256/legacy"
+        );
+        for bytes in [&sha, &legacy] {
+            assert_eq!(
+                KeyFile::<Sum3Kes>::from_bytes(bytes, 0).unwrap_err(),
+                CryptoError::KesError(KesError::UnsupportedKeyType)
+            );
+        }
+        assert!(KeyFile::<LegacySum3Kes>::from_bytes(&legacy, 0).is_ok());
+    }
+
+    #[test]
+    fn refuses_rollback_below_floor() {
+        let bytes = KeyFile::<Sum3Kes>::new(key_at(3)).to_bytes().unwrap();
+
+        assert!(KeyFile::<Sum3Kes>::from_bytes(&bytes, 3).is_ok());
+        assert_eq!(
+            KeyFile::<Sum3Kes>::from_bytes(&bytes, 4).unwrap_err(),
+            CryptoError::KesError(KesError::PeriodBelowFloor {
+                period: 3,
+                floor: 4
+            })
+        );
+    }
+}
@@ -0,0 +1,242 @@
+//! Slot-driven KES key management
+//!
+//! A block producer signs for slots, not periods. [`KesKeyManager`] holds a
+//! signing key together with the slot its first period starts at and the
+//! number of slots per period, maps each slot to its period, and evolves the
+//! key forward as slots advance. It never evolves backwards: once the key has
+//! moved past a period, slots in that period are refused.
+//!
+//! Mainnet uses 129 600 slots per KES period, with the start slot given by
+//! the KES period in the operational certificate times that length.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::num::NonZeroU64;
+//!
+//! use cardano_crypto::kes::{KesAlgorithm, KesKeyManager, Sum6Kes};
+//!
+//! # fn main() -> cardano_crypto::common::Result<()> {
+//! let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[9u8; 32])?;
+//! let vk = Sum6Kes::derive_verification_key(&sk)?;
+//! let slots_per_period = NonZeroU64::new(129_600).unwrap();
+//! let mut manager = KesKeyManager::<Sum6Kes>::new(sk, 1_000_000, slots_per_period);
+//!
+//! let sig = manager.sign_for_slot(&(), 1_300_000, b"header")?;
+//! assert_eq!(manager.period(), 2);
+//! Sum6Kes::verify_kes(&(), &vk, 2, b"header", &sig)?;
+//!
+//! // The key has left periods 0 and 1 behind
+//! assert!(manager.sign_for_slot(&(), 1_100_000, b"header").is_err());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::num::NonZeroU64;
+
+use crate::common::error::{CryptoError, Result};
+use crate::kes::period::check_period;
+use crate::kes::{KesAlgorithm, KesError, Period};
+
+/// A KES signing key that evolves to the period of each slot it signs for
+///
+/// Slot `s` falls in period `(s - start_slot) / slots_per_period`. Signing
+/// for a slot in the current period uses the key as is; a slot in a later
+/// period first evolves the key there, erasing the secrets of the periods
+/// skipped over.
+pub struct KesKeyManager<K: KesAlgorithm> {
+    /// `None` only once an evolution has failed and the key was lost
+    signing_key: Option<K::SigningKey>,
+    period: Period,
+    start_slot: u64,
+    slots_per_period: NonZeroU64,
+}
+
+impl<K: KesAlgorithm> KesKeyManager<K> {
+    /// Manage `signing_key`, whose period 0 begins at `start_slot`
+    ///
+    /// The key may already have been evolved; the manager starts from its
+    /// current period.
+    pub fn new(signing_key: K::SigningKey, start_slot: u64, slots_per_period: NonZeroU64) -> Self {
+        Self {
+            period: K::current_period_kes(&signing_key),
+            signing_key: Some(signing_key),
+            start_slot,
+            slots_per_period,
+        }
+    }
+
+    /// Period the key is at
+    pub fn period(&self) -> Period {
+        self.period
+    }
+
+    /// Slot period 0 begins at
+    pub fn start_slot(&self) -> u64 {
+        self.start_slot
+    }
+
+    /// Number of slots in each period
+    pub fn slots_per_period(&self) -> NonZeroU64 {
+        self.slots_per_period
+    }
+
+    /// The managed key, or `None` if it was lost to a failed evolution
+    pub fn signing_key(&self) -> Option<&K::SigningKey> {
+        self.signing_key.as_ref()
+    }
+
+    /// Period that `slot` falls in
+    ///
+    /// # Errors
+    ///
+    /// [`KesError::SlotBeforeStart`] if `slot` is before the start slot.
+    pub fn period_for_slot(&self, slot: u64) -> core::result::Result<Period, KesError> {
+        let offset = slot
+            .checked_sub(self.start_slot)
+            .ok_or(KesError::SlotBeforeStart {
+                slot,
+                start_slot: self.start_slot,
+            })?;
+        Ok(offset / self.slots_per_period)
+    }
+
+    /// Evolve the key to the period of `slot` and sign `message`
+    ///
+    /// The range checks all happen before the key is touched, so a rejected
+    /// slot leaves the manager as it was.
+    ///
+    /// # Errors
+    ///
+    /// - [`KesError::SlotBeforeStart`] if `slot` is before the start slot
+    /// - [`KesError::PeriodAlreadyPassed`] if the key has evolved past the
+    ///   slot's period
+    /// - [`KesError::PeriodOutOfRange`] if the slot is after the key's last
+    ///   period
+    /// - [`KesError::KeyExpired`] if an earlier evolution failed and the key
+    ///   is gone
+    /// - any error of [`update_kes_to`](KesAlgorithm::update_kes_to) or
+    ///   [`sign_kes`](KesAlgorithm::sign_kes)
+    pub fn sign_for_slot(
+        &mut self,
+        context: &K::Context,
+        slot: u64,
+        message: &[u8],
+    ) -> Result<K::Signature> {
+        let period = self.period_for_slot(slot).map_err(CryptoError::KesError)?;
+        if period < self.period {
+            return Err(CryptoError::KesError(KesError::PeriodAlreadyPassed {
+                requested: period,
+                current: self.period,
+            }));
+        }
+        check_period::<K>(period).map_err(CryptoError::KesError)?;
+
+        if period > self.period {
+            let signing_key = self
+                .signing_key
+                .take()
+                .ok_or(CryptoError::KesError(KesError::KeyExpired))?;
+            // Within the key's lifetime, so the update does not expire it
+            self.signing_key = K::update_kes_to(context, signing_key, self.period, period)?;
+            self.period = period;
+        }
+        let signing_key = self
+            .signing_key
+            .as_ref()
+            .ok_or(CryptoError::KesError(KesError::KeyExpired))?;
+        K::sign_kes(context, period, message, signing_key)
+    }
+
+    /// Stop managing the key and return it
+    pub fn into_signing_key(self) -> Option<K::SigningKey> {
+        self.signing_key
+    }
+}
+
+impl<K: KesAlgorithm> core::fmt::Debug for KesKeyManager<K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KesKeyManager")
+            .field("algorithm", &K::ALGORITHM_NAME)
+            .field("period", &self.period)
+            .field("start_slot", &self.start_slot)
+            .field("slots_per_period", &self.slots_per_period)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kes::{CompactSum2Kes, Sum2Kes};
+
+    fn manager<K: KesAlgorithm>() -> KesKeyManager<K> {
+        let sk = K::gen_key_kes_from_seed_bytes(&[0x55u8; 32]).unwrap();
+        KesKeyManager::new(sk, 1000, NonZeroU64::new(10).unwrap())
+    }
+
+    typed_tests! {
+        fn slots_map_to_periods<K>() for {
+            sum2: Sum2Kes,
+            compact_sum2: CompactSum2Kes,
+        } {
+            let mut manager = manager::<K>();
+            let vk = K::derive_verification_key(manager.signing_key().unwrap()).unwrap();
+
+            assert_eq!(manager.period_for_slot(1000), Ok(0));
+            assert_eq!(manager.period_for_slot(1009), Ok(0));
+            assert_eq!(manager.period_for_slot(1010), Ok(1));
+            assert_eq!(manager.period_for_slot(1039), Ok(3));
+            assert_eq!(
+                manager.period_for_slot(999),
+                Err(KesError::SlotBeforeStart {
+                    slot: 999,
+                    start_slot: 1000
+                })
+            );
+
+            for (slot, period) in [(1003, 0), (1025, 2), (1029, 2), (1030, 3)] {
+                let sig = manager.sign_for_slot(&(), slot, b"block").unwrap();
+                assert_eq!(manager.period(), period);
+                let sk = manager.signing_key().unwrap();
+                assert_eq!(K::current_period_kes(sk), period);
+                K::verify_kes(&(), &vk, period, b"block", &sig).unwrap();
+            }
+            assert!(matches!(
+                manager.sign_for_slot(&(), 1040, b"block"),
+                Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+                    period: 4,
+                    ..
+                }))
+            ));
+            assert_eq!(manager.period(), 3);
+        }
+    }
+
+    typed_tests! {
+        fn key_never_evolves_backwards<K>() for {
+            sum2: Sum2Kes,
+            compact_sum2: CompactSum2Kes,
+        } {
+            let mut manager = manager::<K>();
+            manager.sign_for_slot(&(), 1021, b"block").unwrap();
+            assert_eq!(manager.period(), 2);
+
+            // Earlier slots of the current period are still in the key's reach
+            manager.sign_for_slot(&(), 1020, b"block").unwrap();
+            assert!(matches!(
+                manager.sign_for_slot(&(), 1019, b"block"),
+                Err(CryptoError::KesError(KesError::PeriodAlreadyPassed {
+                    requested: 1,
+                    current: 2
+                }))
+            ));
+            assert!(matches!(
+                manager.sign_for_slot(&(), 5, b"block"),
+                Err(CryptoError::KesError(KesError::SlotBeforeStart { .. }))
+            ));
+            assert_eq!(manager.period(), 2);
+            K::forget_signing_key_kes(manager.into_signing_key().unwrap());
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Merkle paths of KES signatures, for external verification shims
+//!
+//! A Sum or CompactSum signature is an Ed25519 signature at one leaf of a
+//! binary tree, plus the verification keys needed to hash from that leaf up
+//! to the root verification key.
+//! [`Ed25519LeafKes::merkle_path`](crate::kes::Ed25519LeafKes::merkle_path) takes a
+//! signature apart into a [`MerklePath`], and [`MerklePath::root`] rebuilds
+//! the root from it. Together with the raw leaf signature, that is enough for
+//! another crate to verify signatures under conventions this crate does not
+//! implement itself, such as a different message framing at the leaf.
+//!
+//! # Supported formats
+//!
+//! [`KesAlgorithm::verify_kes`](crate::kes::KesAlgorithm::verify_kes) verifies
+//! the two Shelley-era formats of cardano-base, `SumKES` and `CompactSumKES`
+//! over Ed25519, at any depth and with any [`KesHashAlgorithm`]. There is no
+//! older KES format to be compatible with: Byron blocks are signed with
+//! plain Ed25519 keys under delegation certificates, which is a
+//! [`dsign`](crate::dsign) check, not a KES one. Keys derived with the 1.0.x
+//! seed expansion ([`LegacySeedExpansion`](crate::kes::LegacySeedExpansion))
+//! produce signatures in the same format, and those verify normally.
+//!
+//! With a [`MerklePath`] a shim can also handle:
+//!
+//! - a leaf message other than the signed bytes, such as a digest or a
+//!   domain-prefixed encoding. Check [`MerklePath::leaf_signature`] against
+//!   [`MerklePath::leaf_verification_key`] yourself.
+//! - a different node hash. Call [`MerklePath::root`] with another `H`.
+//! - a different period encoding. The path fixes the period through
+//!   [`MerkleStep::active_is_left`], leaf first.
+//!
+//! These are not supported: leaves that are not Ed25519, trees that are not
+//! binary or not balanced, and MMM-style signatures that carry the period or
+//! key material in another order.
+//!
+//! # Example
+//!
+//! ```rust
+//! use cardano_crypto::common::traits::DsignAlgorithm;
+//! use cardano_crypto::dsign::Ed25519;
+//! use cardano_crypto::kes::{Blake2b256, Ed25519LeafKes, KesAlgorithm, Sum3Kes};
+//!
+//! # fn main() -> cardano_crypto::common::Result<()> {
+//! let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[2u8; 32])?;
+//! let vk = Sum3Kes::derive_verification_key(&sk)?;
+//! let sk = Sum3Kes::update_kes_to(&(), sk, 0, 5)?.expect("8 periods");
+//! let sig = Sum3Kes::sign_kes(&(), 5, b"header", &sk)?;
+//!
+//! let path = Sum3Kes::merkle_path(&vk, 5, &sig)?;
+//! assert_eq!(path.period(), 5);
+//! assert!(path.root_matches::<Blake2b256>(&vk));
+//!
+//! let leaf_vk = Ed25519::deserialize_verification_key(&path.leaf_verification_key)?;
+//! let leaf_sig = Ed25519::deserialize_signature(&path.leaf_signature)?;
+//! Ed25519::verify(b"header", &leaf_sig, &leaf_vk)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+
+use subtle::Choice;
+
+use crate::kes::hash::{hash_concat_matches, order_children, KesHashAlgorithm};
+use crate::kes::Period;
+
+/// One level of a [`MerklePath`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleStep {
+    /// Raw verification key of the subtree the signature does not use
+    pub sibling: Vec<u8>,
+    /// Whether the signing subtree is the left one at this level
+    pub active_is_left: bool,
+}
+
+impl MerkleStep {
+    /// [`active_is_left`](Self::active_is_left) inverted, as a [`Choice`]
+    fn is_right(&self) -> Choice {
+        Choice::from(u8::from(!self.active_is_left))
+    }
+}
+
+/// Leaf and authentication path of a KES signature
+///
+/// Returned by
+/// [`Ed25519LeafKes::merkle_path`](crate::kes::Ed25519LeafKes::merkle_path). The path is read from the
+/// signature and has not been checked against any verification key; use
+/// [`root_matches`](Self::root_matches) for that.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerklePath {
+    /// Ed25519 verification key of the signing leaf
+    pub leaf_verification_key: [u8; 32],
+    /// Ed25519 signature made by that leaf
+    pub leaf_signature: [u8; 64],
+    /// One step per Sum level, starting next to the leaf
+    pub steps: Vec<MerkleStep>,
+}
+
+impl MerklePath {
+    /// Period the path leads to
+    ///
+    /// Each step contributes one bit, the leaf level being the least
+    /// significant.
+    #[must_use]
+    pub fn period(&self) -> Period {
+        self.steps
+            .iter()
+            .enumerate()
+            .filter(|(_, step)| !step.active_is_left)
+            .map(|(level, _)| 1 << level)
+            .sum()
+    }
+
+    /// Root verification key obtained by hashing up the path with `H`
+    ///
+    /// For a single-period scheme with no steps, this is the leaf key.
+    #[must_use]
+    pub fn root<H: KesHashAlgorithm>(&self) -> Vec<u8> {
+        self.hash_up::<H>(&self.steps)
+    }
+
+    /// Whether the path hashes up to `verification_key`, compared in constant time
+    ///
+    /// A matching root does not by itself pin the period. Sum signatures carry
+    /// both keys of the lowest level, so flipping that step still reaches the
+    /// same root while naming the sibling leaf. A shim must also check
+    /// [`leaf_signature`](Self::leaf_signature) under
+    /// [`leaf_verification_key`](Self::leaf_verification_key).
+    #[must_use]
+    pub fn root_matches<H: KesHashAlgorithm>(&self, verification_key: &[u8]) -> bool {
+        let Some((top, below)) = self.steps.split_last() else {
+            return crate::hash::constant_time_eq(&self.leaf_verification_key, verification_key);
+        };
+        let (left, right) = order_children(
+            self.hash_up::<H>(below),
+            top.sibling.clone(),
+            top.is_right(),
+        );
+        hash_concat_matches::<H>(&left, &right, verification_key)
+    }
+
+    fn hash_up<H: KesHashAlgorithm>(&self, steps: &[MerkleStep]) -> Vec<u8> {
+        steps
+            .iter()
+            .fold(self.leaf_verification_key.to_vec(), |node, step| {
+                let (left, right) = order_children(node, step.sibling.clone(), step.is_right());
+                H::hash_concat(&left, &right)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::traits::DsignAlgorithm;
+    use crate::dsign::Ed25519;
+    use crate::kes::{Blake2b256, CompactSum3Kes, Ed25519LeafKes, KesAlgorithm, Sum0Kes, Sum3Kes};
+
+    typed_tests! {
+        fn paths_rebuild_root<K>() for {
+            single: Sum0Kes,
+            sum3: Sum3Kes,
+            compact_sum3: CompactSum3Kes,
+        } {
+            let mut sk = K::gen_key_kes_from_seed_bytes(&[17u8; 32]).unwrap();
+            let vk = K::derive_verification_key(&sk).unwrap();
+            let vk_bytes = K::raw_serialize_verification_key_kes(&vk);
+
+            for period in 0..K::total_periods() {
+                let sig = K::sign_kes(&(), period, b"block", &sk).unwrap();
+                let path = K::merkle_path(&vk, period, &sig).unwrap();
+
+                assert_eq!(path.steps.len() as u32, K::total_periods().trailing_zeros());
+                assert_eq!(path.period(), period);
+                assert_eq!(path.root::<Blake2b256>(), vk_bytes);
+                assert!(path.root_matches::<Blake2b256>(&vk_bytes));
+                assert_eq!(path.leaf_signature, K::leaf_signature(&sig));
+
+                let leaf_vk =
+                    Ed25519::deserialize_verification_key(&path.leaf_verification_key).unwrap();
+                let leaf_sig = Ed25519::deserialize_signature(&path.leaf_signature).unwrap();
+                Ed25519::verify(b"block", &leaf_sig, &leaf_vk).unwrap();
+
+                // Claiming another period breaks the root or the leaf check
+                if let Ok(wrong) = K::merkle_path(&vk, period ^ 1, &sig) {
+                    let leaf_holds =
+                        Ed25519::deserialize_verification_key(&wrong.leaf_verification_key)
+                            .and_then(|leaf_vk| Ed25519::verify(b"block", &leaf_sig, &leaf_vk))
+                            .is_ok();
+                    assert!(!(wrong.root_matches::<Blake2b256>(&vk_bytes) && leaf_holds));
+                }
+
+                match K::update_kes(&(), sk, period).unwrap() {
+                    Some(next) => sk = next,
+                    None => return,
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_range_period_is_rejected() {
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[18u8; 32]).unwrap();
+        let vk = Sum3Kes::derive_verification_key(&sk).unwrap();
+        let sig = Sum3Kes::sign_kes(&(), 0, b"block", &sk).unwrap();
+        assert!(Sum3Kes::merkle_path(&vk, 8, &sig).is_err());
+    }
+}
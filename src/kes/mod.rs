@@ -8,6 +8,9 @@
 //! - SingleKES - Single-period signature (base case)
 //! - Sum0Kes through Sum7Kes - Binary tree composition (2^0 to 2^7 periods)
 //! - CompactSum variants - Optimized signatures with smaller size
+//! - [`KesScheme`] and [`verify_dynamic`] - Pick one of the above at runtime
+//! - [`ContextKes`] - Bind signatures of any of the above to a context
+//! - [`opcert`] - Operational certificates binding a KES key to a cold key
 //!
 //! # Examples
 //!
@@ -38,19 +41,69 @@
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
-use crate::common::error::Result;
+use crate::common::error::{CryptoError, Result};
+use crate::metrics;
 
+// Defined before the submodules so that their tests can use it too
+#[cfg(test)]
+#[macro_use]
+mod typed_tests;
+
+pub mod context;
+pub mod dynamic;
+#[cfg(feature = "cbor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cbor")))]
+pub mod envelope;
+pub mod epoch;
 pub mod hash;
+pub mod hex;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod keyfile;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod manager;
+pub mod merkle;
+pub mod opcert;
+pub mod period;
+pub mod prehash;
+pub mod receipt;
+#[cfg(feature = "encrypted-storage")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encrypted-storage")))]
+pub mod sealed;
+pub mod session;
 pub mod single;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod stream;
 pub mod sum;
 pub mod test_vectors;
 
-pub use hash::{Blake2b224, Blake2b256, Blake2b512, KesHashAlgorithm};
+pub use context::ContextKes;
+pub use dynamic::{verify_dynamic, KesScheme};
+pub use epoch::{BranchCache, EpochReport, EpochVerifier};
+pub use hash::{
+    Blake2b224, Blake2b256, Blake2b512, KesDigest, KesHashAlgorithm, LegacySeedExpansion,
+    SeedExpansion, Sha256, Sha512,
+};
+pub use hex::KesHex;
+#[cfg(feature = "std")]
+pub use manager::KesKeyManager;
+pub use merkle::{MerklePath, MerkleStep};
+pub use period::{period_in_range, KesPeriod};
+pub use prehash::{KesSigner, KesVerifier};
+pub use receipt::VerifyReceipt;
+pub use session::{KesSigningSession, SessionKes};
 pub use single::{CompactSingleKes, CompactSingleSig, OptimizedKesSignature, SingleKes};
+#[cfg(feature = "std")]
+pub use stream::KesStream;
 pub use sum::{
-    CompactSum0Kes, CompactSum1Kes, CompactSum2Kes, CompactSum3Kes, CompactSum4Kes, CompactSum5Kes,
-    CompactSum6Kes, CompactSum7Kes, CompactSumKes, Sum0Kes, Sum1Kes, Sum2Kes, Sum3Kes, Sum4Kes,
-    Sum5Kes, Sum6Kes, Sum7Kes, SumKes,
+    CompactSum0Kes, CompactSum1Kes, CompactSum1KesWith, CompactSum2Kes, CompactSum2KesWith,
+    CompactSum3Kes, CompactSum3KesWith, CompactSum4Kes, CompactSum4KesWith, CompactSum5Kes,
+    CompactSum5KesWith, CompactSum6Kes, CompactSum6KesWith, CompactSum7Kes, CompactSum7KesWith,
+    CompactSumKes, GenericSumKes, Sum0Kes, Sum1Kes, Sum1KesWith, Sum2Kes, Sum2KesWith, Sum3Kes,
+    Sum3KesWith, Sum4Kes, Sum4KesWith, Sum5Kes, Sum5KesWith, Sum6Kes, Sum6KesWith, Sum7Kes,
+    Sum7KesWith, SumKes,
 };
 
 /// KES period type (0 to 2^N - 1)
@@ -65,18 +118,67 @@ pub use sum::{
 /// ```
 pub type Period = u64;
 
+/// Deepest KES tree whose period count fits in [`Period`]
+///
+/// A tree of depth `n` covers `2^n` periods, so at depth 64 `TOTAL_PERIODS`
+/// would wrap to 0. [`SumKes`] and [`CompactSumKes`] check their depth
+/// against this limit when `TREE_DEPTH` is evaluated, which every call to
+/// `total_periods` does, so a deeper composition is rejected at compile time
+/// instead of silently wrapping. [`GenericSumKes`] stops well short of it.
+///
+/// ```rust
+/// use cardano_crypto::kes::{GenericSumKes, KesAlgorithm, SumKes, Blake2b256, MAX_TREE_DEPTH};
+///
+/// type Up1<K> = SumKes<K, Blake2b256>;
+/// type Up4<K> = Up1<Up1<Up1<Up1<K>>>>;
+/// type Up16<K> = Up4<Up4<Up4<Up4<K>>>>;
+/// type Deepest = Up16<Up16<Up16<Up1<Up1<Up1<GenericSumKes<12>>>>>>>;
+///
+/// assert_eq!(Deepest::TREE_DEPTH, MAX_TREE_DEPTH);
+/// assert_eq!(Deepest::total_periods(), 1 << 63);
+/// ```
+///
+/// One level more does not compile:
+///
+/// ```compile_fail
+/// use cardano_crypto::kes::{GenericSumKes, KesAlgorithm, SumKes, Blake2b256};
+///
+/// type Up1<K> = SumKes<K, Blake2b256>;
+/// type Up4<K> = Up1<Up1<Up1<Up1<K>>>>;
+/// type Up16<K> = Up4<Up4<Up4<Up4<K>>>>;
+/// type TooDeep = Up16<Up16<Up16<GenericSumKes<16>>>>;
+///
+/// let _ = TooDeep::total_periods();
+/// ```
+pub const MAX_TREE_DEPTH: u32 = Period::BITS - 1;
+
+/// `depth`, or a compile-time error if it exceeds [`MAX_TREE_DEPTH`]
+pub(crate) const fn checked_tree_depth(depth: u32) -> u32 {
+    assert!(
+        depth <= MAX_TREE_DEPTH,
+        "KES tree depth exceeds MAX_TREE_DEPTH: 2^depth periods overflow Period"
+    );
+    depth
+}
+
 /// KES-specific errors
 ///
+/// Non-exhaustive, so new variants can be added without breaking downstream
+/// matches; match the variants you handle and fall back with `_`. The
+/// variants that carry data also have `const` constructors.
+///
 /// # Examples
 ///
 /// ```rust
 /// use cardano_crypto::kes::KesError;
 ///
-/// let err = KesError::PeriodOutOfRange { period: 100, max_period: 63 };
+/// let err = KesError::period_out_of_range(100, 63);
+/// assert_eq!(err, KesError::PeriodOutOfRange { period: 100, max_period: 63 });
 /// let s = format!("{}", err);
 /// assert!(s.contains("100"));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum KesError {
     /// Period out of valid range
     PeriodOutOfRange {
@@ -87,8 +189,14 @@ pub enum KesError {
     },
     /// Key has expired
     KeyExpired,
-    /// Verification failed
+    /// Verification failed, for a reason not covered by a more specific variant
     VerificationFailed,
+    /// A Merkle tree node does not hash to its parent's verification key
+    BadMerklePath,
+    /// The leaf Ed25519 signature does not verify
+    BadEd25519Signature,
+    /// Signature bytes have the right length but do not decode
+    MalformedSignature,
     /// Invalid seed length
     InvalidSeedLength {
         /// Expected seed length
@@ -98,6 +206,156 @@ pub enum KesError {
     },
     /// Key update failed
     UpdateFailed,
+    /// A sealed signing key failed authentication (tampered or wrong key)
+    SealAuthenticationFailed,
+    /// Encoded key or signature has the wrong number of bytes
+    WrongLength {
+        /// Expected length in bytes
+        expected: usize,
+        /// Actual length in bytes
+        actual: usize,
+    },
+    /// Signature is valid, but for a different period than the one requested
+    PeriodMismatch {
+        /// Period verification was requested for
+        requested: Period,
+        /// Period the signature was produced at
+        signed: Period,
+    },
+    /// A cardano-cli text envelope is malformed or its key bytes do not decode
+    InvalidTextEnvelope,
+    /// A text envelope's `type` or a key file's algorithm tag is not the
+    /// KES scheme being read
+    UnsupportedKeyType,
+    /// Encoded key or signature has the right length but does not decode
+    InvalidEncoding,
+    /// Output buffer cannot hold the serialization
+    BufferTooSmall {
+        /// Bytes the serialization needs
+        required: usize,
+        /// Bytes available in the buffer
+        available: usize,
+    },
+    /// Stored key data does not match its checksum
+    ChecksumMismatch,
+    /// A stored key is for an earlier period than the caller will accept
+    PeriodBelowFloor {
+        /// Period of the stored key
+        period: Period,
+        /// Lowest period the caller accepts
+        floor: Period,
+    },
+    /// A loaded signing key does not derive the expected verification key
+    VerificationKeyMismatch,
+    /// A Sum tree's hash expands seeds to a length its subtrees cannot use
+    InvalidSeedExpansion {
+        /// Output size of the tree's hash, the length of each expanded seed
+        hash_output: usize,
+        /// Seed size the subtree scheme requires
+        seed_size: usize,
+    },
+    /// A signing key has not been evolved to the period it was asked to sign
+    KeyBehindPeriod {
+        /// Period signing was requested for
+        requested: Period,
+        /// Period the signing key is at
+        current: Period,
+    },
+    /// Signing was requested for a period the key has already evolved past
+    PeriodAlreadyPassed {
+        /// Period signing was requested for
+        requested: Period,
+        /// Period the signing key is at
+        current: Period,
+    },
+    /// A slot falls before the first period of a key
+    SlotBeforeStart {
+        /// Slot that was requested
+        slot: u64,
+        /// Slot the key's period 0 begins at
+        start_slot: u64,
+    },
+    /// An update was asked to start from a period the key is not at
+    UpdateFromWrongPeriod {
+        /// Period the update was told the key is at
+        from: Period,
+        /// Period the signing key is at
+        current: Period,
+    },
+}
+
+impl KesError {
+    /// [`KesError::PeriodOutOfRange`]
+    #[must_use]
+    pub const fn period_out_of_range(period: Period, max_period: Period) -> Self {
+        Self::PeriodOutOfRange { period, max_period }
+    }
+
+    /// [`KesError::InvalidSeedLength`]
+    #[must_use]
+    pub const fn invalid_seed_length(expected: usize, actual: usize) -> Self {
+        Self::InvalidSeedLength { expected, actual }
+    }
+
+    /// [`KesError::WrongLength`]
+    #[must_use]
+    pub const fn wrong_length(expected: usize, actual: usize) -> Self {
+        Self::WrongLength { expected, actual }
+    }
+
+    /// [`KesError::PeriodMismatch`]
+    #[must_use]
+    pub const fn period_mismatch(requested: Period, signed: Period) -> Self {
+        Self::PeriodMismatch { requested, signed }
+    }
+
+    /// [`KesError::BufferTooSmall`]
+    #[must_use]
+    pub const fn buffer_too_small(required: usize, available: usize) -> Self {
+        Self::BufferTooSmall {
+            required,
+            available,
+        }
+    }
+
+    /// [`KesError::PeriodBelowFloor`]
+    #[must_use]
+    pub const fn period_below_floor(period: Period, floor: Period) -> Self {
+        Self::PeriodBelowFloor { period, floor }
+    }
+
+    /// [`KesError::InvalidSeedExpansion`]
+    #[must_use]
+    pub const fn invalid_seed_expansion(hash_output: usize, seed_size: usize) -> Self {
+        Self::InvalidSeedExpansion {
+            hash_output,
+            seed_size,
+        }
+    }
+
+    /// [`KesError::KeyBehindPeriod`]
+    #[must_use]
+    pub const fn key_behind_period(requested: Period, current: Period) -> Self {
+        Self::KeyBehindPeriod { requested, current }
+    }
+
+    /// [`KesError::PeriodAlreadyPassed`]
+    #[must_use]
+    pub const fn period_already_passed(requested: Period, current: Period) -> Self {
+        Self::PeriodAlreadyPassed { requested, current }
+    }
+
+    /// [`KesError::SlotBeforeStart`]
+    #[must_use]
+    pub const fn slot_before_start(slot: u64, start_slot: u64) -> Self {
+        Self::SlotBeforeStart { slot, start_slot }
+    }
+
+    /// [`KesError::UpdateFromWrongPeriod`]
+    #[must_use]
+    pub const fn update_from_wrong_period(from: Period, current: Period) -> Self {
+        Self::UpdateFromWrongPeriod { from, current }
+    }
 }
 
 impl core::fmt::Display for KesError {
@@ -108,6 +366,9 @@ impl core::fmt::Display for KesError {
             }
             Self::KeyExpired => write!(f, "KES key has expired"),
             Self::VerificationFailed => write!(f, "KES signature verification failed"),
+            Self::BadMerklePath => write!(f, "KES signature Merkle path does not match the key"),
+            Self::BadEd25519Signature => write!(f, "KES leaf Ed25519 signature is invalid"),
+            Self::MalformedSignature => write!(f, "Malformed KES signature"),
             Self::InvalidSeedLength { expected, actual } => {
                 write!(
                     f,
@@ -116,6 +377,66 @@ impl core::fmt::Display for KesError {
                 )
             }
             Self::UpdateFailed => write!(f, "KES key update failed"),
+            Self::SealAuthenticationFailed => {
+                write!(f, "Sealed KES key failed authentication")
+            }
+            Self::WrongLength { expected, actual } => {
+                write!(f, "Expected {} bytes, got {}", expected, actual)
+            }
+            Self::PeriodMismatch { requested, signed } => write!(
+                f,
+                "Signature is for period {} but period {} was requested",
+                signed, requested
+            ),
+            Self::InvalidTextEnvelope => write!(f, "Malformed KES key text envelope"),
+            Self::UnsupportedKeyType => write!(f, "Unsupported KES key envelope type"),
+            Self::InvalidEncoding => write!(f, "Invalid KES key or signature encoding"),
+            Self::BufferTooSmall {
+                required,
+                available,
+            } => write!(
+                f,
+                "Buffer too small: need {} bytes, have {}",
+                required, available
+            ),
+            Self::ChecksumMismatch => write!(f, "Stored KES key checksum mismatch"),
+            Self::PeriodBelowFloor { period, floor } => write!(
+                f,
+                "Stored KES key is for period {} but period {} or later is required",
+                period, floor
+            ),
+            Self::VerificationKeyMismatch => write!(
+                f,
+                "Loaded KES signing key does not match the expected verification key"
+            ),
+            Self::InvalidSeedExpansion {
+                hash_output,
+                seed_size,
+            } => write!(
+                f,
+                "KES hash expands seeds to {} bytes but subtrees need {}-byte seeds",
+                hash_output, seed_size
+            ),
+            Self::KeyBehindPeriod { requested, current } => write!(
+                f,
+                "KES signing key is at period {} and cannot sign for period {}",
+                current, requested
+            ),
+            Self::PeriodAlreadyPassed { requested, current } => write!(
+                f,
+                "KES signing key has evolved to period {} past period {}",
+                current, requested
+            ),
+            Self::SlotBeforeStart { slot, start_slot } => write!(
+                f,
+                "Slot {} is before the KES key's start slot {}",
+                slot, start_slot
+            ),
+            Self::UpdateFromWrongPeriod { from, current } => write!(
+                f,
+                "KES signing key is at period {} but the update starts from period {}",
+                current, from
+            ),
         }
     }
 }
@@ -127,6 +448,32 @@ impl std::error::Error for KesError {}
 ///
 /// Follows the design from "Composition and Efficiency Tradeoffs for Forward-Secure Digital Signatures"
 /// by Tal Malkin, Daniele Micciancio, and Sara Miner (<https://eprint.iacr.org/2001/034>).
+///
+/// # Calling convention
+///
+/// Operations take the scheme's context first and the key last, as in
+/// cardano-base: `sign_kes(&(), period, message, &sk)`. Evolving consumes the
+/// key and returns the next one, or `None` once it has expired, so a key for
+/// an earlier period cannot outlive its update. Code written against the
+/// `sign_kes(&sk, period, message)` / `update_kes(&mut sk, period)` form
+/// migrates as follows:
+///
+/// ```rust
+/// use cardano_crypto::kes::{KesAlgorithm, KesError, Sum2Kes};
+///
+/// # fn main() -> cardano_crypto::common::Result<()> {
+/// let mut sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[6u8; 32])?;
+///
+/// // was: Sum2Kes::sign_kes(&sk, 0, b"block")
+/// let sig = Sum2Kes::sign_kes(&(), 0, b"block", &sk)?;
+///
+/// // was: Sum2Kes::update_kes(&mut sk, 0)
+/// sk = Sum2Kes::update_kes(&(), sk, 0)?.ok_or(KesError::KeyExpired)?;
+/// assert_eq!(Sum2Kes::current_period_kes(&sk), 1);
+/// # let _ = sig;
+/// # Ok(())
+/// # }
+/// ```
 pub trait KesAlgorithm {
     /// Verification key type
     type VerificationKey;
@@ -134,11 +481,18 @@ pub trait KesAlgorithm {
     type SigningKey;
     /// Signature type
     type Signature;
-    /// Context type (usually () for most implementations)
+    /// Context type (usually () for most implementations; see [`ContextKes`])
     type Context;
 
     /// Algorithm name
     const ALGORITHM_NAME: &'static str;
+    /// Name of the hash the scheme's tree is built with, and how it expands
+    /// seeds
+    ///
+    /// `None` for single-period schemes. `SumNKesWith<H>` schemes share
+    /// [`ALGORITHM_NAME`](Self::ALGORITHM_NAME) with the Blake2b-256 alias
+    /// whatever `H` is, so formats that store signing keys record this too.
+    const TREE_HASH: Option<(&'static str, SeedExpansion)> = None;
     /// Seed size in bytes
     const SEED_SIZE: usize;
     /// Verification key size in bytes
@@ -147,16 +501,122 @@ pub trait KesAlgorithm {
     const SIGNING_KEY_SIZE: usize;
     /// Signature size in bytes
     const SIGNATURE_SIZE: usize;
+    /// Depth of the binary tree behind this scheme (0 for single-period schemes)
+    ///
+    /// A scheme of depth `n` supports `2^n` periods.
+    const TREE_DEPTH: u32 = 0;
+    /// Total number of periods, usable in const contexts
+    ///
+    /// Equals [`total_periods`](Self::total_periods), which remains for
+    /// generic and trait-object callers. Concrete schemes can size fixed
+    /// schedules with it:
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{KesAlgorithm, Period, Sum6Kes};
+    ///
+    /// const SCHEDULE: [Period; Sum6Kes::TOTAL_PERIODS as usize] = {
+    ///     let mut schedule = [0; Sum6Kes::TOTAL_PERIODS as usize];
+    ///     let mut period = 0;
+    ///     while period < schedule.len() {
+    ///         schedule[period] = period as Period;
+    ///         period += 1;
+    ///     }
+    ///     schedule
+    /// };
+    /// assert_eq!(SCHEDULE.len(), 64);
+    /// ```
+    const TOTAL_PERIODS: Period = 1 << Self::TREE_DEPTH;
 
     /// Total number of periods this KES scheme supports
     fn total_periods() -> Period;
 
+    /// Last period this scheme can sign at, inclusive
+    ///
+    /// Periods run from 0 to `total_periods() - 1`, so `Sum6Kes` signs at 0
+    /// through 63. [`sign_kes`](Self::sign_kes) and
+    /// [`verify_kes`](Self::verify_kes) reject any later period with
+    /// [`KesError::PeriodOutOfRange`] carrying this value as `max_period`.
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{KesAlgorithm, Sum6Kes};
+    ///
+    /// assert_eq!(Sum6Kes::max_signable_period(), 63);
+    /// ```
+    fn max_signable_period() -> Period {
+        Self::total_periods() - 1
+    }
+
     /// Generate signing key from seed bytes
+    ///
+    /// Fails with [`KesError::InvalidSeedLength`] unless `seed` is exactly
+    /// [`SEED_SIZE`](Self::SEED_SIZE) bytes; seeds are never truncated or
+    /// padded.
     fn gen_key_kes_from_seed_bytes(seed: &[u8]) -> Result<Self::SigningKey>;
 
+    /// Generate signing key from a cryptographically secure RNG
+    ///
+    /// Draws [`SEED_SIZE`](Self::SEED_SIZE) bytes into a buffer that is wiped
+    /// once the key has been built, then defers to
+    /// [`gen_key_kes_from_seed_bytes`](Self::gen_key_kes_from_seed_bytes).
+    #[cfg(feature = "rand")]
+    fn gen_key_kes<R: rand_core::RngCore + rand_core::CryptoRng>(
+        rng: &mut R,
+    ) -> Result<Self::SigningKey> {
+        let mut seed = zeroize::Zeroizing::new(alloc::vec![0u8; Self::SEED_SIZE]);
+        rng.try_fill_bytes(&mut seed)
+            .map_err(|_| CryptoError::KeyGenerationFailed)?;
+        Self::gen_key_kes_from_seed_bytes(&seed)
+    }
+
     /// Derive verification key from signing key
     fn derive_verification_key(signing_key: &Self::SigningKey) -> Result<Self::VerificationKey>;
 
+    /// Generate a signing key from seed bytes together with its verification key
+    ///
+    /// Equivalent to [`gen_key_kes_from_seed_bytes`](Self::gen_key_kes_from_seed_bytes)
+    /// followed by [`derive_verification_key`](Self::derive_verification_key).
+    /// Sum constructions hash the root key during generation, so the second
+    /// step costs only a copy of it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{KesAlgorithm, Sum6Kes};
+    ///
+    /// # fn main() -> cardano_crypto::common::Result<()> {
+    /// let (sk, vk) = Sum6Kes::gen_key_and_vk_kes_from_seed_bytes(&[4u8; 32])?;
+    /// assert_eq!(vk, Sum6Kes::derive_verification_key(&sk)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn gen_key_and_vk_kes_from_seed_bytes(
+        seed: &[u8],
+    ) -> Result<(Self::SigningKey, Self::VerificationKey)> {
+        let signing_key = Self::gen_key_kes_from_seed_bytes(seed)?;
+        match Self::derive_verification_key(&signing_key) {
+            Ok(verification_key) => Ok((signing_key, verification_key)),
+            Err(err) => {
+                Self::forget_signing_key_kes(signing_key);
+                Err(err)
+            }
+        }
+    }
+
+    /// Compute the verification key for `seed` without keeping a signing key
+    ///
+    /// Returns the same key as [`derive_verification_key`](Self::derive_verification_key)
+    /// on the key generated from `seed`. Sum constructions only walk the public
+    /// tree, wiping each intermediate seed as soon as its subtree is hashed,
+    /// so no evolving secret state is ever assembled.
+    fn verification_key_from_seed(seed: &[u8]) -> Result<Self::VerificationKey> {
+        // Keeps the throwaway key out of the signing key count
+        let _scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
+        let signing_key = Self::gen_key_kes_from_seed_bytes(seed)?;
+        let verification_key = Self::derive_verification_key(&signing_key);
+        Self::forget_signing_key_kes(signing_key);
+        verification_key
+    }
+
     /// Sign a message at a specific period
     fn sign_kes(
         context: &Self::Context,
@@ -165,7 +625,119 @@ pub trait KesAlgorithm {
         signing_key: &Self::SigningKey,
     ) -> Result<Self::Signature>;
 
+    /// Sign a sensitive message at `period`, wiping it once signed
+    ///
+    /// Produces the same signature as [`sign_kes`](Self::sign_kes). `message`
+    /// is zeroized when it is dropped at the end of the call, and so is any
+    /// copy of it this crate makes along the way, such as the framed message
+    /// of [`ContextKes`]. The plain schemes sign the bytes in place and make
+    /// no copy.
+    ///
+    /// Only this buffer is wiped: if the caller built it from data held
+    /// elsewhere, that original is untouched unless it is itself kept in a
+    /// [`zeroize::Zeroizing`]. The SHA-512 state inside the leaf Ed25519
+    /// signer belongs to `ed25519-dalek` and is not covered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{KesAlgorithm, Sum2Kes};
+    /// use zeroize::Zeroizing;
+    ///
+    /// # fn main() -> cardano_crypto::common::Result<()> {
+    /// let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[7u8; 32])?;
+    /// let message = Zeroizing::new(b"sensitive payload".to_vec());
+    /// let sig = Sum2Kes::sign_kes_zeroizing(&(), 0, message, &sk)?;
+    /// # let _ = sig;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn sign_kes_zeroizing(
+        context: &Self::Context,
+        period: Period,
+        message: zeroize::Zeroizing<Vec<u8>>,
+        signing_key: &Self::SigningKey,
+    ) -> Result<Self::Signature> {
+        Self::sign_kes(context, period, &message, signing_key)
+    }
+
+    /// Sign a message at `period`, or return `Ok(None)` if the key has already evolved past it
+    ///
+    /// Suits loops that run on past a key's end of life: a period the key has
+    /// left behind is the expected outcome, not a fault. Errors are kept for
+    /// genuine faults, namely a period beyond
+    /// [`max_signable_period`](Self::max_signable_period)
+    /// ([`KesError::PeriodOutOfRange`]), a key not yet evolved to `period`
+    /// ([`KesError::KeyBehindPeriod`]), and anything
+    /// [`sign_kes`](Self::sign_kes) itself reports.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{KesAlgorithm, Sum1Kes};
+    ///
+    /// # fn main() -> cardano_crypto::common::Result<()> {
+    /// let mut sk = Sum1Kes::gen_key_kes_from_seed_bytes(&[9u8; 32])?;
+    /// Sum1Kes::update_kes_in_place(&(), &mut sk, 0)?;
+    ///
+    /// assert!(Sum1Kes::try_sign_kes(&(), 0, b"late", &sk)?.is_none());
+    /// assert!(Sum1Kes::try_sign_kes(&(), 1, b"on time", &sk)?.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_sign_kes(
+        context: &Self::Context,
+        period: Period,
+        message: &[u8],
+        signing_key: &Self::SigningKey,
+    ) -> Result<Option<Self::Signature>> {
+        if period > Self::max_signable_period() {
+            return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+                period,
+                max_period: Self::max_signable_period(),
+            }));
+        }
+        let current = Self::current_period_kes(signing_key);
+        if period < current {
+            return Ok(None);
+        }
+        if period > current {
+            return Err(CryptoError::KesError(KesError::KeyBehindPeriod {
+                requested: period,
+                current,
+            }));
+        }
+        Self::sign_kes(context, period, message, signing_key).map(Some)
+    }
+
+    /// Sign several messages at the same period without evolving the key
+    ///
+    /// Returns one signature per message, in order, each identical to what
+    /// [`sign_kes`](Self::sign_kes) would produce. The tree path for `period`
+    /// is resolved once for the whole batch, and the leaf signing key is
+    /// expanded once rather than per message.
+    fn sign_batch_at_period(
+        context: &Self::Context,
+        period: Period,
+        messages: &[&[u8]],
+        signing_key: &Self::SigningKey,
+    ) -> Result<Vec<Self::Signature>> {
+        messages
+            .iter()
+            .map(|message| Self::sign_kes(context, period, message, signing_key))
+            .collect()
+    }
+
     /// Verify a signature at a specific period
+    ///
+    /// Failures are reported as the first check that fails:
+    /// [`BadMerklePath`](KesError::BadMerklePath) when the subtree keys do
+    /// not hash up to `verification_key`, and
+    /// [`BadEd25519Signature`](KesError::BadEd25519Signature) when the path
+    /// checks out but the leaf signature does not. A signature made at
+    /// another period fails one of these too;
+    /// [`diagnose_verify_failure`](Self::diagnose_verify_failure) tells it
+    /// apart as [`PeriodMismatch`](KesError::PeriodMismatch).
     fn verify_kes(
         context: &Self::Context,
         verification_key: &Self::VerificationKey,
@@ -174,31 +746,1070 @@ pub trait KesAlgorithm {
         signature: &Self::Signature,
     ) -> Result<()>;
 
+    /// Verify a raw signature against a raw verification key
+    ///
+    /// For packet handlers that hold both as received bytes. Parses them
+    /// with [`verification_key_from_bytes`](Self::verification_key_from_bytes)
+    /// and the signature's equivalent, then runs
+    /// [`verify_kes`](Self::verify_kes).
+    ///
+    /// # Errors
+    ///
+    /// - [`KesError::WrongLength`] if either input is not
+    ///   [`VERIFICATION_KEY_SIZE`](Self::VERIFICATION_KEY_SIZE) or
+    ///   [`SIGNATURE_SIZE`](Self::SIGNATURE_SIZE) bytes long
+    /// - [`KesError::InvalidEncoding`] if one has the right length but does
+    ///   not decode
+    /// - the [`KesError`] `verify_kes` reports for a bad signature, and
+    ///   [`KesError::VerificationFailed`] for any failure it reports outside
+    ///   of [`KesError`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{KesAlgorithm, KesError, Sum2Kes};
+    ///
+    /// let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[2u8; 32]).unwrap();
+    /// let vk = Sum2Kes::derive_verification_key(&sk).unwrap();
+    /// let sig = Sum2Kes::sign_kes(&(), 0, b"packet", &sk).unwrap();
+    /// let sig = Sum2Kes::raw_serialize_signature_kes(&sig);
+    ///
+    /// assert_eq!(Sum2Kes::verify_kes_bytes(&(), &vk, 0, b"packet", &sig), Ok(()));
+    /// assert!(matches!(
+    ///     Sum2Kes::verify_kes_bytes(&(), &vk, 0, b"packet", &sig[1..]),
+    ///     Err(KesError::WrongLength { .. })
+    /// ));
+    /// ```
+    fn verify_kes_bytes(
+        context: &Self::Context,
+        verification_key: &[u8],
+        period: Period,
+        message: &[u8],
+        signature: &[u8],
+    ) -> core::result::Result<(), KesError> {
+        let verification_key = Self::verification_key_from_bytes(verification_key)?;
+        if signature.len() != Self::SIGNATURE_SIZE {
+            return Err(KesError::WrongLength {
+                expected: Self::SIGNATURE_SIZE,
+                actual: signature.len(),
+            });
+        }
+        let signature =
+            Self::raw_deserialize_signature_kes(signature).ok_or(KesError::InvalidEncoding)?;
+        Self::verify_kes(context, &verification_key, period, message, &signature).map_err(|err| {
+            match err {
+                CryptoError::KesError(err) => err,
+                _ => KesError::VerificationFailed,
+            }
+        })
+    }
+
+    /// Period at which `signature` is a valid signature of `message`
+    ///
+    /// Returns `None` if it is valid at no period, or if the scheme cannot
+    /// tell (the default). Sum and CompactSum schemes search the Merkle path
+    /// the signature carries, so this costs extra hashing and at most two leaf
+    /// checks, none of them recorded in [`crate::metrics`]. Used to report
+    /// [`KesError::PeriodMismatch`] from
+    /// [`diagnose_verify_failure`](Self::diagnose_verify_failure).
+    fn signed_period_kes(
+        verification_key: &Self::VerificationKey,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Option<Period> {
+        let _ = (verification_key, message, signature);
+        None
+    }
+
+    /// Explain an `error` returned by [`verify_kes`](Self::verify_kes)
+    ///
+    /// `verify_kes` stops at the first check that fails, so a signature made
+    /// at another period is rejected as a bad path or a bad leaf signature.
+    /// After a failure, this searches for the period the signature is valid
+    /// at with [`signed_period_kes`](Self::signed_period_kes) and returns
+    /// [`KesError::PeriodMismatch`] if there is one, or `error` unchanged.
+    /// The search is left to the caller so that rejecting a forged signature
+    /// costs no more than the checks themselves.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::common::error::CryptoError;
+    /// use cardano_crypto::kes::{KesAlgorithm, KesError, Sum2Kes};
+    ///
+    /// let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[2u8; 32]).unwrap();
+    /// let vk = Sum2Kes::derive_verification_key(&sk).unwrap();
+    /// let sig = Sum2Kes::sign_kes(&(), 0, b"block", &sk).unwrap();
+    ///
+    /// let err = Sum2Kes::verify_kes(&(), &vk, 1, b"block", &sig).unwrap_err();
+    /// assert_eq!(
+    ///     Sum2Kes::diagnose_verify_failure(&vk, 1, b"block", &sig, err),
+    ///     CryptoError::KesError(KesError::PeriodMismatch { requested: 1, signed: 0 })
+    /// );
+    /// ```
+    fn diagnose_verify_failure(
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        message: &[u8],
+        signature: &Self::Signature,
+        error: CryptoError,
+    ) -> CryptoError {
+        match Self::signed_period_kes(verification_key, message, signature) {
+            Some(signed) if signed != period => {
+                CryptoError::KesError(KesError::period_mismatch(period, signed))
+            }
+            _ => error,
+        }
+    }
+
+    /// Verify like [`verify_kes`](Self::verify_kes), skipping tree nodes
+    /// already checked with the same `cache`
+    ///
+    /// Used by [`EpochVerifier`]. Sum schemes look up and record each level's
+    /// `(vk0, vk1)` pair; other schemes ignore the cache.
+    fn verify_kes_cached(
+        context: &Self::Context,
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        message: &[u8],
+        signature: &Self::Signature,
+        cache: &mut BranchCache,
+    ) -> Result<()> {
+        let _ = cache;
+        Self::verify_kes(context, verification_key, period, message, signature)
+    }
+
+    /// Sign the Blake2b-256 digest of `message` at `period`
+    ///
+    /// The signature covers the 32-byte digest, not `message`, so it must be
+    /// checked with [`verify_kes_prehashed`](Self::verify_kes_prehashed).
+    /// See [`prehash`] for the streaming [`KesSigner`].
+    fn sign_kes_prehashed(
+        context: &Self::Context,
+        period: Period,
+        message: &[u8],
+        signing_key: &Self::SigningKey,
+    ) -> Result<Self::Signature> {
+        Self::sign_kes_digest(
+            context,
+            period,
+            &prehash::prehash_message(message),
+            signing_key,
+        )
+    }
+
+    /// Verify a signature made by [`sign_kes_prehashed`](Self::sign_kes_prehashed)
+    fn verify_kes_prehashed(
+        context: &Self::Context,
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<()> {
+        Self::verify_kes_digest(
+            context,
+            verification_key,
+            period,
+            &prehash::prehash_message(message),
+            signature,
+        )
+    }
+
+    /// Sign an already computed pre-hash `digest` at `period`
+    ///
+    /// `digest` must be [`prehash_message`](prehash::prehash_message) of the
+    /// message, the unkeyed 32-byte Blake2b-256 digest. The result is the same
+    /// signature [`sign_kes_prehashed`](Self::sign_kes_prehashed) makes from
+    /// the message itself, for callers that already hold the digest and
+    /// should not hash a large payload twice.
+    ///
+    /// This is not the raw-message API under another name: cardano-node signs
+    /// block header bodies with plain [`sign_kes`](Self::sign_kes), while
+    /// this signs [`PREHASH_DOMAIN`](prehash::PREHASH_DOMAIN) followed by
+    /// `digest`. Because of the tag a digest signature only verifies with
+    /// [`verify_kes_digest`](Self::verify_kes_digest) or
+    /// [`verify_kes_prehashed`](Self::verify_kes_prehashed), and a plain
+    /// signature on the 32 digest bytes is not accepted by either.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::prehash::prehash_message;
+    /// use cardano_crypto::kes::{KesAlgorithm, Sum2Kes};
+    ///
+    /// # fn main() -> cardano_crypto::common::Result<()> {
+    /// let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[6u8; 32])?;
+    /// let vk = Sum2Kes::derive_verification_key(&sk)?;
+    ///
+    /// let digest = prehash_message(b"large header body");
+    /// let sig = Sum2Kes::sign_kes_digest(&(), 0, &digest, &sk)?;
+    /// Sum2Kes::verify_kes_digest(&(), &vk, 0, &digest, &sig)?;
+    /// Sum2Kes::verify_kes_prehashed(&(), &vk, 0, b"large header body", &sig)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn sign_kes_digest(
+        context: &Self::Context,
+        period: Period,
+        digest: &[u8; prehash::PREHASH_SIZE],
+        signing_key: &Self::SigningKey,
+    ) -> Result<Self::Signature> {
+        let signed = prehash::prehash_signed_bytes(digest);
+        Self::sign_kes(context, period, &signed, signing_key)
+    }
+
+    /// Verify a pre-hashed signature against an already computed `digest`
+    ///
+    /// Accepts exactly the signatures
+    /// [`verify_kes_prehashed`](Self::verify_kes_prehashed) accepts for the
+    /// message that `digest` was computed from; see
+    /// [`sign_kes_digest`](Self::sign_kes_digest) for the digest format.
+    fn verify_kes_digest(
+        context: &Self::Context,
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        digest: &[u8; prehash::PREHASH_SIZE],
+        signature: &Self::Signature,
+    ) -> Result<()> {
+        let signed = prehash::prehash_signed_bytes(digest);
+        Self::verify_kes(context, verification_key, period, &signed, signature)
+    }
+
+    /// Verify a pre-hashed signature over a message given in parts
+    ///
+    /// The chunks are fed to the pre-hash in order, so this accepts exactly
+    /// what [`verify_kes_prehashed`](Self::verify_kes_prehashed) accepts for
+    /// their concatenation, without allocating it. Meant for framed messages
+    /// such as multi-part headers; like every pre-hashed check it does not
+    /// accept plain [`sign_kes`](Self::sign_kes) signatures.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{KesAlgorithm, Sum2Kes};
+    ///
+    /// # fn main() -> cardano_crypto::common::Result<()> {
+    /// let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[3u8; 32])?;
+    /// let vk = Sum2Kes::derive_verification_key(&sk)?;
+    /// let sig = Sum2Kes::sign_kes_prehashed(&(), 0, b"header body", &sk)?;
+    ///
+    /// let parts: [&[u8]; 3] = [b"header", b" ", b"body"];
+    /// Sum2Kes::verify_kes_chunks(&(), &vk, 0, parts, &sig)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn verify_kes_chunks<'m>(
+        context: &Self::Context,
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        chunks: impl IntoIterator<Item = &'m [u8]>,
+        signature: &Self::Signature,
+    ) -> Result<()> {
+        Self::verify_kes_digest(
+            context,
+            verification_key,
+            period,
+            &prehash::prehash_chunks(chunks),
+            signature,
+        )
+    }
+
+    /// Verify a set of signatures claimed by one verification key
+    ///
+    /// Each entry is `(period, message, signature)`. Rather than stopping at the
+    /// first failure, every entry is checked and the outcome summarised in an
+    /// [`EpochReport`]: valid/invalid counts, the periods covered by valid
+    /// signatures, and any periods claimed more than once.
+    fn verify_epoch(
+        context: &Self::Context,
+        verification_key: &Self::VerificationKey,
+        entries: &[(Period, &[u8], &Self::Signature)],
+    ) -> EpochReport {
+        let mut report = EpochReport::default();
+        let mut seen = alloc::collections::BTreeSet::new();
+
+        for (index, (period, message, signature)) in entries.iter().enumerate() {
+            if !seen.insert(*period) {
+                report.duplicates.insert(*period);
+            }
+            match Self::verify_kes(context, verification_key, *period, message, signature) {
+                Ok(()) => {
+                    report.valid += 1;
+                    report.covered.insert(*period);
+                }
+                Err(_) => {
+                    report.invalid += 1;
+                    report.invalid_indices.push(index);
+                }
+            }
+        }
+
+        report
+    }
+
+    /// Verify a batch of signatures claimed by one verification key
+    ///
+    /// Each entry is `(period, message, signature)`. Every entry is checked and
+    /// the indices of the ones that fail are returned in ascending order; an
+    /// empty vector means the whole batch verified. The outcome for an entry
+    /// depends only on that entry, never on its position in the batch.
+    #[must_use]
+    fn verify_batch_kes(
+        context: &Self::Context,
+        verification_key: &Self::VerificationKey,
+        batch: &[(Period, &[u8], &Self::Signature)],
+    ) -> Vec<usize> {
+        batch
+            .iter()
+            .enumerate()
+            .filter(|(_, (period, message, signature))| {
+                Self::verify_kes(context, verification_key, *period, message, signature).is_err()
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Sign `message` at `period` and check the signature against the key's own
+    /// verification key
+    ///
+    /// Intended as a health check after loading a key from storage: a key
+    /// whose secret state no longer matches its embedded public tree (bit rot,
+    /// a truncated file, a mismatched backup) fails here rather than producing
+    /// invalid block signatures.
+    ///
+    /// # Errors
+    ///
+    /// Returns the signing or verification error encountered.
+    fn self_test_kes(
+        context: &Self::Context,
+        signing_key: &Self::SigningKey,
+        period: Period,
+        message: &[u8],
+    ) -> Result<()> {
+        let verification_key = Self::derive_verification_key(signing_key)?;
+        let signature = Self::sign_kes(context, period, message, signing_key)?;
+        Self::verify_kes(context, &verification_key, period, message, &signature)
+    }
+
+    /// Period the signing key currently signs for
+    ///
+    /// This is read from the evolution state embedded in the key (which subtree
+    /// is active at each level of the tree), so it always agrees with the
+    /// evolutions applied through [`update_kes`](Self::update_kes) and never
+    /// allocates. A freshly generated key reports period 0.
+    fn current_period_kes(signing_key: &Self::SigningKey) -> Period;
+
+    /// Number of periods the signing key can still sign for, including the current one
+    ///
+    /// Derived from [`current_period_kes`](Self::current_period_kes); useful for
+    /// alerting before a key has to be rotated.
+    fn remaining_periods_kes(signing_key: &Self::SigningKey) -> Period {
+        Self::total_periods() - Self::current_period_kes(signing_key)
+    }
+
     /// Update signing key to next period (returns None if key expired)
+    ///
+    /// Evolution is what makes the scheme forward secure, so the secrets the
+    /// evolved key no longer needs are wiped before this returns: the spent
+    /// leaf key, any subtree key left behind, and a right-subtree seed once
+    /// it has been expanded. They are released through
+    /// [`forget_signing_key_kes`](Self::forget_signing_key_kes), exactly as
+    /// an expired key is.
     fn update_kes(
         context: &Self::Context,
         signing_key: Self::SigningKey,
         period: Period,
     ) -> Result<Option<Self::SigningKey>>;
 
+    /// Evolve `signing_key` from `period` to the next period in place
+    ///
+    /// Returns `Ok(true)` once the key has evolved, and `Ok(false)` if
+    /// `period` is the last one. In that case the key has expired and is
+    /// wiped where it lies, as [`update_kes`](Self::update_kes) forgets it:
+    /// the built-in schemes zero the leaf key and drop any subtree seed. The
+    /// wiped key signs nothing that verifies and should simply be dropped.
+    /// On error the key is unchanged, so the only copy is never lost on a
+    /// failed update.
+    ///
+    /// Implementations must wipe the expired key's secrets before returning
+    /// `Ok(false)`; leaves built on a DSIGN algorithm do it with
+    /// [`forget_signing_key_in_place`](crate::common::traits::DsignAlgorithm::forget_signing_key_in_place).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{KesAlgorithm, Sum1Kes};
+    ///
+    /// # fn main() -> cardano_crypto::common::Result<()> {
+    /// let mut sk = Sum1Kes::gen_key_kes_from_seed_bytes(&[8u8; 32])?;
+    /// assert!(Sum1Kes::update_kes_in_place(&(), &mut sk, 0)?);
+    /// assert_eq!(Sum1Kes::current_period_kes(&sk), 1);
+    ///
+    /// // Expired and wiped
+    /// assert!(!Sum1Kes::update_kes_in_place(&(), &mut sk, 1)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn update_kes_in_place(
+        context: &Self::Context,
+        signing_key: &mut Self::SigningKey,
+        period: Period,
+    ) -> Result<bool>;
+
+    /// Do the work of one [`update_kes`](Self::update_kes) without a key
+    ///
+    /// With the `constant-time` feature the Sum and CompactSum updates call
+    /// this to pad every evolution step to the same cost, so the time an
+    /// update takes does not reveal which subtree boundary the period crosses.
+    /// The default does nothing, which matches schemes like `SingleKes` whose
+    /// update is only bookkeeping. Schemes that regenerate subtrees while
+    /// evolving should override it with the worst-case work of one update.
+    #[cfg(feature = "constant-time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "constant-time")))]
+    fn pad_update_kes() {}
+
+    /// Evolve a signing key from period `from` directly to period `to`
+    ///
+    /// The result is identical to calling [`update_kes`](Self::update_kes) for
+    /// every period in `from..to`, but sum constructions skip straight to the
+    /// right subtree when the target lies there instead of evolving the left
+    /// subtree period by period. Returns `Ok(None)` (and forgets the key) if
+    /// `to` is at or beyond [`total_periods`](Self::total_periods).
+    ///
+    /// `from` must be the key's [`current_period_kes`](Self::current_period_kes).
+    /// It is checked before the key is evolved, since starting from any other
+    /// period would apply the wrong updates and could discard the subtree
+    /// seeds the key still needs.
+    ///
+    /// # Errors
+    ///
+    /// - [`KesError::UpdateFromWrongPeriod`] if the key is not at `from`
+    /// - [`KesError::UpdateFailed`] if `to` is before `from`, since keys can
+    ///   only evolve forward
+    ///
+    /// The key is dropped, and wiped, on either error.
+    fn update_kes_to(
+        context: &Self::Context,
+        signing_key: Self::SigningKey,
+        from: Period,
+        to: Period,
+    ) -> Result<Option<Self::SigningKey>> {
+        let current = Self::current_period_kes(&signing_key);
+        if from != current {
+            return Err(CryptoError::KesError(KesError::UpdateFromWrongPeriod {
+                from,
+                current,
+            }));
+        }
+        if to >= Self::total_periods() {
+            Self::forget_signing_key_kes(signing_key);
+            return Ok(None);
+        }
+        if to < from {
+            return Err(CryptoError::KesError(KesError::UpdateFailed));
+        }
+
+        let mut signing_key = signing_key;
+        for period in from..to {
+            match Self::update_kes(context, signing_key, period)? {
+                Some(updated) => signing_key = updated,
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(signing_key))
+    }
+
+    /// Regenerate the signing key `seed` reaches at `period`
+    ///
+    /// For recovering a key from its backed-up seed. The result is
+    /// byte-identical to generating the key at period 0 and calling
+    /// [`update_kes`](Self::update_kes) `period` times. It is built with
+    /// [`update_kes_to`](Self::update_kes_to), so sum constructions generate
+    /// only the subtrees on the path to `period`, and every intermediate key
+    /// is forgotten as it is replaced.
+    ///
+    /// # Errors
+    ///
+    /// [`KesError::PeriodOutOfRange`] if `period` is not below
+    /// [`total_periods`](Self::total_periods), and any error of key
+    /// generation or `update_kes_to`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{KesAlgorithm, Sum3Kes};
+    ///
+    /// # fn main() -> cardano_crypto::common::Result<()> {
+    /// let sk = Sum3Kes::gen_key_kes_at_period(&(), &[7u8; 32], 5)?;
+    /// assert_eq!(Sum3Kes::current_period_kes(&sk), 5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn gen_key_kes_at_period(
+        context: &Self::Context,
+        seed: &[u8],
+        period: Period,
+    ) -> Result<Self::SigningKey> {
+        if period > Self::max_signable_period() {
+            return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+                period,
+                max_period: Self::max_signable_period(),
+            }));
+        }
+        let signing_key = Self::gen_key_kes_from_seed_bytes(seed)?;
+        Self::update_kes_to(context, signing_key, 0, period)?
+            .ok_or(CryptoError::KesError(KesError::KeyExpired))
+    }
+
+    /// Compare two verification keys in constant time
+    ///
+    /// Compares the raw serializations, so the time taken does not depend on
+    /// where the keys differ. Use this when checking a locally derived key
+    /// against a registered one (e.g. from an operational certificate); the
+    /// `Vec<u8>` keys of the Sum constructions compare with ordinary `==`
+    /// otherwise.
+    fn ct_eq_verification_key(a: &Self::VerificationKey, b: &Self::VerificationKey) -> bool {
+        crate::hash::constant_time_eq(
+            &Self::raw_serialize_verification_key_kes(a),
+            &Self::raw_serialize_verification_key_kes(b),
+        )
+    }
+
+    /// Blake2b-224 hash of the raw verification key
+    ///
+    /// This is cardano-node's `hashVerKeyKES` at `Blake2b_224`, which
+    /// hashes the same bytes as
+    /// [`raw_serialize_verification_key_kes`](Self::raw_serialize_verification_key_kes).
+    /// Wallets and explorers use it to index KES keys.
+    fn verification_key_hash(verification_key: &Self::VerificationKey) -> [u8; 28] {
+        use crate::hash::{Blake2b224, HashAlgorithm};
+
+        Blake2b224::hash(&Self::raw_serialize_verification_key_kes(verification_key))
+            .try_into()
+            .expect("Blake2b-224 output is 28 bytes")
+    }
+
     /// Serialize verification key
     #[cfg(feature = "alloc")]
     fn raw_serialize_verification_key_kes(key: &Self::VerificationKey) -> Vec<u8>;
 
     /// Deserialize verification key
+    ///
+    /// Never panics: input of any length or content that is not a valid key
+    /// gives `None`, so untrusted bytes (e.g. from an opcert) can be passed
+    /// straight in.
     fn raw_deserialize_verification_key_kes(bytes: &[u8]) -> Option<Self::VerificationKey>;
 
+    /// Parse a raw verification key, saying why it was rejected
+    ///
+    /// The checked counterpart of
+    /// [`raw_deserialize_verification_key_kes`](Self::raw_deserialize_verification_key_kes)
+    /// for `?`-based parsing. The Sum and CompactSum keys are plain
+    /// `Vec<u8>`, which cannot take a length-checked `TryFrom<&[u8]>`, so this
+    /// is the one parser that works for every scheme.
+    ///
+    /// # Errors
+    ///
+    /// [`KesError::WrongLength`] if `bytes` is not
+    /// [`VERIFICATION_KEY_SIZE`](Self::VERIFICATION_KEY_SIZE) long, and
+    /// [`KesError::InvalidEncoding`] if it is but does not decode, e.g. an
+    /// Ed25519 key that is not a curve point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{KesAlgorithm, KesError, Sum6Kes};
+    ///
+    /// # fn main() -> Result<(), KesError> {
+    /// let vk = Sum6Kes::verification_key_from_bytes(&[7u8; 32])?;
+    /// assert_eq!(vk, vec![7u8; 32]);
+    ///
+    /// assert_eq!(
+    ///     Sum6Kes::verification_key_from_bytes(&[7u8; 31]),
+    ///     Err(KesError::WrongLength { expected: 32, actual: 31 })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn verification_key_from_bytes(
+        bytes: &[u8],
+    ) -> core::result::Result<Self::VerificationKey, KesError> {
+        if bytes.len() != Self::VERIFICATION_KEY_SIZE {
+            return Err(KesError::WrongLength {
+                expected: Self::VERIFICATION_KEY_SIZE,
+                actual: bytes.len(),
+            });
+        }
+        Self::raw_deserialize_verification_key_kes(bytes).ok_or(KesError::InvalidEncoding)
+    }
+
     /// Serialize signature
     #[cfg(feature = "alloc")]
     fn raw_serialize_signature_kes(signature: &Self::Signature) -> Vec<u8>;
 
     /// Deserialize signature
+    ///
+    /// Never panics: input of any length or content that is not a valid
+    /// signature gives `None`.
     fn raw_deserialize_signature_kes(bytes: &[u8]) -> Option<Self::Signature>;
 
+    /// Write the raw verification key into the front of `out`
+    ///
+    /// Returns the number of bytes written, always
+    /// [`VERIFICATION_KEY_SIZE`](Self::VERIFICATION_KEY_SIZE). The bytes equal
+    /// [`raw_serialize_verification_key_kes`](Self::raw_serialize_verification_key_kes),
+    /// but the built-in schemes allocate nothing, so a fixed stack or static
+    /// buffer will do. The provided method copies from that serializer.
+    ///
+    /// # Errors
+    ///
+    /// [`KesError::BufferTooSmall`] if `out` is shorter than the key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{KesAlgorithm, Sum6Kes};
+    ///
+    /// let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[8u8; 32]).unwrap();
+    /// let vk = Sum6Kes::derive_verification_key(&sk).unwrap();
+    ///
+    /// let mut out = [0u8; Sum6Kes::VERIFICATION_KEY_SIZE];
+    /// let written = Sum6Kes::raw_serialize_verification_key_kes_into(&vk, &mut out).unwrap();
+    /// assert_eq!(&out[..written], &vk[..]);
+    /// ```
+    fn raw_serialize_verification_key_kes_into(
+        key: &Self::VerificationKey,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        output_prefix(out, Self::VERIFICATION_KEY_SIZE)?
+            .copy_from_slice(&Self::raw_serialize_verification_key_kes(key));
+        Ok(Self::VERIFICATION_KEY_SIZE)
+    }
+
+    /// Write the raw signature into the front of `out`
+    ///
+    /// The allocation-free counterpart of
+    /// [`raw_serialize_signature_kes`](Self::raw_serialize_signature_kes);
+    /// returns [`SIGNATURE_SIZE`](Self::SIGNATURE_SIZE). The provided method
+    /// copies from that serializer.
+    ///
+    /// # Errors
+    ///
+    /// [`KesError::BufferTooSmall`] if `out` is shorter than the signature.
+    fn raw_serialize_signature_kes_into(
+        signature: &Self::Signature,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        output_prefix(out, Self::SIGNATURE_SIZE)?
+            .copy_from_slice(&Self::raw_serialize_signature_kes(signature));
+        Ok(Self::SIGNATURE_SIZE)
+    }
+
     /// Securely forget/zeroize signing key
+    ///
+    /// Every secret byte is wiped before its memory is released: the leaf
+    /// DSIGN secret, and for the Sum constructions the right-subtree seed at
+    /// every level that has not yet been consumed by an evolution. The
+    /// verification keys cached in the key are public and are simply dropped.
     fn forget_signing_key_kes(signing_key: Self::SigningKey);
 }
 
+/// Shared body of the `from_bytes` methods on signature types
+pub(crate) fn signature_from_bytes<K: KesAlgorithm>(
+    bytes: &[u8],
+) -> core::result::Result<K::Signature, KesError> {
+    if bytes.len() != K::SIGNATURE_SIZE {
+        return Err(KesError::WrongLength {
+            expected: K::SIGNATURE_SIZE,
+            actual: bytes.len(),
+        });
+    }
+    K::raw_deserialize_signature_kes(bytes).ok_or(KesError::InvalidEncoding)
+}
+
+/// Tag naming the scheme `K`, with its tree hash and seed expansion
+///
+/// Written into key files and authenticated with sealed keys, so that a key
+/// stored for one scheme is not read back as another of the same size.
+#[cfg(any(feature = "std", feature = "encrypted-storage"))]
+pub(crate) fn algorithm_tag<K: KesAlgorithm>() -> alloc::string::String {
+    let mut tag = alloc::string::String::from(K::ALGORITHM_NAME);
+    if let Some((hash, expansion)) = K::TREE_HASH {
+        tag.push('/');
+        tag.push_str(hash);
+        if expansion == SeedExpansion::Legacy {
+            tag.push_str("/legacy");
+        }
+    }
+    tag
+}
+
+/// Generate and forget a throwaway `D` key, the cost of one subtree transition
+///
+/// Used by the Sum updates under `constant-time` on every step that does not
+/// regenerate a subtree.
+#[cfg(feature = "constant-time")]
+pub(crate) fn pad_subtree_generation<D: KesAlgorithm>() {
+    let seed = zeroize::Zeroizing::new(alloc::vec![0u8; D::SEED_SIZE]);
+    if let Ok(sk) = D::gen_key_kes_from_seed_bytes(core::hint::black_box(&seed)) {
+        D::forget_signing_key_kes(core::hint::black_box(sk));
+    }
+}
+
+/// [`KesError::InvalidSeedExpansion`] unless `H` expands seeds to `D`'s seed size
+///
+/// Checked by Sum key generation before any subtree is built; see the
+/// compatibility table in [`hash`].
+pub(crate) fn check_seed_expansion<D: KesAlgorithm, H: KesHashAlgorithm>() -> Result<()> {
+    if H::OUTPUT_SIZE == D::SEED_SIZE {
+        Ok(())
+    } else {
+        Err(CryptoError::KesError(KesError::InvalidSeedExpansion {
+            hash_output: H::OUTPUT_SIZE,
+            seed_size: D::SEED_SIZE,
+        }))
+    }
+}
+
+/// Shallowest subtree that `parallel` key generation hands to another thread
+///
+/// A depth-3 subtree holds eight Ed25519 leaves, enough work to outweigh the
+/// cost of a rayon task.
+#[cfg(feature = "parallel")]
+const PARALLEL_MIN_DEPTH: u32 = 3;
+
+/// Left signing key and both verification keys of a Sum node with seeds `r0`, `r1`
+///
+/// The right subtree only contributes its verification key. Under `parallel`,
+/// subtrees at least [`PARALLEL_MIN_DEPTH`] deep are built on the rayon pool,
+/// the right one alongside the left; both paths run the same derivations, so
+/// the keys are identical.
+pub(crate) fn gen_subtrees<D: KesAlgorithm>(
+    r0: &[u8],
+    r1: &[u8],
+) -> Result<(D::SigningKey, D::VerificationKey, D::VerificationKey)> {
+    #[cfg(feature = "parallel")]
+    if D::TREE_DEPTH >= PARALLEL_MIN_DEPTH {
+        let mut right = None;
+        let left = rayon::in_place_scope(|scope| {
+            scope.spawn(|_| {
+                right = Some(
+                    D::verification_key_from_seed(r1)
+                        .map(|vk| D::raw_serialize_verification_key_kes(&vk)),
+                );
+            });
+            D::gen_key_and_vk_kes_from_seed_bytes(r0)
+        });
+        let (sk0, vk0) = left?;
+        let vk1 = right
+            .expect("scope waits for the right subtree")
+            .and_then(|bytes| {
+                D::raw_deserialize_verification_key_kes(&bytes)
+                    .ok_or(CryptoError::KesError(KesError::InvalidEncoding))
+            });
+        return match vk1 {
+            Ok(vk1) => Ok((sk0, vk0, vk1)),
+            Err(err) => {
+                D::forget_signing_key_kes(sk0);
+                Err(err)
+            }
+        };
+    }
+
+    let (sk0, vk0) = D::gen_key_and_vk_kes_from_seed_bytes(r0)?;
+    match D::verification_key_from_seed(r1) {
+        Ok(vk1) => Ok((sk0, vk0, vk1)),
+        Err(err) => {
+            D::forget_signing_key_kes(sk0);
+            Err(err)
+        }
+    }
+}
+
+/// Raw verification keys of the two subtrees of a Sum node with seeds `r0`, `r1`
+///
+/// Under `parallel`, subtrees at least [`PARALLEL_MIN_DEPTH`] deep are hashed
+/// up on the rayon pool.
+pub(crate) fn subtree_verification_keys<D: KesAlgorithm>(
+    r0: &[u8],
+    r1: &[u8],
+) -> Result<(Vec<u8>, Vec<u8>)> {
+    let raw_vk = |seed: &[u8]| {
+        D::verification_key_from_seed(seed).map(|vk| D::raw_serialize_verification_key_kes(&vk))
+    };
+
+    #[cfg(feature = "parallel")]
+    if D::TREE_DEPTH >= PARALLEL_MIN_DEPTH {
+        let (vk0, vk1) = rayon::join(|| raw_vk(r0), || raw_vk(r1));
+        return Ok((vk0?, vk1?));
+    }
+
+    Ok((raw_vk(r0)?, raw_vk(r1)?))
+}
+
+/// Front `size` bytes of `out`, for the `raw_serialize_*_into` methods
+pub(crate) fn output_prefix(
+    out: &mut [u8],
+    size: usize,
+) -> core::result::Result<&mut [u8], KesError> {
+    let available = out.len();
+    out.get_mut(..size).ok_or(KesError::BufferTooSmall {
+        required: size,
+        available,
+    })
+}
+
+/// Serialization of KES signing keys
+///
+/// Mirrors `UnsoundPureKESAlgorithm` from cardano-base: exporting the secret
+/// state of a KES key undermines forward security if the bytes outlive the
+/// evolution they describe, so this is kept out of [`KesAlgorithm`] and only
+/// used for persistence and test tooling.
+///
+/// The serialized form is exactly [`KesAlgorithm::SIGNING_KEY_SIZE`] bytes and
+/// is returned in a [`zeroize::Zeroizing`] buffer.
+pub trait UnsoundKesAlgorithm: KesAlgorithm {
+    /// Serialize the complete signing key state
+    fn raw_serialize_signing_key_kes(signing_key: &Self::SigningKey)
+        -> zeroize::Zeroizing<Vec<u8>>;
+
+    /// Write the complete signing key state into the front of `out`
+    ///
+    /// The allocation-free counterpart of
+    /// [`raw_serialize_signing_key_kes`](Self::raw_serialize_signing_key_kes);
+    /// returns [`SIGNING_KEY_SIZE`](KesAlgorithm::SIGNING_KEY_SIZE). The
+    /// caller owns `out` and must wipe it once the key has been stored. The
+    /// provided method copies from that serializer, whose buffer is wiped.
+    ///
+    /// # Errors
+    ///
+    /// [`KesError::BufferTooSmall`] if `out` is shorter than the key.
+    fn raw_serialize_signing_key_kes_into(
+        signing_key: &Self::SigningKey,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        output_prefix(out, Self::SIGNING_KEY_SIZE)?
+            .copy_from_slice(&Self::raw_serialize_signing_key_kes(signing_key));
+        Ok(Self::SIGNING_KEY_SIZE)
+    }
+
+    /// Deserialize a signing key previously produced by
+    /// [`raw_serialize_signing_key_kes`](Self::raw_serialize_signing_key_kes)
+    ///
+    /// Never panics: malformed input of any length gives `None`.
+    fn raw_deserialize_signing_key_kes(bytes: &[u8]) -> Option<Self::SigningKey>;
+
+    /// Deserialize a signing key and check it belongs to `expected_vk`
+    ///
+    /// Derives the verification key of the loaded key and compares its raw
+    /// serialization with `expected_vk` in constant time. Pass the key from
+    /// the operational certificate to catch a signing key restored next to
+    /// the wrong certificate, before it signs a block the network would reject.
+    ///
+    /// # Errors
+    ///
+    /// - [`KesError::WrongLength`] unless `bytes` is exactly
+    ///   [`SIGNING_KEY_SIZE`](KesAlgorithm::SIGNING_KEY_SIZE) long
+    /// - [`KesError::InvalidEncoding`] if the bytes do not decode
+    /// - [`KesError::VerificationKeyMismatch`] if the key derives another
+    ///   verification key; the loaded key is forgotten
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{KesAlgorithm, KesError, Sum2Kes, UnsoundKesAlgorithm};
+    ///
+    /// let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[9u8; 32]).unwrap();
+    /// let vk = Sum2Kes::raw_serialize_verification_key_kes(&Sum2Kes::derive_verification_key(&sk).unwrap());
+    /// let bytes = Sum2Kes::raw_serialize_signing_key_kes(&sk);
+    ///
+    /// assert!(Sum2Kes::load_and_verify(&bytes, &vk).is_ok());
+    /// assert_eq!(
+    ///     Sum2Kes::load_and_verify(&bytes, &[0u8; 32]).err(),
+    ///     Some(KesError::VerificationKeyMismatch)
+    /// );
+    /// ```
+    fn load_and_verify(
+        bytes: &[u8],
+        expected_vk: &[u8],
+    ) -> core::result::Result<Self::SigningKey, KesError> {
+        if bytes.len() != Self::SIGNING_KEY_SIZE {
+            return Err(KesError::WrongLength {
+                expected: Self::SIGNING_KEY_SIZE,
+                actual: bytes.len(),
+            });
+        }
+        let signing_key =
+            Self::raw_deserialize_signing_key_kes(bytes).ok_or(KesError::InvalidEncoding)?;
+        let matches = Self::derive_verification_key(&signing_key).is_ok_and(|vk| {
+            crate::hash::constant_time_eq(
+                &Self::raw_serialize_verification_key_kes(&vk),
+                expected_vk,
+            )
+        });
+        if matches {
+            Ok(signing_key)
+        } else {
+            Self::forget_signing_key_kes(signing_key);
+            Err(KesError::VerificationKeyMismatch)
+        }
+    }
+
+    /// Deep-copy a signing key, including all of its secret state
+    ///
+    /// **Test tooling only.** Forward security relies on there being exactly
+    /// one copy of the key, which is wiped as it evolves; a clone that
+    /// outlives an evolution can still sign for the periods the original has
+    /// already forgotten. This lets a test branch a key to try several
+    /// evolution paths without re-deriving it from the seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scheme's raw serialization does not round-trip.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{KesAlgorithm, Sum2Kes, UnsoundKesAlgorithm};
+    ///
+    /// let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[9u8; 32]).unwrap();
+    /// let branch = Sum2Kes::unsafe_clone_signing_key(&sk);
+    /// let branch = Sum2Kes::update_kes_to(&(), branch, 0, 3).unwrap().unwrap();
+    ///
+    /// assert_eq!(Sum2Kes::current_period_kes(&sk), 0);
+    /// assert_eq!(Sum2Kes::current_period_kes(&branch), 3);
+    /// ```
+    fn unsafe_clone_signing_key(signing_key: &Self::SigningKey) -> Self::SigningKey {
+        let bytes = Self::raw_serialize_signing_key_kes(signing_key);
+        Self::raw_deserialize_signing_key_kes(&bytes)
+            .expect("raw signing key serialization round-trips")
+    }
+}
+
+/// Leaf check used by [`Ed25519LeafKes`]: `(verification key, message, signature) -> valid`
+pub type Ed25519LeafVerifier<'a> = dyn Fn(&[u8; 32], &[u8], &[u8; 64]) -> bool + 'a;
+
+/// KES schemes whose leaves are Ed25519 signatures
+///
+/// Some deployments must route every Ed25519 check through a hardware module
+/// or a separately audited library. Implementors navigate the tree and check
+/// the Merkle hashes themselves, and hand only the leaf check to `verifier`,
+/// which receives the leaf verification key, the message and the leaf
+/// signature and returns whether it is valid.
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::common::traits::DsignAlgorithm;
+/// use cardano_crypto::dsign::Ed25519;
+/// use cardano_crypto::kes::{Ed25519LeafKes, KesAlgorithm, Sum2Kes};
+///
+/// # fn main() -> cardano_crypto::common::Result<()> {
+/// let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[5u8; 32])?;
+/// let vk = Sum2Kes::derive_verification_key(&sk)?;
+/// let sig = Sum2Kes::sign_kes(&(), 0, b"block", &sk)?;
+///
+/// // Stand-in for an external verifier
+/// let verifier = |leaf_vk: &[u8; 32], message: &[u8], leaf_sig: &[u8; 64]| {
+///     match (
+///         Ed25519::deserialize_verification_key(leaf_vk),
+///         Ed25519::deserialize_signature(leaf_sig),
+///     ) {
+///         (Ok(leaf_vk), Ok(leaf_sig)) => Ed25519::verify(message, &leaf_sig, &leaf_vk).is_ok(),
+///         _ => false,
+///     }
+/// };
+/// Sum2Kes::verify_kes_with_ed25519(&verifier, &(), &vk, 0, b"block", &sig)?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait Ed25519LeafKes: KesAlgorithm {
+    /// Verify like [`KesAlgorithm::verify_kes`], delegating the leaf Ed25519 check
+    ///
+    /// # Errors
+    ///
+    /// Returns the same tree-level errors as `verify_kes`, and
+    /// [`KesError::BadEd25519Signature`] when `verifier` rejects the leaf.
+    fn verify_kes_with_ed25519(
+        verifier: &Ed25519LeafVerifier<'_>,
+        context: &Self::Context,
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<()>;
+
+    /// The Ed25519 signature at the leaf of `signature`
+    ///
+    /// For cross-checking leaf signing against a standalone Ed25519
+    /// implementation, e.g. while bringing up test vectors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{CompactSum2Kes, Ed25519LeafKes, KesAlgorithm};
+    ///
+    /// # fn main() -> cardano_crypto::common::Result<()> {
+    /// let sk = CompactSum2Kes::gen_key_kes_from_seed_bytes(&[5u8; 32])?;
+    /// let sig = CompactSum2Kes::sign_kes(&(), 0, b"block", &sk)?;
+    ///
+    /// let bytes = CompactSum2Kes::raw_serialize_signature_kes(&sig);
+    /// assert_eq!(CompactSum2Kes::leaf_signature(&sig), bytes[..64]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn leaf_signature(signature: &Self::Signature) -> [u8; 64];
+
+    /// The leaf and authentication path `signature` claims for `period`
+    ///
+    /// Reads the leaf verification key, the leaf signature and one sibling
+    /// key per level out of `signature`, for verification shims built outside
+    /// this crate; see [`merkle`] for what they can and cannot cover.
+    /// `verification_key` is only used as the leaf key of a bare
+    /// single-period scheme, whose signature does not carry it. Nothing is
+    /// checked against it, use [`MerklePath::root_matches`] for that.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KesError::PeriodOutOfRange`] if `period` is not below
+    /// [`total_periods`](KesAlgorithm::total_periods).
+    fn merkle_path(
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        signature: &Self::Signature,
+    ) -> Result<MerklePath>;
+
+    /// Verify like [`KesAlgorithm::verify_kes`], returning a [`VerifyReceipt`] on success
+    ///
+    /// The receipt is built from the checked signature, for audit logs that
+    /// correlate blocks by key or leaf; see [`receipt`].
+    ///
+    /// # Errors
+    ///
+    /// The [`KesError`] `verify_kes` reports.
+    fn verify_kes_with_receipt(
+        context: &Self::Context,
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> core::result::Result<VerifyReceipt, KesError> {
+        let kes_error = |err| match err {
+            CryptoError::KesError(err) => err,
+            _ => KesError::VerificationFailed,
+        };
+        Self::verify_kes(context, verification_key, period, message, signature)
+            .map_err(kes_error)?;
+        let path = Self::merkle_path(verification_key, period, signature).map_err(kes_error)?;
+
+        Ok(VerifyReceipt {
+            period,
+            verification_key_hash: Self::verification_key_hash(verification_key),
+            leaf_verification_key: path.leaf_verification_key,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -208,4 +1819,842 @@ mod tests {
         let period: Period = 42;
         assert_eq!(period, 42u64);
     }
+
+    typed_tests! {
+        fn verification_keys_from_same_seed_dedup_in_hash_set<K>() for {
+            single: SingleKes,
+            compact_single: CompactSingleKes<crate::dsign::Ed25519>,
+            sum6: Sum6Kes,
+            compact_sum6: CompactSum6Kes,
+        } {
+            use std::collections::HashSet;
+
+            let first =
+                K::derive_verification_key(&K::gen_key_kes_from_seed_bytes(&[61u8; 32]).unwrap())
+                    .unwrap();
+            let second = K::verification_key_from_seed(&[61u8; 32]).unwrap();
+            let other = K::verification_key_from_seed(&[62u8; 32]).unwrap();
+
+            let keys: HashSet<_> = [first, second, other].into_iter().collect();
+            assert_eq!(keys.len(), 2, "{}", K::ALGORITHM_NAME);
+        }
+    }
+
+    #[test]
+    fn signing_key_debug_redacts_secrets() {
+        let seed = [63u8; 32];
+        let seed_hex = crate::common::hex::encode(&seed);
+
+        let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let sk = Sum6Kes::update_kes_to(&(), sk, 0, 3).unwrap().unwrap();
+        let debug = format!("{sk:?}");
+        assert_eq!(
+            debug,
+            r#"SumSigningKey { algorithm: "Sum6Kes", period: 3, .. }"#
+        );
+        assert!(!debug.contains(&seed_hex));
+
+        let sk = CompactSum6Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let debug = format!("{sk:?}");
+        assert_eq!(
+            debug,
+            r#"CompactSumSigningKey { algorithm: "CompactSum6Kes", period: 0, .. }"#
+        );
+        assert!(!debug.contains(&seed_hex));
+
+        let sk = <SingleKes>::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        assert!(!format!("{sk:?}").contains(&seed_hex));
+    }
+
+    typed_tests! {
+        fn verification_key_from_bytes_checks_length<K>() for {
+            single: SingleKes,
+            compact_single: CompactSingleKes<crate::dsign::Ed25519>,
+            sum6: Sum6Kes,
+            compact_sum6: CompactSum6Kes,
+        } {
+            let sk = K::gen_key_kes_from_seed_bytes(&[65u8; 32]).unwrap();
+            let bytes =
+                K::raw_serialize_verification_key_kes(&K::derive_verification_key(&sk).unwrap());
+
+            let vk = K::verification_key_from_bytes(&bytes).unwrap();
+            assert_eq!(K::raw_serialize_verification_key_kes(&vk), bytes);
+
+            for len in [
+                0,
+                K::VERIFICATION_KEY_SIZE - 1,
+                K::VERIFICATION_KEY_SIZE + 1,
+            ] {
+                let mut wrong = bytes.clone();
+                wrong.resize(len, 0);
+                assert_eq!(
+                    K::verification_key_from_bytes(&wrong).err(),
+                    Some(KesError::WrongLength {
+                        expected: K::VERIFICATION_KEY_SIZE,
+                        actual: len,
+                    })
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn verification_key_from_bytes_rejects_off_curve_point() {
+        // Right length, but not an Ed25519 curve point
+        let mut off_curve = [0u8; 32];
+        off_curve[0] = 2;
+        assert_eq!(
+            <SingleKes>::verification_key_from_bytes(&off_curve).err(),
+            Some(KesError::InvalidEncoding)
+        );
+    }
+
+    typed_tests! {
+        fn update_in_place_matches_consuming_update<K>() for {
+            single: SingleKes,
+            compact_single: CompactSingleKes<crate::dsign::Ed25519>,
+            sum3: Sum3Kes,
+            compact_sum3: CompactSum3Kes,
+        } {
+            let seed = [64u8; 32];
+            let mut in_place = K::gen_key_kes_from_seed_bytes(&seed).unwrap();
+            let mut consumed = K::gen_key_kes_from_seed_bytes(&seed).unwrap();
+
+            for period in 0..K::total_periods() - 1 {
+                assert!(K::update_kes_in_place(&(), &mut in_place, period).unwrap());
+                consumed = K::update_kes(&(), consumed, period).unwrap().unwrap();
+                assert_eq!(K::current_period_kes(&in_place), period + 1);
+                assert_eq!(
+                    *K::raw_serialize_signing_key_kes(&in_place),
+                    *K::raw_serialize_signing_key_kes(&consumed),
+                    "{} period {}",
+                    K::ALGORITHM_NAME,
+                    period + 1
+                );
+            }
+
+            // Expiring wipes the leaf key, which leads the serialized key
+            let last = K::total_periods() - 1;
+            let vk = K::derive_verification_key(&in_place).unwrap();
+            assert!(!K::update_kes_in_place(&(), &mut in_place, last).unwrap());
+            let wiped = K::raw_serialize_signing_key_kes(&in_place);
+            assert_eq!(wiped[..32], [0u8; 32], "{}", K::ALGORITHM_NAME);
+            assert!(K::sign_kes(&(), last, b"expired", &in_place)
+                .map_or(true, |sig| K::verify_kes(&(), &vk, last, b"expired", &sig)
+                    .is_err()));
+            K::forget_signing_key_kes(consumed);
+        }
+    }
+
+    /// Deterministic xorshift so the permutations are reproducible without an RNG crate
+    fn next_random(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn verify_batch_failures_follow_permutation() {
+        let seed = [31u8; 32];
+        let mut sk = Sum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = Sum3Kes::derive_verification_key(&sk).unwrap();
+
+        let messages: Vec<Vec<u8>> = (0..8u8).map(|i| vec![i; 16]).collect();
+        let mut signatures = Vec::new();
+        for period in 0..8 {
+            signatures
+                .push(Sum3Kes::sign_kes(&(), period, &messages[period as usize], &sk).unwrap());
+            if period < 7 {
+                sk = Sum3Kes::update_kes(&(), sk, period).unwrap().unwrap();
+            }
+        }
+
+        // Entries 1, 4 and 6 are invalid: wrong message, wrong period, wrong signature
+        let forged = b"forged".to_vec();
+        let batch: Vec<(Period, &[u8], &<Sum3Kes as KesAlgorithm>::Signature)> = (0..8)
+            .map(|i| match i {
+                1 => (1, forged.as_slice(), &signatures[1]),
+                4 => (5, messages[4].as_slice(), &signatures[4]),
+                6 => (6, messages[6].as_slice(), &signatures[2]),
+                _ => (i as Period, messages[i].as_slice(), &signatures[i]),
+            })
+            .collect();
+
+        let expected = vec![1, 4, 6];
+        assert_eq!(Sum3Kes::verify_batch_kes(&(), &vk, &batch), expected);
+
+        let mut state = 0x5eed_u64;
+        for _ in 0..5 {
+            // Fisher-Yates: permutation[new_index] = original_index
+            let mut permutation: Vec<usize> = (0..batch.len()).collect();
+            for i in (1..permutation.len()).rev() {
+                let j = (next_random(&mut state) % (i as u64 + 1)) as usize;
+                permutation.swap(i, j);
+            }
+            let shuffled: Vec<_> = permutation.iter().map(|&i| batch[i]).collect();
+
+            let mut failed: Vec<usize> = Sum3Kes::verify_batch_kes(&(), &vk, &shuffled)
+                .into_iter()
+                .map(|i| permutation[i])
+                .collect();
+            failed.sort_unstable();
+            assert_eq!(failed, expected, "permutation {permutation:?}");
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    struct XorShiftRng(u64);
+
+    #[cfg(feature = "rand")]
+    impl rand_core::RngCore for XorShiftRng {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            next_random(&mut self.0)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dest)
+        }
+
+        fn try_fill_bytes(
+            &mut self,
+            dest: &mut [u8],
+        ) -> core::result::Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    impl rand_core::CryptoRng for XorShiftRng {}
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn gen_key_kes_matches_seed_drawn_from_rng() {
+        let mut seed = [0u8; 32];
+        rand_core::RngCore::fill_bytes(&mut XorShiftRng(7), &mut seed);
+
+        let sk = Sum3Kes::gen_key_kes(&mut XorShiftRng(7)).unwrap();
+        let expected = Sum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        assert_eq!(
+            Sum3Kes::derive_verification_key(&sk).unwrap(),
+            Sum3Kes::derive_verification_key(&expected).unwrap()
+        );
+
+        let other = Sum3Kes::gen_key_kes(&mut XorShiftRng(8)).unwrap();
+        assert_ne!(
+            Sum3Kes::derive_verification_key(&sk).unwrap(),
+            Sum3Kes::derive_verification_key(&other).unwrap()
+        );
+    }
+
+    typed_tests! {
+        fn unsafe_clone_signing_key_branches_evolution<K>() for {
+            sum3: Sum3Kes,
+            compact_sum3: CompactSum3Kes,
+        } {
+            let sk = K::gen_key_kes_from_seed_bytes(&[21u8; 32]).unwrap();
+            let vk = K::derive_verification_key(&sk).unwrap();
+            let sk = K::update_kes_to(&(), sk, 0, 5).unwrap().unwrap();
+
+            let branch = K::unsafe_clone_signing_key(&sk);
+            assert_eq!(
+                *K::raw_serialize_signing_key_kes(&branch),
+                *K::raw_serialize_signing_key_kes(&sk)
+            );
+            assert_eq!(K::current_period_kes(&branch), 5);
+
+            // Evolving the branch leaves the original untouched
+            let branch = K::update_kes(&(), branch, 5).unwrap().unwrap();
+            assert_eq!(K::current_period_kes(&branch), 6);
+            assert_eq!(K::current_period_kes(&sk), 5);
+
+            for (period, key) in [(5, &sk), (6, &branch)] {
+                let sig = K::sign_kes(&(), period, b"branch", key).unwrap();
+                K::verify_kes(&(), &vk, period, b"branch", &sig).unwrap();
+            }
+            assert_eq!(K::derive_verification_key(&branch).unwrap(), vk);
+        }
+    }
+
+    typed_tests! {
+        fn gen_key_rejects_wrong_seed_lengths<K>() for {
+            single: SingleKes,
+            compact_single: CompactSingleKes<crate::dsign::Ed25519>,
+            sum1: Sum1Kes,
+            sum6: Sum6Kes,
+            compact_sum1: CompactSum1Kes,
+            compact_sum6: CompactSum6Kes,
+        } {
+            for len in [0, K::SEED_SIZE - 1, K::SEED_SIZE + 1, 2 * K::SEED_SIZE] {
+                let seed = vec![3u8; len];
+                let expected = Err(CryptoError::KesError(KesError::InvalidSeedLength {
+                    expected: K::SEED_SIZE,
+                    actual: len,
+                }));
+                assert_eq!(
+                    K::gen_key_kes_from_seed_bytes(&seed).map(|_| ()),
+                    expected,
+                    "{} seed of {len} bytes",
+                    K::ALGORITHM_NAME
+                );
+                assert_eq!(K::verification_key_from_seed(&seed).map(|_| ()), expected);
+            }
+        }
+    }
+
+    typed_tests! {
+        fn incompatible_hash_output_is_rejected<K>(hash_output: usize) for {
+            sum1_blake2b512: Sum1KesWith<Blake2b512> => 64,
+            sum2_sha512: Sum2KesWith<Sha512> => 64,
+            sum1_blake2b224: Sum1KesWith<Blake2b224> => 28,
+            compact_sum1_blake2b512: CompactSum1KesWith<Blake2b512> => 64,
+            compact_sum2_blake2b224: CompactSum2KesWith<Blake2b224> => 28,
+        } {
+            let expected = CryptoError::KesError(KesError::InvalidSeedExpansion {
+                hash_output,
+                seed_size: 32,
+            });
+            assert_eq!(
+                K::gen_key_kes_from_seed_bytes(&[0x2bu8; 32]).err(),
+                Some(expected.clone())
+            );
+            assert_eq!(
+                K::verification_key_from_seed(&[0x2bu8; 32]).err(),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn matching_hash_output_is_accepted() {
+        let sk = Sum2KesWith::<Sha256>::gen_key_kes_from_seed_bytes(&[0x2bu8; 32]).unwrap();
+        let sig = Sum2KesWith::<Sha256>::sign_kes(&(), 0, b"ok", &sk).unwrap();
+        let vk = Sum2KesWith::<Sha256>::derive_verification_key(&sk).unwrap();
+        Sum2KesWith::<Sha256>::verify_kes(&(), &vk, 0, b"ok", &sig).unwrap();
+    }
+
+    typed_tests! {
+        fn try_sign_separates_expiry_from_faults<K>() for {
+            sum2: Sum2Kes,
+            compact_sum3: CompactSum3Kes,
+        } {
+            let mut sk = K::gen_key_kes_from_seed_bytes(&[0x3cu8; 32]).unwrap();
+            let vk = K::derive_verification_key(&sk).unwrap();
+
+            // Not yet evolved to period 2
+            assert_eq!(
+                K::try_sign_kes(&(), 2, b"early", &sk).err(),
+                Some(CryptoError::KesError(KesError::KeyBehindPeriod {
+                    requested: 2,
+                    current: 0,
+                }))
+            );
+
+            for period in 0..K::max_signable_period() {
+                assert!(K::update_kes_in_place(&(), &mut sk, period).unwrap());
+            }
+            let last = K::max_signable_period();
+            let sig = K::try_sign_kes(&(), last, b"last", &sk).unwrap().unwrap();
+            K::verify_kes(&(), &vk, last, b"last", &sig).unwrap();
+
+            // Every earlier period has been left behind
+            for period in 0..last {
+                assert!(K::try_sign_kes(&(), period, b"late", &sk)
+                    .unwrap()
+                    .is_none());
+            }
+            assert_eq!(
+                K::try_sign_kes(&(), K::total_periods(), b"beyond", &sk).err(),
+                Some(CryptoError::KesError(KesError::PeriodOutOfRange {
+                    period: K::total_periods(),
+                    max_period: last,
+                }))
+            );
+
+            // A damaged key file never gets as far as signing
+            let bytes = K::raw_serialize_signing_key_kes(&sk);
+            let expected_vk = K::raw_serialize_verification_key_kes(&vk);
+            assert_eq!(
+                K::load_and_verify(&bytes[..bytes.len() - 1], &expected_vk).err(),
+                Some(KesError::WrongLength {
+                    expected: K::SIGNING_KEY_SIZE,
+                    actual: K::SIGNING_KEY_SIZE - 1,
+                })
+            );
+            // The leaf's stored public half no longer matches its seed
+            let mut corrupted = bytes.to_vec();
+            corrupted[40] ^= 0x01;
+            assert_eq!(
+                K::load_and_verify(&corrupted, &expected_vk).err(),
+                Some(KesError::InvalidEncoding)
+            );
+            K::forget_signing_key_kes(sk);
+        }
+    }
+
+    typed_tests! {
+        fn verify_receipt_matches_inputs<K>() for {
+            sum3: Sum3Kes,
+            compact_sum3: CompactSum3Kes,
+        } {
+            use crate::common::traits::DsignAlgorithm;
+            use crate::dsign::Ed25519;
+
+            let sk = K::gen_key_kes_from_seed_bytes(&[0x5au8; 32]).unwrap();
+            let vk = K::derive_verification_key(&sk).unwrap();
+            let sk = K::update_kes_to(&(), sk, 0, 5).unwrap().unwrap();
+            let sig = K::sign_kes(&(), 5, b"block", &sk).unwrap();
+
+            let receipt = K::verify_kes_with_receipt(&(), &vk, 5, b"block", &sig).unwrap();
+            assert_eq!(receipt.period, 5);
+            assert_eq!(receipt.verification_key_hash, K::verification_key_hash(&vk));
+            let leaf_vk =
+                Ed25519::deserialize_verification_key(&receipt.leaf_verification_key).unwrap();
+            let leaf_sig = Ed25519::deserialize_signature(&K::leaf_signature(&sig)).unwrap();
+            Ed25519::verify(b"block", &leaf_sig, &leaf_vk).unwrap();
+
+            assert_eq!(
+                K::verify_kes_with_receipt(&(), &vk, 5, b"other", &sig),
+                Err(KesError::BadEd25519Signature)
+            );
+            K::forget_signing_key_kes(sk);
+        }
+    }
+
+    typed_tests! {
+        fn constant_time_path_ordering_keeps_every_period_valid<K>() for {
+            sum4: Sum4Kes,
+            compact_sum4: CompactSum4Kes,
+        } {
+            let mut sk = K::gen_key_kes_from_seed_bytes(&[0x44u8; 32]).unwrap();
+            let vk = K::derive_verification_key(&sk).unwrap();
+            let vk_bytes = K::raw_serialize_verification_key_kes(&vk);
+
+            for period in 0..K::total_periods() {
+                let sig = K::sign_kes(&(), period, b"block", &sk).unwrap();
+                K::verify_kes(&(), &vk, period, b"block", &sig).unwrap();
+                assert!(K::verify_kes(&(), &vk, period, b"other", &sig).is_err());
+                for other in (0..K::total_periods()).filter(|&other| other != period) {
+                    assert!(K::verify_kes(&(), &vk, other, b"block", &sig).is_err());
+                }
+
+                let path = K::merkle_path(&vk, period, &sig).unwrap();
+                assert_eq!(path.period(), period);
+                assert!(path.root_matches::<Blake2b256>(&vk_bytes));
+                K::update_kes_in_place(&(), &mut sk, period).unwrap();
+            }
+            K::forget_signing_key_kes(sk);
+        }
+    }
+
+    #[test]
+    fn constant_time_path_ordering_picks_the_right_child() {
+        for period in 0..16 {
+            let (is_right, child) = period::split_period(period, 8);
+            assert_eq!(bool::from(is_right), period >= 8);
+            assert_eq!(child, period % 8);
+        }
+        let (left, right) = hash::order_children(vec![1, 2], vec![3, 4], subtle::Choice::from(1));
+        assert_eq!((left, right), (vec![3, 4], vec![1, 2]));
+    }
+
+    typed_tests! {
+        fn sign_zeroizing_matches_sign<K>(context: &<K as KesAlgorithm>::Context) for {
+            sum3: Sum3Kes => &(),
+            compact_sum3: CompactSum3Kes => &(),
+            context_sum3: ContextKes<Sum3Kes> => &b"tag".to_vec(),
+        } {
+            let sk = K::gen_key_kes_from_seed_bytes(&[0x2eu8; 32]).unwrap();
+            let sk = K::update_kes(context, sk, 0).unwrap().unwrap();
+            let message = zeroize::Zeroizing::new(b"sensitive payload".to_vec());
+
+            let plain = K::sign_kes(context, 1, &message, &sk).unwrap();
+            let zeroizing = K::sign_kes_zeroizing(context, 1, message, &sk).unwrap();
+            assert_eq!(
+                K::raw_serialize_signature_kes(&zeroizing),
+                K::raw_serialize_signature_kes(&plain)
+            );
+            K::forget_signing_key_kes(sk);
+        }
+    }
+
+    typed_tests! {
+        fn load_and_verify_rejects_mismatched_verification_key<K>() for {
+            single: SingleKes,
+            compact_single: CompactSingleKes<crate::dsign::Ed25519>,
+            sum1: Sum1Kes,
+            sum6: Sum6Kes,
+            compact_sum1: CompactSum1Kes,
+            compact_sum6: CompactSum6Kes,
+        } {
+            let sk = K::gen_key_kes_from_seed_bytes(&[0x29u8; 32]).unwrap();
+            let sk = K::update_kes_to(&(), sk, 0, K::max_signable_period())
+                .unwrap()
+                .unwrap();
+            let vk =
+                K::raw_serialize_verification_key_kes(&K::derive_verification_key(&sk).unwrap());
+            let bytes = K::raw_serialize_signing_key_kes(&sk);
+
+            let loaded = K::load_and_verify(&bytes, &vk).unwrap();
+            assert_eq!(*K::raw_serialize_signing_key_kes(&loaded), *bytes);
+
+            // The certificate of another key from a different seed
+            let other = K::gen_key_kes_from_seed_bytes(&[0x2au8; 32]).unwrap();
+            let other_vk =
+                K::raw_serialize_verification_key_kes(&K::derive_verification_key(&other).unwrap());
+            assert_eq!(
+                K::load_and_verify(&bytes, &other_vk).err(),
+                Some(KesError::VerificationKeyMismatch)
+            );
+            assert_eq!(
+                K::load_and_verify(&bytes, &vk[1..]).err(),
+                Some(KesError::VerificationKeyMismatch)
+            );
+            assert_eq!(
+                K::load_and_verify(&bytes[1..], &vk).err(),
+                Some(KesError::WrongLength {
+                    expected: K::SIGNING_KEY_SIZE,
+                    actual: K::SIGNING_KEY_SIZE - 1,
+                })
+            );
+        }
+    }
+
+    typed_tests! {
+        fn max_signable_period_is_the_last_period<K>() for {
+            single: SingleKes,
+            compact_single: CompactSingleKes<crate::dsign::Ed25519>,
+            sum1: Sum1Kes,
+            sum6: Sum6Kes,
+            compact_sum1: CompactSum1Kes,
+            compact_sum6: CompactSum6Kes,
+        } {
+            let max = K::max_signable_period();
+            assert_eq!(max + 1, K::total_periods());
+
+            let sk = K::gen_key_kes_from_seed_bytes(&[0x28u8; 32]).unwrap();
+            let vk = K::derive_verification_key(&sk).unwrap();
+            let sk = K::update_kes_to(&(), sk, 0, max).unwrap().unwrap();
+            let sig = K::sign_kes(&(), max, b"last", &sk).unwrap();
+            K::verify_kes(&(), &vk, max, b"last", &sig).unwrap();
+
+            let out_of_range = CryptoError::KesError(KesError::PeriodOutOfRange {
+                period: max + 1,
+                max_period: max,
+            });
+            assert_eq!(
+                K::sign_kes(&(), max + 1, b"last", &sk).err(),
+                Some(out_of_range.clone())
+            );
+            assert_eq!(
+                K::verify_kes(&(), &vk, max + 1, b"last", &sig),
+                Err(out_of_range)
+            );
+        }
+    }
+
+    #[test]
+    fn sum6_max_signable_period_is_63() {
+        assert_eq!(Sum6Kes::max_signable_period(), 63);
+    }
+
+    typed_tests! {
+        fn serialize_into_matches_allocating_serialization<K>() for {
+            single: SingleKes,
+            compact_single: CompactSingleKes<crate::dsign::Ed25519>,
+            sum3: Sum3Kes,
+            compact_sum3: CompactSum3Kes,
+        } {
+            let sk = K::gen_key_kes_from_seed_bytes(&[0x27u8; 32]).unwrap();
+            let sk = K::update_kes_to(&(), sk, 0, K::total_periods() - 1)
+                .unwrap()
+                .unwrap();
+            let vk = K::derive_verification_key(&sk).unwrap();
+            let sig = K::sign_kes(&(), K::total_periods() - 1, b"into", &sk).unwrap();
+
+            // One spare byte checks that only the prefix is written
+            let mut out = vec![0xffu8; K::SIGNING_KEY_SIZE.max(K::SIGNATURE_SIZE) + 1];
+            let written = K::raw_serialize_signing_key_kes_into(&sk, &mut out).unwrap();
+            assert_eq!(&out[..written], &K::raw_serialize_signing_key_kes(&sk)[..]);
+            assert_eq!(out[written], 0xff);
+
+            let written = K::raw_serialize_signature_kes_into(&sig, &mut out).unwrap();
+            assert_eq!(&out[..written], &K::raw_serialize_signature_kes(&sig)[..]);
+
+            let written = K::raw_serialize_verification_key_kes_into(&vk, &mut out).unwrap();
+            assert_eq!(
+                &out[..written],
+                &K::raw_serialize_verification_key_kes(&vk)[..]
+            );
+            assert_eq!(
+                K::raw_serialize_verification_key_kes_into(&vk, &mut out[..written - 1]),
+                Err(KesError::BufferTooSmall {
+                    required: K::VERIFICATION_KEY_SIZE,
+                    available: written - 1,
+                })
+            );
+        }
+    }
+
+    typed_tests! {
+        fn deserialize_rejects_truncated_and_oversized_input<K>() for {
+            single: SingleKes,
+            compact_single: CompactSingleKes<crate::dsign::Ed25519>,
+            sum1: Sum1Kes,
+            sum6: Sum6Kes,
+            compact_sum1: CompactSum1Kes,
+            compact_sum6: CompactSum6Kes,
+        } {
+            let sizes = [
+                K::VERIFICATION_KEY_SIZE,
+                K::SIGNATURE_SIZE,
+                K::SIGNING_KEY_SIZE,
+            ];
+            let longest = sizes.iter().max().unwrap() + 1;
+            for fill in [0x00u8, 0xff] {
+                for len in 0..=longest {
+                    let bytes = vec![fill; len];
+                    if len != K::VERIFICATION_KEY_SIZE {
+                        assert!(K::raw_deserialize_verification_key_kes(&bytes).is_none());
+                    }
+                    if len != K::SIGNATURE_SIZE {
+                        assert!(K::raw_deserialize_signature_kes(&bytes).is_none());
+                    }
+                    if len != K::SIGNING_KEY_SIZE {
+                        assert!(K::raw_deserialize_signing_key_kes(&bytes).is_none());
+                    }
+                }
+            }
+
+            // Truncated and oversized copies of a real signature
+            let sk = K::gen_key_kes_from_seed_bytes(&[0x40u8; 32]).unwrap();
+            let vk =
+                K::raw_serialize_verification_key_kes(&K::derive_verification_key(&sk).unwrap());
+            let mut sig =
+                K::raw_serialize_signature_kes(&K::sign_kes(&(), 0, b"opcert", &sk).unwrap());
+            assert!(K::raw_deserialize_signature_kes(&sig[..sig.len() - 1]).is_none());
+            assert!(K::raw_deserialize_verification_key_kes(&vk[1..]).is_none());
+            sig.push(0);
+            assert!(K::raw_deserialize_signature_kes(&sig).is_none());
+        }
+    }
+
+    #[test]
+    fn signature_to_bytes_roundtrips_and_rejects_truncation() {
+        type Sum3Signature = <Sum3Kes as KesAlgorithm>::Signature;
+        type CompactSum3Signature = <CompactSum3Kes as KesAlgorithm>::Signature;
+        type Ed25519CompactSingleSig = CompactSingleSig<crate::dsign::Ed25519>;
+
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[0x41u8; 32]).unwrap();
+        let sig = Sum3Kes::sign_kes(&(), 0, b"bytes", &sk).unwrap();
+        let bytes = sig.to_bytes();
+        assert_eq!(bytes, Sum3Kes::raw_serialize_signature_kes(&sig));
+        assert_eq!(Sum3Signature::from_bytes(&bytes).unwrap().to_bytes(), bytes);
+        assert!(matches!(
+            Sum3Signature::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(KesError::WrongLength { expected, actual })
+                if expected == Sum3Kes::SIGNATURE_SIZE && actual == expected - 1
+        ));
+        // Right length, but the first leaf key is y = 2, which is not on the curve
+        let mut garbage = bytes.clone();
+        garbage[64..96].fill(0);
+        garbage[64] = 2;
+        assert!(matches!(
+            Sum3Signature::from_bytes(&garbage),
+            Err(KesError::InvalidEncoding)
+        ));
+
+        let sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&[0x41u8; 32]).unwrap();
+        let sig = CompactSum3Kes::sign_kes(&(), 0, b"bytes", &sk).unwrap();
+        let bytes = sig.to_bytes();
+        let parsed = CompactSum3Signature::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed.to_bytes(), bytes);
+        assert!(matches!(
+            CompactSum3Signature::from_bytes(&bytes[1..]),
+            Err(KesError::WrongLength { .. })
+        ));
+
+        let sk =
+            CompactSingleKes::<crate::dsign::Ed25519>::gen_key_kes_from_seed_bytes(&[0x41u8; 32])
+                .unwrap();
+        let sig =
+            CompactSingleKes::<crate::dsign::Ed25519>::sign_kes(&(), 0, b"bytes", &sk).unwrap();
+        let bytes = sig.to_bytes();
+        assert_eq!(
+            Ed25519CompactSingleSig::from_bytes(&bytes)
+                .unwrap()
+                .to_bytes(),
+            bytes
+        );
+        assert!(matches!(
+            Ed25519CompactSingleSig::from_bytes(&[]),
+            Err(KesError::WrongLength { actual: 0, .. })
+        ));
+    }
+
+    typed_tests! {
+        fn verify_bytes_parses_and_verifies<K>() for {
+            single: SingleKes,
+            sum3: Sum3Kes,
+            compact_sum3: CompactSum3Kes,
+        } {
+            let sk = K::gen_key_kes_from_seed_bytes(&[0x42u8; 32]).unwrap();
+            let vk = K::derive_verification_key(&sk).unwrap();
+            let vk = K::raw_serialize_verification_key_kes(&vk);
+            let sig = K::sign_kes(&(), 0, b"packet", &sk).unwrap();
+            let sig = K::raw_serialize_signature_kes(&sig);
+
+            assert_eq!(K::verify_kes_bytes(&(), &vk, 0, b"packet", &sig), Ok(()));
+            assert_eq!(
+                K::verify_kes_bytes(&(), &vk[1..], 0, b"packet", &sig),
+                Err(KesError::WrongLength {
+                    expected: K::VERIFICATION_KEY_SIZE,
+                    actual: K::VERIFICATION_KEY_SIZE - 1,
+                })
+            );
+            let mut long = sig.clone();
+            long.push(0);
+            assert_eq!(
+                K::verify_kes_bytes(&(), &vk, 0, b"packet", &long),
+                Err(KesError::WrongLength {
+                    expected: K::SIGNATURE_SIZE,
+                    actual: K::SIGNATURE_SIZE + 1,
+                })
+            );
+
+            let mut forged = sig.clone();
+            forged[0] ^= 1;
+            assert_eq!(
+                K::verify_kes_bytes(&(), &vk, 0, b"packet", &forged),
+                Err(KesError::BadEd25519Signature)
+            );
+            assert!(K::verify_kes_bytes(&(), &vk, 0, b"other", &sig).is_err());
+            K::forget_signing_key_kes(sk);
+        }
+    }
+
+    typed_tests! {
+        fn gen_at_period_matches_stepping_from_zero<K>(period: Period) for {
+            sum5_period_17: Sum5Kes => 17,
+            compact_sum5_period_17: CompactSum5Kes => 17,
+            sum5_period_0: Sum5Kes => 0,
+        } {
+            let seed = [0x43u8; 32];
+            let mut stepped = K::gen_key_kes_from_seed_bytes(&seed).unwrap();
+            for from in 0..period {
+                stepped = K::update_kes(&(), stepped, from).unwrap().unwrap();
+            }
+            let direct = K::gen_key_kes_at_period(&(), &seed, period).unwrap();
+            assert_eq!(K::current_period_kes(&direct), period);
+            assert_eq!(
+                *K::raw_serialize_signing_key_kes(&direct),
+                *K::raw_serialize_signing_key_kes(&stepped)
+            );
+            K::forget_signing_key_kes(direct);
+            K::forget_signing_key_kes(stepped);
+
+            assert_eq!(
+                K::gen_key_kes_at_period(&(), &seed, K::total_periods()).err(),
+                Some(CryptoError::KesError(KesError::PeriodOutOfRange {
+                    period: K::total_periods(),
+                    max_period: K::max_signable_period(),
+                }))
+            );
+        }
+    }
+
+    #[test]
+    fn constructors_build_their_variants() {
+        let cases = [
+            (
+                KesError::period_out_of_range(9, 7),
+                KesError::PeriodOutOfRange {
+                    period: 9,
+                    max_period: 7,
+                },
+            ),
+            (
+                KesError::invalid_seed_length(32, 31),
+                KesError::InvalidSeedLength {
+                    expected: 32,
+                    actual: 31,
+                },
+            ),
+            (
+                KesError::wrong_length(448, 447),
+                KesError::WrongLength {
+                    expected: 448,
+                    actual: 447,
+                },
+            ),
+            (
+                KesError::period_mismatch(3, 4),
+                KesError::PeriodMismatch {
+                    requested: 3,
+                    signed: 4,
+                },
+            ),
+            (
+                KesError::buffer_too_small(64, 32),
+                KesError::BufferTooSmall {
+                    required: 64,
+                    available: 32,
+                },
+            ),
+            (
+                KesError::period_below_floor(2, 5),
+                KesError::PeriodBelowFloor {
+                    period: 2,
+                    floor: 5,
+                },
+            ),
+            (
+                KesError::invalid_seed_expansion(28, 32),
+                KesError::InvalidSeedExpansion {
+                    hash_output: 28,
+                    seed_size: 32,
+                },
+            ),
+            (
+                KesError::key_behind_period(6, 5),
+                KesError::KeyBehindPeriod {
+                    requested: 6,
+                    current: 5,
+                },
+            ),
+            (
+                KesError::period_already_passed(1, 2),
+                KesError::PeriodAlreadyPassed {
+                    requested: 1,
+                    current: 2,
+                },
+            ),
+            (
+                KesError::slot_before_start(10, 20),
+                KesError::SlotBeforeStart {
+                    slot: 10,
+                    start_slot: 20,
+                },
+            ),
+            (
+                KesError::update_from_wrong_period(3, 5),
+                KesError::UpdateFromWrongPeriod {
+                    from: 3,
+                    current: 5,
+                },
+            ),
+        ];
+        for (constructed, literal) in cases {
+            assert_eq!(constructed, literal);
+            assert!(!constructed.to_string().is_empty());
+            let wrapped = CryptoError::from(constructed.clone());
+            assert_eq!(wrapped.as_kes_error(), Some(&constructed));
+        }
+        assert_eq!(CryptoError::InvalidInput.as_kes_error(), None);
+    }
 }
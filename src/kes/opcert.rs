@@ -0,0 +1,202 @@
+//! Operational certificates
+//!
+//! A stake pool's hot KES key is bound to its cold key by an operational
+//! certificate: the cold key signs the KES verification key together with an
+//! issue counter and the KES period the certificate starts at. Block headers
+//! carry the certificate, and validators check the cold signature before
+//! trusting any KES signature made with the hot key.
+//!
+//! The signed payload matches cardano-node's `OCertSignable`: the raw KES
+//! verification key, then the counter and the start period as big-endian
+//! `u64`s.
+//!
+//! # Example
+//!
+//! ```rust
+//! use cardano_crypto::dsign::{DsignAlgorithm, Ed25519};
+//! use cardano_crypto::kes::opcert::OperationalCertificate;
+//! use cardano_crypto::kes::{KesAlgorithm, Sum6Kes};
+//!
+//! # fn main() -> cardano_crypto::common::Result<()> {
+//! let cold_sk = Ed25519::gen_key(&[1u8; 32]);
+//! let cold_vk = Ed25519::derive_verification_key(&cold_sk);
+//!
+//! let kes_sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[2u8; 32])?;
+//! let kes_vk = Sum6Kes::raw_serialize_verification_key_kes(&Sum6Kes::derive_verification_key(&kes_sk)?);
+//!
+//! let opcert = OperationalCertificate::<Ed25519>::sign(&kes_vk, 0, 412, &cold_sk);
+//! opcert.verify(&cold_vk)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::common::error::Result;
+use crate::dsign::DsignAlgorithm;
+use crate::kes::Period;
+
+/// Build the bytes a cold key signs for an operational certificate
+///
+/// The layout is `kes_verification_key || counter || kes_period`, with both
+/// integers as big-endian `u64`. For a 32-byte Sum KES key this is 48 bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::kes::opcert::signable_bytes;
+///
+/// let payload = signable_bytes(&[0xaa; 32], 1, 2);
+/// assert_eq!(payload.len(), 48);
+/// assert_eq!(&payload[32..], &[0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2]);
+/// ```
+#[must_use]
+pub fn signable_bytes(kes_verification_key: &[u8], counter: u64, kes_period: Period) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(kes_verification_key.len() + 16);
+    payload.extend_from_slice(kes_verification_key);
+    payload.extend_from_slice(&counter.to_be_bytes());
+    payload.extend_from_slice(&kes_period.to_be_bytes());
+    payload
+}
+
+/// An operational certificate signed by a cold key of algorithm `D`
+///
+/// The KES verification key is kept in its raw serialization, as it appears
+/// in block headers, so one certificate type covers every KES scheme.
+#[derive(Debug, Clone)]
+pub struct OperationalCertificate<D: DsignAlgorithm> {
+    /// Raw KES verification key the certificate delegates to
+    pub kes_verification_key: Vec<u8>,
+    /// Issue counter; a pool must never reuse or lower it
+    pub counter: u64,
+    /// KES period the certificate becomes valid at
+    pub kes_period: Period,
+    /// Cold key signature over [`signable_bytes`]
+    pub cold_signature: D::Signature,
+}
+
+impl<D: DsignAlgorithm> OperationalCertificate<D> {
+    /// Sign a new certificate with the cold signing key
+    #[must_use]
+    pub fn sign(
+        kes_verification_key: &[u8],
+        counter: u64,
+        kes_period: Period,
+        cold_signing_key: &D::SigningKey,
+    ) -> Self {
+        let payload = signable_bytes(kes_verification_key, counter, kes_period);
+        Self {
+            kes_verification_key: kes_verification_key.to_vec(),
+            counter,
+            kes_period,
+            cold_signature: D::sign(cold_signing_key, &payload),
+        }
+    }
+
+    /// The payload the cold key signed
+    #[must_use]
+    pub fn signable_bytes(&self) -> Vec<u8> {
+        signable_bytes(&self.kes_verification_key, self.counter, self.kes_period)
+    }
+
+    /// Check the cold signature against the pool's cold verification key
+    ///
+    /// # Errors
+    ///
+    /// Whatever [`D::verify`](DsignAlgorithm::verify) returns for a bad
+    /// signature or malformed key.
+    pub fn verify(&self, cold_verification_key: &D::VerificationKey) -> Result<()> {
+        D::verify(
+            cold_verification_key,
+            &self.signable_bytes(),
+            &self.cold_signature,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::hex::decode;
+    use crate::dsign::Ed25519;
+    use crate::kes::{KesAlgorithm, Sum6Kes};
+
+    #[test]
+    fn payload_matches_ocert_signable_layout() {
+        let kes_vk = [0x11u8; 32];
+        let payload = signable_bytes(&kes_vk, 0x0102_0304_0506_0708, 0x2a);
+        let mut expected = kes_vk.to_vec();
+        expected.extend_from_slice(&decode("0102030405060708").unwrap());
+        expected.extend_from_slice(&decode("000000000000002a").unwrap());
+        assert_eq!(payload, expected);
+    }
+
+    #[test]
+    fn opcert_binds_kes_key_counter_and_period() {
+        let cold_sk = Ed25519::gen_key(&[0x0cu8; 32]);
+        let cold_vk = Ed25519::derive_verification_key(&cold_sk);
+        let kes_sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[0x6bu8; 32]).unwrap();
+        let kes_vk = Sum6Kes::raw_serialize_verification_key_kes(
+            &Sum6Kes::derive_verification_key(&kes_sk).unwrap(),
+        );
+
+        let opcert = OperationalCertificate::<Ed25519>::sign(&kes_vk, 3, 412, &cold_sk);
+        assert_eq!(opcert.signable_bytes().len(), 48);
+        opcert.verify(&cold_vk).unwrap();
+
+        let mut bumped = opcert.clone();
+        bumped.counter += 1;
+        assert!(bumped.verify(&cold_vk).is_err());
+
+        let mut moved = opcert.clone();
+        moved.kes_period -= 1;
+        assert!(moved.verify(&cold_vk).is_err());
+
+        let other_vk = Ed25519::derive_verification_key(&Ed25519::gen_key(&[0x0du8; 32]));
+        assert!(opcert.verify(&other_vk).is_err());
+    }
+
+    /// `tests/test_vectors/node.cert`, written by
+    /// `tests/test_vectors/reference_envelopes.py` in cardano-cli's
+    /// `issue-op-cert` format from a model independent of this crate. It is
+    /// not the output of a real cardano-cli run.
+    const NODE_CERT: &str = include_str!("../../tests/test_vectors/node.cert");
+
+    #[test]
+    fn opcert_matches_node_cert_fixture() {
+        let cbor_hex = NODE_CERT
+            .split("\"cborHex\": \"")
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap();
+        assert!(NODE_CERT.contains("\"type\": \"NodeOperationalCertificate\""));
+        let cbor = decode(cbor_hex).unwrap();
+
+        let cold_sk = Ed25519::gen_key(&[0x0cu8; 32]);
+        let cold_vk = Ed25519::derive_verification_key(&cold_sk);
+        let kes_vk = Sum6Kes::raw_serialize_verification_key_kes(
+            &Sum6Kes::verification_key_from_seed(&[0x5au8; 32]).unwrap(),
+        );
+        let opcert = OperationalCertificate::<Ed25519>::sign(&kes_vk, 3, 412, &cold_sk);
+
+        assert_eq!(
+            opcert.signable_bytes(),
+            decode(concat!(
+                "2b5c7dac4eb6d7bc2742b4fee46c119f71d635915a909cce024de51acf1a33a9",
+                "0000000000000003",
+                "000000000000019c",
+            ))
+            .unwrap()
+        );
+
+        // [[kes_vk, counter, period, cold_signature], cold_vk]
+        let mut expected = vec![0x82, 0x84, 0x58, 0x20];
+        expected.extend_from_slice(&kes_vk);
+        expected.extend_from_slice(&[0x03, 0x19, 0x01, 0x9c, 0x58, 0x40]);
+        expected.extend_from_slice(opcert.cold_signature.as_bytes());
+        expected.extend_from_slice(&[0x58, 0x20]);
+        expected.extend_from_slice(cold_vk.as_bytes());
+        assert_eq!(cbor, expected);
+        opcert.verify(&cold_vk).unwrap();
+    }
+}
@@ -0,0 +1,280 @@
+//! Range-checked KES periods
+//!
+//! A [`Period`] is a bare `u64`, so nothing stops a caller from passing
+//! `total_periods()` or more to [`update_kes`](KesAlgorithm::update_kes) or
+//! [`sign_kes`](KesAlgorithm::sign_kes). [`KesPeriod<K>`] carries a period
+//! that is known to be below `K::total_periods()`, and its arithmetic keeps it
+//! there. It derefs to [`Period`], so it can be passed wherever one is
+//! expected.
+//!
+//! # Example
+//!
+//! ```rust
+//! use cardano_crypto::kes::period::{period_in_range, KesPeriod};
+//! use cardano_crypto::kes::{KesAlgorithm, Sum2Kes};
+//!
+//! assert!(period_in_range::<Sum2Kes>(3));
+//! assert!(!period_in_range::<Sum2Kes>(4));
+//!
+//! let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[1u8; 32]).unwrap();
+//! let period = KesPeriod::<Sum2Kes>::first();
+//! let sk = Sum2Kes::update_kes(&(), sk, *period).unwrap().unwrap();
+//!
+//! let next = period.checked_add(1).unwrap();
+//! assert_eq!(Sum2Kes::current_period_kes(&sk), *next);
+//! assert_eq!(next.saturating_add(10), KesPeriod::last());
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeLess};
+
+use crate::kes::{KesAlgorithm, KesError, Period};
+
+/// Whether `period` is a valid period of the scheme `K`
+///
+/// True for `0..K::total_periods()`.
+#[must_use]
+pub fn period_in_range<K: KesAlgorithm>(period: Period) -> bool {
+    period <= K::max_signable_period()
+}
+
+/// [`KesError::PeriodOutOfRange`] unless `period` is a valid period of `K`
+pub(crate) fn check_period<K: KesAlgorithm>(period: Period) -> Result<(), KesError> {
+    if period_in_range::<K>(period) {
+        Ok(())
+    } else {
+        Err(KesError::PeriodOutOfRange {
+            period,
+            max_period: K::max_signable_period(),
+        })
+    }
+}
+
+/// Which half of a Sum level `period` falls in, and its period within that half
+///
+/// `t_half` is the number of periods of each subtree. Returns a set
+/// [`Choice`] for the right half. Neither the comparison nor the subtraction
+/// branches on `period`.
+pub(crate) fn split_period(period: Period, t_half: Period) -> (Choice, Period) {
+    let is_right = !period.ct_lt(&t_half);
+    let child_period = Period::conditional_select(&period, &period.wrapping_sub(t_half), is_right);
+    (is_right, child_period)
+}
+
+/// A period of the scheme `K`, always below `K::total_periods()`
+///
+/// Periods of different schemes are different types, so a `KesPeriod<Sum6Kes>`
+/// cannot be handed to a `Sum7Kes` key by mistake.
+pub struct KesPeriod<K> {
+    period: Period,
+    scheme: PhantomData<fn() -> K>,
+}
+
+impl<K: KesAlgorithm> KesPeriod<K> {
+    /// `period` if it is in range for `K`
+    #[must_use]
+    pub fn new(period: Period) -> Option<Self> {
+        period_in_range::<K>(period).then_some(Self::new_unchecked(period))
+    }
+
+    /// Period 0
+    #[must_use]
+    pub fn first() -> Self {
+        Self::new_unchecked(0)
+    }
+
+    /// The last period, [`K::max_signable_period`](KesAlgorithm::max_signable_period)
+    #[must_use]
+    pub fn last() -> Self {
+        Self::new_unchecked(K::max_signable_period())
+    }
+
+    /// The period as a plain [`Period`]
+    #[must_use]
+    pub fn get(self) -> Period {
+        self.period
+    }
+
+    /// Periods left after this one before the key expires
+    #[must_use]
+    pub fn remaining(self) -> Period {
+        K::max_signable_period() - self.period
+    }
+
+    /// `self + rhs`, or `None` if that is not a period of `K`
+    #[must_use]
+    pub fn checked_add(self, rhs: Period) -> Option<Self> {
+        self.period.checked_add(rhs).and_then(Self::new)
+    }
+
+    /// `self + rhs`, clamped to [`last`](Self::last)
+    #[must_use]
+    pub fn saturating_add(self, rhs: Period) -> Self {
+        self.checked_add(rhs).unwrap_or_else(Self::last)
+    }
+
+    /// `self + rhs` wrapped modulo `K::total_periods()`, and whether it wrapped
+    #[must_use]
+    pub fn overflowing_add(self, rhs: Period) -> (Self, bool) {
+        let total = K::total_periods();
+        match self.checked_add(rhs) {
+            Some(period) => (period, false),
+            None => {
+                // Both operands are reduced first so the sum cannot overflow u64
+                let wrapped = (self.period + rhs % total) % total;
+                (Self::new_unchecked(wrapped), true)
+            }
+        }
+    }
+
+    /// The following period, or `None` after the last one
+    #[must_use]
+    pub fn next(self) -> Option<Self> {
+        self.checked_add(1)
+    }
+
+    fn new_unchecked(period: Period) -> Self {
+        Self {
+            period,
+            scheme: PhantomData,
+        }
+    }
+}
+
+impl<K: KesAlgorithm> TryFrom<Period> for KesPeriod<K> {
+    type Error = KesError;
+
+    fn try_from(period: Period) -> Result<Self, Self::Error> {
+        Self::new(period).ok_or(KesError::PeriodOutOfRange {
+            period,
+            max_period: K::max_signable_period(),
+        })
+    }
+}
+
+impl<K> From<KesPeriod<K>> for Period {
+    fn from(period: KesPeriod<K>) -> Self {
+        period.period
+    }
+}
+
+impl<K> Deref for KesPeriod<K> {
+    type Target = Period;
+
+    fn deref(&self) -> &Period {
+        &self.period
+    }
+}
+
+// Manual impls so that none of them require `K` to implement the trait
+
+impl<K> Clone for KesPeriod<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for KesPeriod<K> {}
+
+impl<K> PartialEq for KesPeriod<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.period == other.period
+    }
+}
+
+impl<K> Eq for KesPeriod<K> {}
+
+impl<K> PartialOrd for KesPeriod<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K> Ord for KesPeriod<K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.period.cmp(&other.period)
+    }
+}
+
+impl<K> Hash for KesPeriod<K> {
+    fn hash<S: Hasher>(&self, state: &mut S) {
+        self.period.hash(state);
+    }
+}
+
+impl<K> fmt::Debug for KesPeriod<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("KesPeriod").field(&self.period).finish()
+    }
+}
+
+impl<K> fmt::Display for KesPeriod<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.period, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kes::{Sum0Kes, Sum6Kes};
+
+    #[test]
+    fn range_boundary_at_total_periods() {
+        assert!(period_in_range::<Sum6Kes>(63));
+        assert!(!period_in_range::<Sum6Kes>(64));
+        assert!(period_in_range::<Sum0Kes>(0));
+        assert!(!period_in_range::<Sum0Kes>(1));
+
+        assert_eq!(KesPeriod::<Sum6Kes>::new(64), None);
+        assert_eq!(
+            KesPeriod::<Sum6Kes>::try_from(64),
+            Err(KesError::PeriodOutOfRange {
+                period: 64,
+                max_period: 63
+            })
+        );
+
+        let last = KesPeriod::<Sum6Kes>::last();
+        assert_eq!(*last, 63);
+        assert_eq!(last.remaining(), 0);
+        assert_eq!(last.next(), None);
+        assert_eq!(last.checked_add(1), None);
+        assert_eq!(last.saturating_add(1), last);
+        assert_eq!(last.overflowing_add(1), (KesPeriod::first(), true));
+        assert_eq!(
+            KesPeriod::<Sum6Kes>::new(60).unwrap().overflowing_add(3),
+            (last, false)
+        );
+    }
+
+    #[test]
+    fn arithmetic_at_u64_max() {
+        let period = KesPeriod::<Sum6Kes>::new(10).unwrap();
+
+        assert_eq!(period.checked_add(u64::MAX), None);
+        assert_eq!(period.saturating_add(u64::MAX), KesPeriod::last());
+        // u64::MAX = 63 (mod 64), so 10 + u64::MAX wraps to 9
+        assert_eq!(
+            period.overflowing_add(u64::MAX),
+            (KesPeriod::new(9).unwrap(), true)
+        );
+        assert_eq!(KesPeriod::<Sum6Kes>::new(u64::MAX), None);
+    }
+
+    #[test]
+    fn orders_by_period() {
+        let a = KesPeriod::<Sum6Kes>::new(3).unwrap();
+        let b = a.next().unwrap();
+
+        assert!(a < b);
+        assert_eq!(a.max(b), b);
+        assert_eq!(Period::from(b), 4);
+        assert_eq!(*b - *a, 1);
+    }
+}
@@ -0,0 +1,268 @@
+//! Pre-hashed and streaming KES signatures
+//!
+//! [`KesAlgorithm::sign_kes`] signs the message bytes directly, so the whole
+//! message has to be in memory. The pre-hashed variants sign the Blake2b-256
+//! digest of the message instead, which lets [`KesSigner`] and
+//! [`KesVerifier`] consume it in chunks.
+//!
+//! A pre-hashed signature is a signature over [`PREHASH_DOMAIN`] followed by
+//! the 32-byte digest, not over the message: it only verifies with
+//! [`verify_kes_prehashed`](KesAlgorithm::verify_kes_prehashed) or
+//! [`KesVerifier`], never with plain `verify_kes`. The tag also keeps a
+//! plain signature on some 32-byte message from passing as a pre-hashed
+//! signature on whatever hashes to those bytes. Callers that
+//! already hold the digest pass it to
+//! [`sign_kes_digest`](KesAlgorithm::sign_kes_digest) and
+//! [`verify_kes_digest`](KesAlgorithm::verify_kes_digest) directly, and
+//! callers holding the message as a list of parts pass them to
+//! [`verify_kes_chunks`](KesAlgorithm::verify_kes_chunks).
+//!
+//! # Example
+//!
+//! ```rust
+//! use cardano_crypto::kes::{KesAlgorithm, KesSigner, KesVerifier, Sum2Kes};
+//!
+//! # fn main() -> cardano_crypto::common::Result<()> {
+//! let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[1u8; 32])?;
+//! let vk = Sum2Kes::derive_verification_key(&sk)?;
+//!
+//! let mut signer = KesSigner::<Sum2Kes>::new(&(), &sk);
+//! signer.update(b"large ");
+//! signer.update(b"artifact");
+//! let sig = signer.finalize_sign(0)?;
+//!
+//! let mut verifier = KesVerifier::<Sum2Kes>::new(&(), &vk);
+//! verifier.update(b"large artifact");
+//! verifier.finalize_verify(0, &sig)?;
+//!
+//! Sum2Kes::verify_kes_prehashed(&(), &vk, 0, b"large artifact", &sig)?;
+//! assert!(Sum2Kes::verify_kes(&(), &vk, 0, b"large artifact", &sig).is_err());
+//! # Ok(())
+//! # }
+//! ```
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+
+use crate::common::error::Result;
+use crate::kes::{KesAlgorithm, Period};
+
+/// Size of the message digest that pre-hashed signatures cover
+pub const PREHASH_SIZE: usize = 32;
+
+/// Domain-separation tag signed in front of every pre-hash digest
+pub const PREHASH_DOMAIN: &[u8] = b"cardano-crypto/kes-prehash/v1";
+
+/// Length of [`prehash_signed_bytes`]
+pub const PREHASH_SIGNED_SIZE: usize = PREHASH_DOMAIN.len() + PREHASH_SIZE;
+
+/// The bytes a pre-hashed signature on `digest` is a plain KES signature of
+///
+/// [`PREHASH_DOMAIN`] followed by `digest`. Only needed to check pre-hashed
+/// signatures with a KES implementation that lacks the pre-hashed API.
+#[must_use]
+pub fn prehash_signed_bytes(digest: &[u8; PREHASH_SIZE]) -> [u8; PREHASH_SIGNED_SIZE] {
+    let mut signed = [0u8; PREHASH_SIGNED_SIZE];
+    signed[..PREHASH_DOMAIN.len()].copy_from_slice(PREHASH_DOMAIN);
+    signed[PREHASH_DOMAIN.len()..].copy_from_slice(digest);
+    signed
+}
+
+/// Blake2b-256 digest of `message`, the bytes a pre-hashed signature covers
+#[must_use]
+pub fn prehash_message(message: &[u8]) -> [u8; PREHASH_SIZE] {
+    Blake2b::<U32>::digest(message).into()
+}
+
+/// Blake2b-256 digest of the concatenation of `chunks`
+///
+/// Equal to [`prehash_message`] of the chunks joined together, without
+/// building the joined buffer.
+#[must_use]
+pub fn prehash_chunks<'m>(chunks: impl IntoIterator<Item = &'m [u8]>) -> [u8; PREHASH_SIZE] {
+    let mut hasher = Blake2b::<U32>::new();
+    for chunk in chunks {
+        hasher.update(chunk);
+    }
+    hasher.finalize().into()
+}
+
+/// Incrementally hashes a message, then signs its digest
+///
+/// Produces the same signature as
+/// [`sign_kes_prehashed`](KesAlgorithm::sign_kes_prehashed) over the
+/// concatenation of every chunk passed to [`update`](Self::update).
+pub struct KesSigner<'a, K: KesAlgorithm> {
+    context: &'a K::Context,
+    signing_key: &'a K::SigningKey,
+    hasher: Blake2b<U32>,
+}
+
+impl<K: KesAlgorithm> core::fmt::Debug for KesSigner<'_, K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KesSigner")
+            .field("algorithm", &K::ALGORITHM_NAME)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, K: KesAlgorithm> KesSigner<'a, K> {
+    /// Start a streaming signature with `signing_key`
+    pub fn new(context: &'a K::Context, signing_key: &'a K::SigningKey) -> Self {
+        Self {
+            context,
+            signing_key,
+            hasher: Blake2b::<U32>::new(),
+        }
+    }
+
+    /// Feed the next chunk of the message
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.hasher.update(chunk);
+    }
+
+    /// Sign the digest of everything fed so far at `period`
+    pub fn finalize_sign(self, period: Period) -> Result<K::Signature> {
+        let digest: [u8; PREHASH_SIZE] = self.hasher.finalize().into();
+        K::sign_kes_digest(self.context, period, &digest, self.signing_key)
+    }
+}
+
+/// Incrementally hashes a message, then verifies a pre-hashed signature on it
+pub struct KesVerifier<'a, K: KesAlgorithm> {
+    context: &'a K::Context,
+    verification_key: &'a K::VerificationKey,
+    hasher: Blake2b<U32>,
+}
+
+impl<K: KesAlgorithm> core::fmt::Debug for KesVerifier<'_, K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KesVerifier")
+            .field("algorithm", &K::ALGORITHM_NAME)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, K: KesAlgorithm> KesVerifier<'a, K> {
+    /// Start a streaming verification against `verification_key`
+    pub fn new(context: &'a K::Context, verification_key: &'a K::VerificationKey) -> Self {
+        Self {
+            context,
+            verification_key,
+            hasher: Blake2b::<U32>::new(),
+        }
+    }
+
+    /// Feed the next chunk of the message
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.hasher.update(chunk);
+    }
+
+    /// Verify `signature` over the digest of everything fed so far at `period`
+    pub fn finalize_verify(self, period: Period, signature: &K::Signature) -> Result<()> {
+        let digest: [u8; PREHASH_SIZE] = self.hasher.finalize().into();
+        K::verify_kes_digest(
+            self.context,
+            self.verification_key,
+            period,
+            &digest,
+            signature,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kes::{CompactSum3Kes, Sum3Kes};
+
+    #[test]
+    fn streaming_matches_one_shot_prehashed() {
+        let message: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[4u8; 32]).unwrap();
+        let vk = Sum3Kes::derive_verification_key(&sk).unwrap();
+
+        let mut signer = KesSigner::<Sum3Kes>::new(&(), &sk);
+        for chunk in message.chunks(777) {
+            signer.update(chunk);
+        }
+        let streamed = signer.finalize_sign(0).unwrap();
+        let one_shot = Sum3Kes::sign_kes_prehashed(&(), 0, &message, &sk).unwrap();
+        assert_eq!(
+            Sum3Kes::raw_serialize_signature_kes(&streamed),
+            Sum3Kes::raw_serialize_signature_kes(&one_shot)
+        );
+
+        let mut verifier = KesVerifier::<Sum3Kes>::new(&(), &vk);
+        for chunk in message.chunks(1000) {
+            verifier.update(chunk);
+        }
+        verifier.finalize_verify(0, &streamed).unwrap();
+
+        // The signed bytes are the tagged digest, not the message or the bare digest
+        let digest = prehash_message(&message);
+        Sum3Kes::verify_kes(&(), &vk, 0, &prehash_signed_bytes(&digest), &streamed).unwrap();
+        assert!(Sum3Kes::verify_kes(&(), &vk, 0, &digest, &streamed).is_err());
+        assert!(Sum3Kes::verify_kes(&(), &vk, 0, &message, &streamed).is_err());
+
+        // Nor does a plain signature on the digest pass as a pre-hashed one
+        let plain = Sum3Kes::sign_kes(&(), 0, &digest, &sk).unwrap();
+        assert!(Sum3Kes::verify_kes_digest(&(), &vk, 0, &digest, &plain).is_err());
+    }
+
+    #[test]
+    fn streaming_verifier_rejects_altered_message() {
+        let sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&[5u8; 32]).unwrap();
+        let vk = CompactSum3Kes::derive_verification_key(&sk).unwrap();
+        let sig = CompactSum3Kes::sign_kes_prehashed(&(), 0, b"artifact", &sk).unwrap();
+
+        let mut verifier = KesVerifier::<CompactSum3Kes>::new(&(), &vk);
+        verifier.update(b"artefact");
+        assert!(verifier.finalize_verify(0, &sig).is_err());
+
+        assert!(CompactSum3Kes::verify_kes_prehashed(&(), &vk, 1, b"artifact", &sig).is_err());
+        CompactSum3Kes::verify_kes_prehashed(&(), &vk, 0, b"artifact", &sig).unwrap();
+    }
+
+    #[test]
+    fn digest_path_agrees_with_message_path() {
+        let message: Vec<u8> = (0..4096u32).map(|i| (i % 241) as u8).collect();
+        let digest = prehash_message(&message);
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[6u8; 32]).unwrap();
+        let sk = Sum3Kes::update_kes_to(&(), sk, 0, 5).unwrap().unwrap();
+        let vk = Sum3Kes::derive_verification_key(&sk).unwrap();
+
+        let from_digest = Sum3Kes::sign_kes_digest(&(), 5, &digest, &sk).unwrap();
+        let from_message = Sum3Kes::sign_kes_prehashed(&(), 5, &message, &sk).unwrap();
+        assert_eq!(
+            Sum3Kes::raw_serialize_signature_kes(&from_digest),
+            Sum3Kes::raw_serialize_signature_kes(&from_message)
+        );
+
+        Sum3Kes::verify_kes_digest(&(), &vk, 5, &digest, &from_message).unwrap();
+        Sum3Kes::verify_kes_prehashed(&(), &vk, 5, &message, &from_digest).unwrap();
+
+        let other = prehash_message(b"different payload");
+        assert!(Sum3Kes::verify_kes_digest(&(), &vk, 5, &other, &from_digest).is_err());
+        assert!(Sum3Kes::verify_kes_digest(&(), &vk, 4, &digest, &from_digest).is_err());
+        assert!(Sum3Kes::verify_kes(&(), &vk, 5, &message, &from_digest).is_err());
+    }
+
+    #[test]
+    fn chunked_verification_agrees_with_concatenated() {
+        let header: [&[u8]; 4] = [b"era", b"", b"slot 42", b"body hash"];
+        let joined = header.concat();
+        assert_eq!(prehash_chunks(header), prehash_message(&joined));
+
+        let sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&[7u8; 32]).unwrap();
+        let vk = CompactSum3Kes::derive_verification_key(&sk).unwrap();
+        let sig = CompactSum3Kes::sign_kes_prehashed(&(), 0, &joined, &sk).unwrap();
+        CompactSum3Kes::verify_kes_prehashed(&(), &vk, 0, &joined, &sig).unwrap();
+        CompactSum3Kes::verify_kes_chunks(&(), &vk, 0, header, &sig).unwrap();
+        CompactSum3Kes::verify_kes_chunks(&(), &vk, 0, joined.chunks(3), &sig).unwrap();
+
+        let reordered: [&[u8]; 4] = [b"slot 42", b"era", b"", b"body hash"];
+        assert!(CompactSum3Kes::verify_kes_chunks(&(), &vk, 0, reordered, &sig).is_err());
+        assert!(CompactSum3Kes::verify_kes_chunks(&(), &vk, 1, header, &sig).is_err());
+    }
+}
@@ -0,0 +1,43 @@
+//! Loggable records of successful KES verifications
+//!
+//! [`Ed25519LeafKes::verify_kes_with_receipt`] returns a [`VerifyReceipt`]
+//! naming what a signature was checked against: the period, the hash of the
+//! verification key and the leaf key that signed. Operators can log it to
+//! correlate blocks signed under the same key or leaf without keeping the
+//! signature around and re-deriving that data.
+//!
+//! # Example
+//!
+//! ```rust
+//! use cardano_crypto::kes::{Ed25519LeafKes, KesAlgorithm, Sum3Kes};
+//!
+//! # fn main() -> Result<(), cardano_crypto::kes::KesError> {
+//! let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[6u8; 32]).unwrap();
+//! let vk = Sum3Kes::derive_verification_key(&sk).unwrap();
+//! let sig = Sum3Kes::sign_kes(&(), 0, b"header", &sk).unwrap();
+//!
+//! let receipt = Sum3Kes::verify_kes_with_receipt(&(), &vk, 0, b"header", &sig)?;
+//! assert_eq!(receipt.period, 0);
+//! assert_eq!(receipt.verification_key_hash, Sum3Kes::verification_key_hash(&vk));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Ed25519LeafKes::verify_kes_with_receipt`]: crate::kes::Ed25519LeafKes::verify_kes_with_receipt
+
+use crate::kes::Period;
+
+/// What a signature was verified against
+///
+/// Only produced once verification has succeeded, so every field describes
+/// a valid signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VerifyReceipt {
+    /// Period the signature is valid for
+    pub period: Period,
+    /// Blake2b-224 hash of the verification key, as in
+    /// [`verification_key_hash`](crate::kes::KesAlgorithm::verification_key_hash)
+    pub verification_key_hash: [u8; 28],
+    /// Ed25519 verification key of the leaf that signed
+    pub leaf_verification_key: [u8; 32],
+}
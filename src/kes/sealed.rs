@@ -0,0 +1,258 @@
+//! Authenticated encryption of KES signing keys at rest
+//!
+//! Block producers must persist their evolving KES key between restarts. This
+//! module wraps the serialized key (see [`UnsoundKesAlgorithm`]) together with
+//! its current period in ChaCha20-Poly1305, so operators do not have to roll
+//! their own AEAD.
+//!
+//! # Format
+//!
+//! ```text
+//! [0]       version (currently 1)
+//! [1..13]   nonce
+//! [13..]    ChaCha20-Poly1305(period (u64 BE) || signing key) || tag
+//! ```
+//!
+//! The nonce is derived from the encryption key and the plaintext
+//! (Blake2b-256, truncated to 12 bytes), so no RNG is needed and a nonce is
+//! only ever repeated for an identical plaintext under the same key. The
+//! version byte is authenticated as associated data, followed by the scheme's
+//! algorithm tag (its name, tree hash and seed expansion, as written in key
+//! files), so a blob sealed for one scheme fails authentication when
+//! unsealed as another of the same size.
+//!
+//! # Example
+//!
+//! ```rust
+//! use cardano_crypto::kes::{KesAlgorithm, Sum2Kes};
+//! use cardano_crypto::kes::sealed::{seal, unseal};
+//!
+//! # fn main() -> cardano_crypto::common::Result<()> {
+//! let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[3u8; 32])?;
+//! let storage_key = [9u8; 32];
+//!
+//! let sk = Sum2Kes::update_kes(&(), sk, 0)?.unwrap();
+//!
+//! let sealed = seal::<Sum2Kes>(&sk, &storage_key)?;
+//! let (restored, period) = unseal::<Sum2Kes>(&sealed, &storage_key)?;
+//! assert_eq!(period, 1);
+//! assert_eq!(
+//!     Sum2Kes::derive_verification_key(&restored)?,
+//!     Sum2Kes::derive_verification_key(&sk)?,
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use zeroize::Zeroizing;
+
+use crate::common::error::{CryptoError, Result};
+use crate::kes::{algorithm_tag, KesError, Period, UnsoundKesAlgorithm};
+
+/// Current sealed-key format version
+pub const SEALED_KEY_VERSION: u8 = 1;
+
+const NONCE_SIZE: usize = 12;
+const TAG_SIZE: usize = 16;
+const HEADER_SIZE: usize = 1 + NONCE_SIZE;
+const PERIOD_SIZE: usize = 8;
+const NONCE_DOMAIN: &[u8] = b"cardano-kes-sealed-key-nonce";
+
+/// Version byte followed by the algorithm tag of `K`
+fn associated_data<K: UnsoundKesAlgorithm>() -> Vec<u8> {
+    let mut aad = Vec::from([SEALED_KEY_VERSION]);
+    aad.extend_from_slice(algorithm_tag::<K>().as_bytes());
+    aad
+}
+
+/// Encrypt a signing key and its current period
+///
+/// The period is read from the key with
+/// [`current_period_kes`](crate::kes::KesAlgorithm::current_period_kes), so
+/// it cannot disagree with the key's evolution state. The intermediate
+/// plaintext is held in a zeroizing buffer and wiped before
+/// returning.
+///
+/// # Errors
+///
+/// Returns [`CryptoError::SerializationError`] if encryption fails.
+pub fn seal<K: UnsoundKesAlgorithm>(
+    signing_key: &K::SigningKey,
+    key: &[u8; 32],
+) -> Result<Vec<u8>> {
+    let period = K::current_period_kes(signing_key);
+    let mut plaintext = Zeroizing::new(Vec::with_capacity(PERIOD_SIZE + K::SIGNING_KEY_SIZE));
+    plaintext.extend_from_slice(&period.to_be_bytes());
+    plaintext.extend_from_slice(&K::raw_serialize_signing_key_kes(signing_key));
+
+    let mut hasher = Blake2b::<U32>::new();
+    hasher.update(NONCE_DOMAIN);
+    hasher.update(key);
+    hasher.update(plaintext.as_slice());
+    let digest = hasher.finalize();
+    let nonce = Nonce::from_slice(&digest[..NONCE_SIZE]);
+
+    let cipher = ChaCha20Poly1305::new(key.into());
+    let ciphertext = cipher
+        .encrypt(
+            nonce,
+            Payload {
+                msg: plaintext.as_slice(),
+                aad: &associated_data::<K>(),
+            },
+        )
+        .map_err(|_| CryptoError::SerializationError)?;
+
+    let mut sealed = Vec::with_capacity(HEADER_SIZE + ciphertext.len());
+    sealed.push(SEALED_KEY_VERSION);
+    sealed.extend_from_slice(nonce);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(sealed)
+}
+
+/// Decrypt a sealed signing key, returning it with the period it was sealed at
+///
+/// # Errors
+///
+/// - [`CryptoError::DeserializationError`] if the blob is malformed, uses an
+///   unknown version, or does not contain a valid key for `K` at the sealed
+///   period
+/// - [`KesError::SealAuthenticationFailed`] if the ciphertext was tampered with,
+///   the wrong key was supplied, or the blob was sealed for another scheme
+pub fn unseal<K: UnsoundKesAlgorithm>(
+    sealed: &[u8],
+    key: &[u8; 32],
+) -> Result<(K::SigningKey, Period)> {
+    if sealed.len() != HEADER_SIZE + PERIOD_SIZE + K::SIGNING_KEY_SIZE + TAG_SIZE
+        || sealed[0] != SEALED_KEY_VERSION
+    {
+        return Err(CryptoError::DeserializationError);
+    }
+
+    let nonce = Nonce::from_slice(&sealed[1..HEADER_SIZE]);
+    let cipher = ChaCha20Poly1305::new(key.into());
+    let plaintext = Zeroizing::new(
+        cipher
+            .decrypt(
+                nonce,
+                Payload {
+                    msg: &sealed[HEADER_SIZE..],
+                    aad: &associated_data::<K>(),
+                },
+            )
+            .map_err(|_| CryptoError::KesError(KesError::SealAuthenticationFailed))?,
+    );
+
+    let mut period_bytes = [0u8; PERIOD_SIZE];
+    period_bytes.copy_from_slice(&plaintext[..PERIOD_SIZE]);
+    let period = Period::from_be_bytes(period_bytes);
+    let signing_key = K::raw_deserialize_signing_key_kes(&plaintext[PERIOD_SIZE..])
+        .ok_or(CryptoError::DeserializationError)?;
+    if K::current_period_kes(&signing_key) != period {
+        K::forget_signing_key_kes(signing_key);
+        return Err(CryptoError::DeserializationError);
+    }
+
+    Ok((signing_key, period))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kes::{KesAlgorithm, LegacySeedExpansion, Sha256, Sum3Kes, Sum3KesWith};
+
+    #[test]
+    fn seal_unseal_roundtrip_after_evolution() {
+        let storage_key = [0x42u8; 32];
+        let mut sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[7u8; 32]).unwrap();
+        let vk = Sum3Kes::derive_verification_key(&sk).unwrap();
+        for period in 0..5 {
+            sk = Sum3Kes::update_kes(&(), sk, period).unwrap().unwrap();
+        }
+
+        let sealed = seal::<Sum3Kes>(&sk, &storage_key).unwrap();
+        let (restored, period) = unseal::<Sum3Kes>(&sealed, &storage_key).unwrap();
+        assert_eq!(period, 5);
+
+        let sig = Sum3Kes::sign_kes(&(), 5, b"after restart", &restored).unwrap();
+        Sum3Kes::verify_kes(&(), &vk, 5, b"after restart", &sig).unwrap();
+        assert_eq!(
+            *Sum3Kes::raw_serialize_signing_key_kes(&restored),
+            *Sum3Kes::raw_serialize_signing_key_kes(&sk)
+        );
+    }
+
+    #[test]
+    fn tampered_ciphertext_fails_authentication() {
+        let storage_key = [0x24u8; 32];
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[8u8; 32]).unwrap();
+        let mut sealed = seal::<Sum3Kes>(&sk, &storage_key).unwrap();
+
+        let last = sealed.len() - 1;
+        sealed[HEADER_SIZE + 3] ^= 0x01;
+        assert_eq!(
+            unseal::<Sum3Kes>(&sealed, &storage_key).err(),
+            Some(CryptoError::KesError(KesError::SealAuthenticationFailed))
+        );
+
+        // Undo the flip and corrupt the tag instead
+        sealed[HEADER_SIZE + 3] ^= 0x01;
+        sealed[last] ^= 0x80;
+        assert_eq!(
+            unseal::<Sum3Kes>(&sealed, &storage_key).err(),
+            Some(CryptoError::KesError(KesError::SealAuthenticationFailed))
+        );
+    }
+
+    #[test]
+    fn other_scheme_of_the_same_size_fails_authentication() {
+        type LegacySum3Kes = Sum3KesWith<LegacySeedExpansion>;
+        let storage_key = [0x25u8; 32];
+        let sk = Sum3KesWith::<Sha256>::gen_key_kes_from_seed_bytes(&[11u8; 32]).unwrap();
+        let sealed = seal::<Sum3KesWith<Sha256>>(&sk, &storage_key).unwrap();
+        assert_eq!(
+            Sum3KesWith::<Sha256>::SIGNING_KEY_SIZE,
+            Sum3Kes::SIGNING_KEY_SIZE
+        );
+        assert_eq!(LegacySum3Kes::SIGNING_KEY_SIZE, Sum3Kes::SIGNING_KEY_SIZE);
+
+        // Another tree hash, and the same hash with the legacy seed expansion
+        assert_eq!(
+            unseal::<Sum3Kes>(&sealed, &storage_key).err(),
+            Some(CryptoError::KesError(KesError::SealAuthenticationFailed))
+        );
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[11u8; 32]).unwrap();
+        let sealed = seal::<Sum3Kes>(&sk, &storage_key).unwrap();
+        assert_eq!(
+            unseal::<LegacySum3Kes>(&sealed, &storage_key).err(),
+            Some(CryptoError::KesError(KesError::SealAuthenticationFailed))
+        );
+        unseal::<Sum3Kes>(&sealed, &storage_key).unwrap();
+    }
+
+    #[test]
+    fn wrong_storage_key_fails_authentication() {
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[9u8; 32]).unwrap();
+        let sealed = seal::<Sum3Kes>(&sk, &[1u8; 32]).unwrap();
+        assert_eq!(
+            unseal::<Sum3Kes>(&sealed, &[2u8; 32]).err(),
+            Some(CryptoError::KesError(KesError::SealAuthenticationFailed))
+        );
+    }
+
+    #[test]
+    fn truncated_blob_is_rejected() {
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[10u8; 32]).unwrap();
+        let sealed = seal::<Sum3Kes>(&sk, &[1u8; 32]).unwrap();
+        assert_eq!(
+            unseal::<Sum3Kes>(&sealed[..sealed.len() - 1], &[1u8; 32]).err(),
+            Some(CryptoError::DeserializationError)
+        );
+    }
+}
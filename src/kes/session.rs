@@ -0,0 +1,218 @@
+//! Repeated signing at one period
+//!
+//! [`sign_kes`](KesAlgorithm::sign_kes) walks the tree from the root on
+//! every call and re-expands the leaf Ed25519 key from its seed. A node that
+//! signs many messages in the same period can open a [`KesSigningSession`]
+//! instead: it resolves the path to the current leaf once, keeps the expanded
+//! leaf key and the verification keys along the path, and reuses them until
+//! [`evolve`](KesSigningSession::evolve) moves to the next period.
+//!
+//! # Example
+//!
+//! ```rust
+//! use cardano_crypto::kes::{KesAlgorithm, KesSigningSession, Sum6Kes};
+//!
+//! # fn main() -> cardano_crypto::common::Result<()> {
+//! let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[3u8; 32])?;
+//! let vk = Sum6Kes::derive_verification_key(&sk)?;
+//!
+//! let session = KesSigningSession::<Sum6Kes>::new(&(), sk)?;
+//! let sig = session.sign(b"tx 1");
+//! Sum6Kes::verify_kes(&(), &vk, 0, b"tx 1", &sig)?;
+//!
+//! let session = session.evolve(&())?.expect("64 periods");
+//! assert_eq!(session.period(), 1);
+//! Sum6Kes::verify_kes(&(), &vk, 1, b"tx 2", &session.sign(b"tx 2"))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::common::error::Result;
+use crate::dsign::ed25519::{Ed25519Signature, Ed25519SigningKey};
+use crate::kes::{KesAlgorithm, Period};
+use crate::metrics::{self, Operation};
+
+/// KES schemes that can cache the signing path for one period
+///
+/// Implemented for `SingleKes<Ed25519>`, `CompactSingleKes<Ed25519>` and every
+/// Sum and CompactSum tree built on them. Use it through
+/// [`KesSigningSession`].
+pub trait SessionKes: KesAlgorithm {
+    /// Cached signing state for one period
+    ///
+    /// Holds the expanded leaf signing key, which is wiped on drop, and the
+    /// verification keys each level adds to a signature.
+    type Branch;
+
+    /// Resolve the path to the leaf that signs at `period`
+    ///
+    /// # Errors
+    ///
+    /// The same period errors as [`sign_kes`](KesAlgorithm::sign_kes).
+    fn session_branch(
+        context: &Self::Context,
+        period: Period,
+        signing_key: &Self::SigningKey,
+    ) -> Result<Self::Branch>;
+
+    /// Sign `message` with a branch from
+    /// [`session_branch`](Self::session_branch)
+    ///
+    /// Produces the same signature as `sign_kes` at the branch's period.
+    fn sign_with_branch(branch: &Self::Branch, message: &[u8]) -> Self::Signature;
+}
+
+/// Expanded Ed25519 key of a KES leaf
+///
+/// Expanding the seed costs a SHA-512 and a base point multiplication, which
+/// dominate a plain `sign_kes` call. The key is zeroized on drop.
+pub struct Ed25519LeafBranch(ed25519_dalek::SigningKey);
+
+impl Ed25519LeafBranch {
+    pub(crate) fn new(signing_key: &Ed25519SigningKey) -> Self {
+        Self(signing_key.signing_key())
+    }
+
+    pub(crate) fn sign(&self, message: &[u8]) -> Ed25519Signature {
+        use ed25519_dalek::Signer;
+        Ed25519Signature::from_dalek(&self.0.sign(message))
+    }
+}
+
+impl core::fmt::Debug for Ed25519LeafBranch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Ed25519LeafBranch([REDACTED])")
+    }
+}
+
+/// A signing key together with its cached path for the current period
+///
+/// [`sign`](Self::sign) produces exactly the signature
+/// [`sign_kes`](KesAlgorithm::sign_kes) would at [`period`](Self::period),
+/// without walking the tree or expanding the leaf key again.
+pub struct KesSigningSession<K: SessionKes> {
+    signing_key: K::SigningKey,
+    period: Period,
+    branch: K::Branch,
+}
+
+impl<K: SessionKes> KesSigningSession<K> {
+    /// Open a session at the signing key's current period
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path to the current leaf cannot be resolved.
+    pub fn new(context: &K::Context, signing_key: K::SigningKey) -> Result<Self> {
+        let period = K::current_period_kes(&signing_key);
+        let branch = K::session_branch(context, period, &signing_key)?;
+        Ok(Self {
+            signing_key,
+            period,
+            branch,
+        })
+    }
+
+    /// Period the session signs at
+    pub fn period(&self) -> Period {
+        self.period
+    }
+
+    /// The underlying signing key
+    pub fn signing_key(&self) -> &K::SigningKey {
+        &self.signing_key
+    }
+
+    /// Sign `message` at the session's period
+    pub fn sign(&self, message: &[u8]) -> K::Signature {
+        let scope = metrics::operation_scope(K::ALGORITHM_NAME, Operation::Sign);
+        let signature = K::sign_with_branch(&self.branch, message);
+        scope.record_signatures(1);
+        signature
+    }
+
+    /// Evolve the key to the next period and resolve the new path once
+    ///
+    /// The cached branch is dropped, and with it the expanded leaf key, before
+    /// the key is updated. Returns `None` once the key has expired.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`update_kes`](KesAlgorithm::update_kes).
+    pub fn evolve(self, context: &K::Context) -> Result<Option<Self>> {
+        let Self {
+            signing_key,
+            period,
+            branch,
+        } = self;
+        drop(branch);
+        match K::update_kes(context, signing_key, period)? {
+            Some(signing_key) => Self::new(context, signing_key).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Close the session and return the signing key
+    pub fn into_signing_key(self) -> K::SigningKey {
+        self.signing_key
+    }
+}
+
+impl<K: SessionKes> core::fmt::Debug for KesSigningSession<K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KesSigningSession")
+            .field("algorithm", &K::ALGORITHM_NAME)
+            .field("period", &self.period)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kes::{CompactSum3Kes, Sum0Kes, Sum3Kes};
+
+    typed_tests! {
+        fn session_matches_sign_kes<K>() for {
+            single: Sum0Kes,
+            sum3: Sum3Kes,
+            compact_sum3: CompactSum3Kes,
+        } {
+            let sk = K::gen_key_kes_from_seed_bytes(&[21u8; 32]).unwrap();
+            let vk = K::derive_verification_key(&sk).unwrap();
+            let mut session = KesSigningSession::<K>::new(&(), sk).unwrap();
+
+            for period in 0..K::total_periods() {
+                assert_eq!(session.period(), period);
+                for message in [&b"first"[..], b"second"] {
+                    let signature = session.sign(message);
+                    let expected =
+                        K::sign_kes(&(), period, message, session.signing_key()).unwrap();
+                    assert_eq!(
+                        K::raw_serialize_signature_kes(&signature),
+                        K::raw_serialize_signature_kes(&expected)
+                    );
+                    K::verify_kes(&(), &vk, period, message, &signature).unwrap();
+                }
+                match session.evolve(&()).unwrap() {
+                    Some(next) => session = next,
+                    None => {
+                        assert_eq!(period, K::total_periods() - 1);
+                        return;
+                    }
+                }
+            }
+            panic!("session outlived the key");
+        }
+    }
+
+    #[test]
+    fn opens_at_current_period() {
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[22u8; 32]).unwrap();
+        let sk = Sum3Kes::update_kes_to(&(), sk, 0, 5).unwrap().unwrap();
+        let session = KesSigningSession::<Sum3Kes>::new(&(), sk).unwrap();
+
+        assert_eq!(session.period(), 5);
+        let sk = session.into_signing_key();
+        assert_eq!(Sum3Kes::current_period_kes(&sk), 5);
+    }
+}
@@ -9,8 +9,14 @@ use core::marker::PhantomData;
 use alloc::vec::Vec;
 
 use crate::common::error::{CryptoError, Result};
-use crate::common::traits::DsignAlgorithm;
-use crate::kes::{KesAlgorithm, KesError, Period};
+use crate::common::traits::{DsignAlgorithm, UnsoundDsignAlgorithm};
+use crate::dsign::ed25519::Ed25519;
+use crate::kes::session::{Ed25519LeafBranch, SessionKes};
+use crate::kes::{
+    output_prefix, Ed25519LeafKes, Ed25519LeafVerifier, KesAlgorithm, KesError, MerklePath, Period,
+    UnsoundKesAlgorithm,
+};
+use crate::metrics::{self, Operation};
 
 /// SingleKES wraps a DSIGN algorithm to provide a 1-period KES.
 ///
@@ -18,7 +24,8 @@ use crate::kes::{KesAlgorithm, KesError, Period};
 /// underlying DSIGN algorithm and only supports period 0.
 ///
 /// # Type Parameters
-/// * `D` - The underlying digital signature algorithm
+/// * `D` - The underlying digital signature algorithm (defaults to [`Ed25519`],
+///   the base case of every Cardano `Sum` construction)
 ///
 /// # Example
 ///
@@ -45,7 +52,7 @@ use crate::kes::{KesAlgorithm, KesError, Period};
 /// # }
 /// ```
 #[derive(Debug)]
-pub struct SingleKes<D: DsignAlgorithm>(PhantomData<D>);
+pub struct SingleKes<D: DsignAlgorithm = Ed25519>(PhantomData<D>);
 
 impl<D> KesAlgorithm for SingleKes<D>
 where
@@ -76,13 +83,34 @@ where
         message: &[u8],
         signing_key: &Self::SigningKey,
     ) -> Result<Self::Signature> {
-        if period != 0 {
+        let scope = metrics::operation_scope(Self::ALGORITHM_NAME, Operation::Sign);
+        if period > Self::max_signable_period() {
             return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
                 period,
-                max_period: 0,
+                max_period: Self::max_signable_period(),
             }));
         }
-        D::sign(message, signing_key)
+        let signature = D::sign(message, signing_key)?;
+        scope.record_signatures(1);
+        Ok(signature)
+    }
+
+    fn sign_batch_at_period(
+        _context: &Self::Context,
+        period: Period,
+        messages: &[&[u8]],
+        signing_key: &Self::SigningKey,
+    ) -> Result<Vec<Self::Signature>> {
+        let scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
+        if period > Self::max_signable_period() {
+            return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+                period,
+                max_period: Self::max_signable_period(),
+            }));
+        }
+        let signatures = D::sign_batch(messages, signing_key)?;
+        scope.record_signatures(signatures.len() as u64);
+        Ok(signatures)
     }
 
     fn verify_kes(
@@ -92,13 +120,32 @@ where
         message: &[u8],
         signature: &Self::Signature,
     ) -> Result<()> {
-        if period != 0 {
-            return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+        let _scope = metrics::operation_scope(Self::ALGORITHM_NAME, Operation::Verify);
+        let result = if period > Self::max_signable_period() {
+            Err(CryptoError::KesError(KesError::PeriodOutOfRange {
                 period,
-                max_period: 0,
-            }));
-        }
+                max_period: Self::max_signable_period(),
+            }))
+        } else {
+            D::verify(message, signature, verification_key)
+                .map_err(|_| CryptoError::KesError(KesError::BadEd25519Signature))
+        };
+        metrics::record_verification(result.is_ok());
+        result
+    }
+
+    fn signed_period_kes(
+        verification_key: &Self::VerificationKey,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Option<Period> {
         D::verify(message, signature, verification_key)
+            .is_ok()
+            .then_some(0)
+    }
+
+    fn current_period_kes(_signing_key: &Self::SigningKey) -> Period {
+        0
     }
 
     fn update_kes(
@@ -107,7 +154,7 @@ where
         period: Period,
     ) -> Result<Option<Self::SigningKey>> {
         // Period 0 is the last valid period for SingleKES (total_periods = 1)
-        if period >= Self::total_periods() - 1 {
+        if period >= Self::max_signable_period() {
             // Key expired after period 0
             D::forget_signing_key(signing_key);
             Ok(None)
@@ -117,14 +164,30 @@ where
         }
     }
 
+    fn update_kes_in_place(
+        _context: &Self::Context,
+        signing_key: &mut Self::SigningKey,
+        period: Period,
+    ) -> Result<bool> {
+        // A single-period key never evolves, so it expires here
+        if period < Self::max_signable_period() {
+            return Ok(true);
+        }
+        D::forget_signing_key_in_place(signing_key);
+        Ok(false)
+    }
+
     fn gen_key_kes_from_seed_bytes(seed: &[u8]) -> Result<Self::SigningKey> {
+        let scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
         if seed.len() != Self::SEED_SIZE {
             return Err(CryptoError::KesError(KesError::InvalidSeedLength {
                 expected: Self::SEED_SIZE,
                 actual: seed.len(),
             }));
         }
-        D::gen_key_from_seed(seed)
+        let signing_key = D::gen_key_from_seed(seed)?;
+        scope.record_signing_key();
+        Ok(signing_key)
     }
 
     #[cfg(feature = "alloc")]
@@ -145,15 +208,120 @@ where
         D::deserialize_signature(bytes).ok()
     }
 
+    fn raw_serialize_verification_key_kes_into(
+        key: &Self::VerificationKey,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        D::serialize_verification_key_into(key, output_prefix(out, Self::VERIFICATION_KEY_SIZE)?);
+        Ok(Self::VERIFICATION_KEY_SIZE)
+    }
+
+    fn raw_serialize_signature_kes_into(
+        signature: &Self::Signature,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        D::serialize_signature_into(signature, output_prefix(out, Self::SIGNATURE_SIZE)?);
+        Ok(Self::SIGNATURE_SIZE)
+    }
+
     fn forget_signing_key_kes(signing_key: Self::SigningKey) {
         D::forget_signing_key(signing_key);
     }
 }
 
+impl<D> UnsoundKesAlgorithm for SingleKes<D>
+where
+    D: UnsoundDsignAlgorithm,
+{
+    fn raw_serialize_signing_key_kes(
+        signing_key: &Self::SigningKey,
+    ) -> zeroize::Zeroizing<Vec<u8>> {
+        D::raw_serialize_signing_key(signing_key)
+    }
+
+    fn raw_serialize_signing_key_kes_into(
+        signing_key: &Self::SigningKey,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        D::raw_serialize_signing_key_into(signing_key, output_prefix(out, Self::SIGNING_KEY_SIZE)?);
+        Ok(Self::SIGNING_KEY_SIZE)
+    }
+
+    fn raw_deserialize_signing_key_kes(bytes: &[u8]) -> Option<Self::SigningKey> {
+        D::raw_deserialize_signing_key(bytes).ok()
+    }
+}
+
+impl Ed25519LeafKes for SingleKes<Ed25519> {
+    fn verify_kes_with_ed25519(
+        verifier: &Ed25519LeafVerifier<'_>,
+        _context: &Self::Context,
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<()> {
+        if period > Self::max_signable_period() {
+            return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+                period,
+                max_period: Self::max_signable_period(),
+            }));
+        }
+        if verifier(verification_key.as_bytes(), message, signature.as_bytes()) {
+            Ok(())
+        } else {
+            Err(CryptoError::KesError(KesError::BadEd25519Signature))
+        }
+    }
+
+    fn leaf_signature(signature: &Self::Signature) -> [u8; 64] {
+        *signature.as_bytes()
+    }
+
+    fn merkle_path(
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        signature: &Self::Signature,
+    ) -> Result<MerklePath> {
+        if period > Self::max_signable_period() {
+            return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+                period,
+                max_period: Self::max_signable_period(),
+            }));
+        }
+        Ok(MerklePath {
+            leaf_verification_key: *verification_key.as_bytes(),
+            leaf_signature: *signature.as_bytes(),
+            steps: Vec::new(),
+        })
+    }
+}
+
+impl SessionKes for SingleKes<Ed25519> {
+    type Branch = Ed25519LeafBranch;
+
+    fn session_branch(
+        _context: &Self::Context,
+        period: Period,
+        signing_key: &Self::SigningKey,
+    ) -> Result<Self::Branch> {
+        if period > Self::max_signable_period() {
+            return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+                period,
+                max_period: Self::max_signable_period(),
+            }));
+        }
+        Ok(Ed25519LeafBranch::new(signing_key))
+    }
+
+    fn sign_with_branch(branch: &Self::Branch, message: &[u8]) -> Self::Signature {
+        branch.sign(message)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::dsign::ed25519::Ed25519;
 
     #[test]
     fn single_kes_only_supports_period_zero() {
@@ -202,4 +370,120 @@ mod tests {
         // Verify with restored data
         <SingleKes<Ed25519>>::verify_kes(&(), &vk_restored, 0, msg, &sig_restored).unwrap();
     }
+
+    #[test]
+    fn single_kes_current_period_is_zero() {
+        let seed = vec![4u8; <SingleKes<Ed25519>>::SEED_SIZE];
+        let sk = <SingleKes<Ed25519>>::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        assert_eq!(<SingleKes<Ed25519>>::current_period_kes(&sk), 0);
+    }
+
+    #[test]
+    fn single_kes_defaults_to_ed25519() {
+        assert_eq!(<SingleKes>::total_periods(), 1);
+        assert_eq!(
+            <SingleKes>::SEED_SIZE,
+            <Ed25519 as DsignAlgorithm>::SEED_SIZE
+        );
+        assert_eq!(
+            <SingleKes>::SIGNATURE_SIZE,
+            <Ed25519 as DsignAlgorithm>::SIGNATURE_SIZE
+        );
+    }
+
+    /// Ed25519 over domain-separated messages: a stand-in for an alternative base signature
+    struct TaggedEd25519;
+
+    impl TaggedEd25519 {
+        fn tagged(message: &[u8]) -> Vec<u8> {
+            let mut tagged = b"tagged:".to_vec();
+            tagged.extend_from_slice(message);
+            tagged
+        }
+    }
+
+    impl DsignAlgorithm for TaggedEd25519 {
+        type VerificationKey = <Ed25519 as DsignAlgorithm>::VerificationKey;
+        type SigningKey = <Ed25519 as DsignAlgorithm>::SigningKey;
+        type Signature = <Ed25519 as DsignAlgorithm>::Signature;
+        type Context = ();
+
+        const ALGORITHM_NAME: &'static str = "TaggedEd25519";
+        const SEED_SIZE: usize = <Ed25519 as DsignAlgorithm>::SEED_SIZE;
+        const VERIFICATION_KEY_SIZE: usize = <Ed25519 as DsignAlgorithm>::VERIFICATION_KEY_SIZE;
+        const SIGNING_KEY_SIZE: usize = <Ed25519 as DsignAlgorithm>::SIGNING_KEY_SIZE;
+        const SIGNATURE_SIZE: usize = <Ed25519 as DsignAlgorithm>::SIGNATURE_SIZE;
+
+        fn gen_key_from_seed(seed: &[u8]) -> Result<Self::SigningKey> {
+            <Ed25519 as DsignAlgorithm>::gen_key_from_seed(seed)
+        }
+
+        fn derive_verification_key(
+            signing_key: &Self::SigningKey,
+        ) -> Result<Self::VerificationKey> {
+            <Ed25519 as DsignAlgorithm>::derive_verification_key(signing_key)
+        }
+
+        fn sign(message: &[u8], signing_key: &Self::SigningKey) -> Result<Self::Signature> {
+            <Ed25519 as DsignAlgorithm>::sign(&Self::tagged(message), signing_key)
+        }
+
+        fn verify(
+            message: &[u8],
+            signature: &Self::Signature,
+            verification_key: &Self::VerificationKey,
+        ) -> Result<()> {
+            <Ed25519 as DsignAlgorithm>::verify(&Self::tagged(message), signature, verification_key)
+        }
+
+        fn serialize_verification_key(key: &Self::VerificationKey) -> Vec<u8> {
+            <Ed25519 as DsignAlgorithm>::serialize_verification_key(key)
+        }
+
+        fn deserialize_verification_key(bytes: &[u8]) -> Result<Self::VerificationKey> {
+            <Ed25519 as DsignAlgorithm>::deserialize_verification_key(bytes)
+        }
+
+        fn serialize_signature(signature: &Self::Signature) -> Vec<u8> {
+            <Ed25519 as DsignAlgorithm>::serialize_signature(signature)
+        }
+
+        fn deserialize_signature(bytes: &[u8]) -> Result<Self::Signature> {
+            <Ed25519 as DsignAlgorithm>::deserialize_signature(bytes)
+        }
+
+        fn forget_signing_key(signing_key: Self::SigningKey) {
+            <Ed25519 as DsignAlgorithm>::forget_signing_key(signing_key)
+        }
+
+        fn forget_signing_key_in_place(signing_key: &mut Self::SigningKey) {
+            <Ed25519 as DsignAlgorithm>::forget_signing_key_in_place(signing_key)
+        }
+    }
+
+    #[test]
+    fn sum_tree_accepts_alternative_base_signature() {
+        use crate::kes::{Blake2b256, Sum2Kes, SumKes};
+
+        type TaggedSum2 = SumKes<SumKes<SingleKes<TaggedEd25519>, Blake2b256>, Blake2b256>;
+
+        let seed = [26u8; 32];
+        let sk = TaggedSum2::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = TaggedSum2::derive_verification_key(&sk).unwrap();
+        let sk = TaggedSum2::update_kes_to(&(), sk, 0, 2).unwrap().unwrap();
+        let sig = TaggedSum2::sign_kes(&(), 2, b"block", &sk).unwrap();
+        TaggedSum2::verify_kes(&(), &vk, 2, b"block", &sig).unwrap();
+
+        // Same keys as plain Sum2Kes, but the leaf signatures differ
+        let plain_sk = Sum2Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        assert_eq!(Sum2Kes::derive_verification_key(&plain_sk).unwrap(), vk);
+        let plain_sk = Sum2Kes::update_kes_to(&(), plain_sk, 0, 2)
+            .unwrap()
+            .unwrap();
+        let plain_sig = Sum2Kes::sign_kes(&(), 2, b"block", &plain_sk).unwrap();
+        assert_ne!(
+            TaggedSum2::raw_serialize_signature_kes(&sig),
+            Sum2Kes::raw_serialize_signature_kes(&plain_sig)
+        );
+    }
 }
@@ -10,8 +10,14 @@ use core::marker::PhantomData;
 use alloc::vec::Vec;
 
 use crate::common::error::{CryptoError, Result};
-use crate::common::traits::DsignAlgorithm;
-use crate::kes::{KesAlgorithm, KesError, Period};
+use crate::common::traits::{DsignAlgorithm, UnsoundDsignAlgorithm};
+use crate::dsign::ed25519::Ed25519;
+use crate::kes::session::{Ed25519LeafBranch, SessionKes};
+use crate::kes::{
+    output_prefix, signature_from_bytes, Ed25519LeafKes, Ed25519LeafVerifier, KesAlgorithm,
+    KesError, MerklePath, Period, UnsoundKesAlgorithm,
+};
+use crate::metrics::{self, Operation};
 
 /// CompactSingleKES wraps a DSIGN algorithm with an embedded verification key
 ///
@@ -70,6 +76,43 @@ where
     }
 }
 
+/// Lowercase hex of the raw serialization: signature, then verification key
+impl<D> core::fmt::Display for CompactSingleSig<D>
+where
+    D: DsignAlgorithm,
+    D::VerificationKey: Clone,
+    D::Signature: Clone,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::common::hex::fmt(&CompactSingleKes::<D>::raw_serialize_signature_kes(self), f)
+    }
+}
+
+impl<D> CompactSingleSig<D>
+where
+    D: DsignAlgorithm,
+    D::VerificationKey: Clone,
+    D::Signature: Clone,
+{
+    /// Raw serialization, the same bytes as
+    /// [`raw_serialize_signature_kes`](KesAlgorithm::raw_serialize_signature_kes)
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        CompactSingleKes::<D>::raw_serialize_signature_kes(self)
+    }
+
+    /// Parse a signature from its raw serialization
+    ///
+    /// # Errors
+    ///
+    /// - [`KesError::WrongLength`] unless `bytes` is exactly
+    ///   [`SIGNATURE_SIZE`](KesAlgorithm::SIGNATURE_SIZE) long
+    /// - [`KesError::InvalidEncoding`] if the bytes do not decode
+    pub fn from_bytes(bytes: &[u8]) -> core::result::Result<Self, KesError> {
+        signature_from_bytes::<CompactSingleKes<D>>(bytes)
+    }
+}
+
 /// Helper trait to extract the verification key from a KES signature
 ///
 /// This is used by CompactSumKES to reconstruct verification keys from signatures.
@@ -90,6 +133,30 @@ pub trait CompactKesComponents: KesAlgorithm {
         signature: &Self::Signature,
         period: Period,
     ) -> Self::VerificationKey;
+
+    /// Every period paired with the verification key the path in `signature`
+    /// hashes up to if it was signed at that period
+    ///
+    /// The provided method rebuilds each key independently; CompactSumKES
+    /// overrides it to share the hashes below each level.
+    fn candidate_verification_keys(
+        signature: &Self::Signature,
+    ) -> Vec<(Period, Self::VerificationKey)> {
+        (0..Self::total_periods())
+            .map(|period| {
+                (
+                    period,
+                    Self::active_verification_key_from_signature(signature, period),
+                )
+            })
+            .collect()
+    }
+
+    /// Check only the leaf signature over `message`, ignoring the Merkle path
+    ///
+    /// The leaf carries its own verification key, so this holds or fails
+    /// independently of the period. Not recorded in [`crate::metrics`].
+    fn leaf_signature_valid(message: &[u8], signature: &Self::Signature) -> bool;
 }
 
 impl<D: DsignAlgorithm> OptimizedKesSignature for CompactSingleSig<D> {
@@ -100,6 +167,43 @@ impl<D: DsignAlgorithm> OptimizedKesSignature for CompactSingleSig<D> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<D> serde::Serialize for CompactSingleSig<D>
+where
+    D: DsignAlgorithm,
+    D::VerificationKey: Clone,
+    D::Signature: Clone,
+{
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        crate::common::bytes_serde::serialize(
+            &CompactSingleKes::<D>::raw_serialize_signature_kes(self),
+            serializer,
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, D> serde::Deserialize<'de> for CompactSingleSig<D>
+where
+    D: DsignAlgorithm,
+    D::VerificationKey: Clone,
+    D::Signature: Clone,
+{
+    fn deserialize<De: serde::Deserializer<'de>>(
+        deserializer: De,
+    ) -> core::result::Result<Self, De::Error> {
+        let bytes = crate::common::bytes_serde::deserialize(
+            deserializer,
+            CompactSingleKes::<D>::SIGNATURE_SIZE,
+        )?;
+        CompactSingleKes::<D>::raw_deserialize_signature_kes(&bytes)
+            .ok_or_else(|| serde::de::Error::custom("invalid CompactSingleKES signature"))
+    }
+}
+
 impl<D> KesAlgorithm for CompactSingleKes<D>
 where
     D: DsignAlgorithm,
@@ -132,22 +236,50 @@ where
         message: &[u8],
         signing_key: &Self::SigningKey,
     ) -> Result<Self::Signature> {
-        if period != 0 {
+        let scope = metrics::operation_scope(Self::ALGORITHM_NAME, Operation::Sign);
+        if period > Self::max_signable_period() {
             return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
                 period,
-                max_period: 0,
+                max_period: Self::max_signable_period(),
             }));
         }
 
         let signature = D::sign(message, signing_key)?;
         let verification_key = D::derive_verification_key(signing_key)?;
 
+        scope.record_signatures(1);
         Ok(CompactSingleSig {
             signature,
             verification_key,
         })
     }
 
+    fn sign_batch_at_period(
+        _context: &Self::Context,
+        period: Period,
+        messages: &[&[u8]],
+        signing_key: &Self::SigningKey,
+    ) -> Result<Vec<Self::Signature>> {
+        let scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
+        if period > Self::max_signable_period() {
+            return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+                period,
+                max_period: Self::max_signable_period(),
+            }));
+        }
+
+        let verification_key = D::derive_verification_key(signing_key)?;
+        let signatures = D::sign_batch(messages, signing_key)?;
+        scope.record_signatures(signatures.len() as u64);
+        Ok(signatures
+            .into_iter()
+            .map(|signature| CompactSingleSig {
+                signature,
+                verification_key: verification_key.clone(),
+            })
+            .collect())
+    }
+
     fn verify_kes(
         _context: &Self::Context,
         _verification_key: &Self::VerificationKey,
@@ -155,15 +287,33 @@ where
         message: &[u8],
         signature: &Self::Signature,
     ) -> Result<()> {
-        if period != 0 {
-            return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+        let _scope = metrics::operation_scope(Self::ALGORITHM_NAME, Operation::Verify);
+        let result = if period > Self::max_signable_period() {
+            Err(CryptoError::KesError(KesError::PeriodOutOfRange {
                 period,
-                max_period: 0,
-            }));
-        }
+                max_period: Self::max_signable_period(),
+            }))
+        } else {
+            // Verify using the embedded verification key from the signature
+            D::verify(message, &signature.signature, &signature.verification_key)
+                .map_err(|_| CryptoError::KesError(KesError::BadEd25519Signature))
+        };
+        metrics::record_verification(result.is_ok());
+        result
+    }
 
-        // Verify using the embedded verification key from the signature
-        D::verify(message, &signature.signature, &signature.verification_key)
+    /// The leaf carries its own verification key, which the enclosing
+    /// CompactSum level checks against the tree
+    fn signed_period_kes(
+        _verification_key: &Self::VerificationKey,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Option<Period> {
+        Self::leaf_signature_valid(message, signature).then_some(0)
+    }
+
+    fn current_period_kes(_signing_key: &Self::SigningKey) -> Period {
+        0
     }
 
     fn update_kes(
@@ -172,7 +322,7 @@ where
         period: Period,
     ) -> Result<Option<Self::SigningKey>> {
         // Period 0 is the last valid period for CompactSingleKES
-        if period >= Self::total_periods() - 1 {
+        if period >= Self::max_signable_period() {
             D::forget_signing_key(signing_key);
             Ok(None)
         } else {
@@ -180,14 +330,30 @@ where
         }
     }
 
+    fn update_kes_in_place(
+        _context: &Self::Context,
+        signing_key: &mut Self::SigningKey,
+        period: Period,
+    ) -> Result<bool> {
+        // A single-period key never evolves, so it expires here
+        if period < Self::max_signable_period() {
+            return Ok(true);
+        }
+        D::forget_signing_key_in_place(signing_key);
+        Ok(false)
+    }
+
     fn gen_key_kes_from_seed_bytes(seed: &[u8]) -> Result<Self::SigningKey> {
+        let scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
         if seed.len() != Self::SEED_SIZE {
             return Err(CryptoError::KesError(KesError::InvalidSeedLength {
                 expected: Self::SEED_SIZE,
                 actual: seed.len(),
             }));
         }
-        D::gen_key_from_seed(seed)
+        let signing_key = D::gen_key_from_seed(seed)?;
+        scope.record_signing_key();
+        Ok(signing_key)
     }
 
     #[cfg(feature = "alloc")]
@@ -211,8 +377,7 @@ where
             return None;
         }
 
-        let sig_bytes = &bytes[0..D::SIGNATURE_SIZE];
-        let vk_bytes = &bytes[D::SIGNATURE_SIZE..];
+        let (sig_bytes, vk_bytes) = bytes.split_at_checked(D::SIGNATURE_SIZE)?;
 
         let signature = D::deserialize_signature(sig_bytes).ok()?;
         let verification_key = D::deserialize_verification_key(vk_bytes).ok()?;
@@ -223,6 +388,25 @@ where
         })
     }
 
+    fn raw_serialize_verification_key_kes_into(
+        key: &Self::VerificationKey,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        D::serialize_verification_key_into(key, output_prefix(out, Self::VERIFICATION_KEY_SIZE)?);
+        Ok(Self::VERIFICATION_KEY_SIZE)
+    }
+
+    fn raw_serialize_signature_kes_into(
+        signature: &Self::Signature,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        let out = output_prefix(out, Self::SIGNATURE_SIZE)?;
+        let (sig_out, vk_out) = out.split_at_mut(D::SIGNATURE_SIZE);
+        D::serialize_signature_into(&signature.signature, sig_out);
+        D::serialize_verification_key_into(&signature.verification_key, vk_out);
+        Ok(Self::SIGNATURE_SIZE)
+    }
+
     fn forget_signing_key_kes(signing_key: Self::SigningKey) {
         D::forget_signing_key(signing_key);
     }
@@ -240,6 +424,120 @@ where
     ) -> Self::VerificationKey {
         signature.verification_key.clone()
     }
+
+    fn leaf_signature_valid(message: &[u8], signature: &Self::Signature) -> bool {
+        D::verify(message, &signature.signature, &signature.verification_key).is_ok()
+    }
+}
+
+impl<D> UnsoundKesAlgorithm for CompactSingleKes<D>
+where
+    D: UnsoundDsignAlgorithm,
+    D::VerificationKey: Clone,
+    D::Signature: Clone,
+{
+    fn raw_serialize_signing_key_kes(
+        signing_key: &Self::SigningKey,
+    ) -> zeroize::Zeroizing<Vec<u8>> {
+        D::raw_serialize_signing_key(signing_key)
+    }
+
+    fn raw_serialize_signing_key_kes_into(
+        signing_key: &Self::SigningKey,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        D::raw_serialize_signing_key_into(signing_key, output_prefix(out, Self::SIGNING_KEY_SIZE)?);
+        Ok(Self::SIGNING_KEY_SIZE)
+    }
+
+    fn raw_deserialize_signing_key_kes(bytes: &[u8]) -> Option<Self::SigningKey> {
+        D::raw_deserialize_signing_key(bytes).ok()
+    }
+}
+
+impl Ed25519LeafKes for CompactSingleKes<Ed25519> {
+    fn verify_kes_with_ed25519(
+        verifier: &Ed25519LeafVerifier<'_>,
+        _context: &Self::Context,
+        _verification_key: &Self::VerificationKey,
+        period: Period,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<()> {
+        if period > Self::max_signable_period() {
+            return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+                period,
+                max_period: Self::max_signable_period(),
+            }));
+        }
+
+        // Verify using the embedded verification key from the signature
+        if verifier(
+            signature.verification_key.as_bytes(),
+            message,
+            signature.signature.as_bytes(),
+        ) {
+            Ok(())
+        } else {
+            Err(CryptoError::KesError(KesError::BadEd25519Signature))
+        }
+    }
+
+    fn leaf_signature(signature: &Self::Signature) -> [u8; 64] {
+        *signature.signature.as_bytes()
+    }
+
+    fn merkle_path(
+        _verification_key: &Self::VerificationKey,
+        period: Period,
+        signature: &Self::Signature,
+    ) -> Result<MerklePath> {
+        if period > Self::max_signable_period() {
+            return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+                period,
+                max_period: Self::max_signable_period(),
+            }));
+        }
+        Ok(MerklePath {
+            leaf_verification_key: *signature.verification_key.as_bytes(),
+            leaf_signature: *signature.signature.as_bytes(),
+            steps: Vec::new(),
+        })
+    }
+}
+
+impl SessionKes for CompactSingleKes<Ed25519> {
+    type Branch = (
+        Ed25519LeafBranch,
+        <Ed25519 as DsignAlgorithm>::VerificationKey,
+    );
+
+    fn session_branch(
+        _context: &Self::Context,
+        period: Period,
+        signing_key: &Self::SigningKey,
+    ) -> Result<Self::Branch> {
+        if period > Self::max_signable_period() {
+            return Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+                period,
+                max_period: Self::max_signable_period(),
+            }));
+        }
+        Ok((
+            Ed25519LeafBranch::new(signing_key),
+            Ed25519::derive_verification_key(signing_key)?,
+        ))
+    }
+
+    fn sign_with_branch(
+        (leaf, verification_key): &Self::Branch,
+        message: &[u8],
+    ) -> Self::Signature {
+        CompactSingleSig {
+            signature: leaf.sign(message),
+            verification_key: verification_key.clone(),
+        }
+    }
 }
 
 #[cfg(test)]
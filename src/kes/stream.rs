@@ -0,0 +1,202 @@
+//! Streaming KES verification keys and signatures through `std::io`
+//!
+//! Tools that keep many keys or signatures in a file, or receive them over a
+//! socket, can read and write them one at a time with [`KesStream`] instead of
+//! collecting each into a `Vec` first. The bytes are the raw serialization, so
+//! a stream is just the values laid end to end, each
+//! [`VERIFICATION_KEY_SIZE`](KesAlgorithm::VERIFICATION_KEY_SIZE) or
+//! [`SIGNATURE_SIZE`](KesAlgorithm::SIGNATURE_SIZE) bytes long.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::io::Cursor;
+//!
+//! use cardano_crypto::kes::{KesAlgorithm, KesStream, Sum6Kes};
+//!
+//! # fn main() -> cardano_crypto::common::Result<()> {
+//! let mut out = Vec::new();
+//! for seed in 0..3u8 {
+//!     let vk = Sum6Kes::verification_key_from_seed(&[seed; 32])?;
+//!     Sum6Kes::raw_serialize_verification_key_to_writer(&vk, &mut out)?;
+//! }
+//!
+//! let mut reader = Cursor::new(out);
+//! for seed in 0..3u8 {
+//!     let vk = Sum6Kes::raw_deserialize_verification_key_from_reader(&mut reader)?;
+//!     assert_eq!(vk, Sum6Kes::verification_key_from_seed(&[seed; 32])?);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::{Read, Write};
+
+use crate::common::error::{CryptoError, Result};
+use crate::kes::KesAlgorithm;
+
+/// Largest value staged on the stack; every built-in scheme fits
+const STACK_BUFFER: usize = 1024;
+
+/// Reading and writing the public values of a KES scheme through `std::io`
+///
+/// Implemented for every [`KesAlgorithm`]. Each value is staged in a stack
+/// buffer through the `_into` serializers, so nothing is allocated for the
+/// schemes this crate defines; only a custom scheme with values over 1 KiB
+/// falls back to a heap buffer.
+pub trait KesStream: KesAlgorithm {
+    /// Write the raw verification key to `writer`
+    ///
+    /// # Errors
+    ///
+    /// [`CryptoError::Io`] if the write fails.
+    fn raw_serialize_verification_key_to_writer<W: Write>(
+        key: &Self::VerificationKey,
+        mut writer: W,
+    ) -> Result<()> {
+        with_buffer(Self::VERIFICATION_KEY_SIZE, |buffer| {
+            let written = Self::raw_serialize_verification_key_kes_into(key, buffer)
+                .map_err(CryptoError::KesError)?;
+            writer.write_all(&buffer[..written]).map_err(io_error)
+        })
+    }
+
+    /// Read one raw verification key from `reader`
+    ///
+    /// Consumes exactly
+    /// [`VERIFICATION_KEY_SIZE`](KesAlgorithm::VERIFICATION_KEY_SIZE) bytes.
+    ///
+    /// # Errors
+    ///
+    /// - [`CryptoError::Io`] if the read fails, with
+    ///   [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) if the stream
+    ///   ends part way through the key
+    /// - [`CryptoError::DeserializationError`] if the bytes are not a valid key
+    fn raw_deserialize_verification_key_from_reader<R: Read>(
+        mut reader: R,
+    ) -> Result<Self::VerificationKey> {
+        with_buffer(Self::VERIFICATION_KEY_SIZE, |buffer| {
+            reader.read_exact(buffer).map_err(io_error)?;
+            Self::raw_deserialize_verification_key_kes(buffer)
+                .ok_or(CryptoError::DeserializationError)
+        })
+    }
+
+    /// Write the raw signature to `writer`
+    ///
+    /// # Errors
+    ///
+    /// [`CryptoError::Io`] if the write fails.
+    fn raw_serialize_signature_to_writer<W: Write>(
+        signature: &Self::Signature,
+        mut writer: W,
+    ) -> Result<()> {
+        with_buffer(Self::SIGNATURE_SIZE, |buffer| {
+            let written = Self::raw_serialize_signature_kes_into(signature, buffer)
+                .map_err(CryptoError::KesError)?;
+            writer.write_all(&buffer[..written]).map_err(io_error)
+        })
+    }
+
+    /// Read one raw signature from `reader`
+    ///
+    /// Consumes exactly [`SIGNATURE_SIZE`](KesAlgorithm::SIGNATURE_SIZE)
+    /// bytes.
+    ///
+    /// # Errors
+    ///
+    /// Same as
+    /// [`raw_deserialize_verification_key_from_reader`](Self::raw_deserialize_verification_key_from_reader).
+    fn raw_deserialize_signature_from_reader<R: Read>(mut reader: R) -> Result<Self::Signature> {
+        with_buffer(Self::SIGNATURE_SIZE, |buffer| {
+            reader.read_exact(buffer).map_err(io_error)?;
+            Self::raw_deserialize_signature_kes(buffer).ok_or(CryptoError::DeserializationError)
+        })
+    }
+}
+
+impl<K: KesAlgorithm> KesStream for K {}
+
+fn io_error(err: std::io::Error) -> CryptoError {
+    CryptoError::Io(err.kind())
+}
+
+/// Run `f` on a zeroed buffer of exactly `len` bytes
+fn with_buffer<T>(len: usize, f: impl FnOnce(&mut [u8]) -> T) -> T {
+    if len <= STACK_BUFFER {
+        let mut buffer = [0u8; STACK_BUFFER];
+        f(&mut buffer[..len])
+    } else {
+        f(&mut alloc::vec![0u8; len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kes::{CompactSum3Kes, Sum3Kes, Sum7Kes};
+    use std::io::{Cursor, ErrorKind};
+    use std::vec::Vec;
+
+    typed_tests! {
+        fn values_roundtrip_through_a_cursor<K>() for {
+            sum3: Sum3Kes,
+            compact_sum3: CompactSum3Kes,
+            sum7: Sum7Kes,
+        } {
+            let sk = K::gen_key_kes_from_seed_bytes(&[0x37u8; 32]).unwrap();
+            let vk = K::derive_verification_key(&sk).unwrap();
+            let sigs: Vec<_> = [&b"one"[..], b"two", b"three"]
+                .iter()
+                .map(|message| K::sign_kes(&(), 0, message, &sk).unwrap())
+                .collect();
+
+            let mut cursor = Cursor::new(Vec::new());
+            K::raw_serialize_verification_key_to_writer(&vk, &mut cursor).unwrap();
+            for sig in &sigs {
+                K::raw_serialize_signature_to_writer(sig, &mut cursor).unwrap();
+            }
+            let bytes = cursor.into_inner();
+            assert_eq!(
+                bytes.len(),
+                K::VERIFICATION_KEY_SIZE + 3 * K::SIGNATURE_SIZE
+            );
+            assert_eq!(
+                &bytes[..K::VERIFICATION_KEY_SIZE],
+                &K::raw_serialize_verification_key_kes(&vk)[..]
+            );
+
+            let mut cursor = Cursor::new(&bytes[..]);
+            let read_vk = K::raw_deserialize_verification_key_from_reader(&mut cursor).unwrap();
+            assert_eq!(
+                K::raw_serialize_verification_key_kes(&read_vk),
+                K::raw_serialize_verification_key_kes(&vk)
+            );
+            for (sig, message) in sigs.iter().zip([&b"one"[..], b"two", b"three"]) {
+                let read = K::raw_deserialize_signature_from_reader(&mut cursor).unwrap();
+                assert_eq!(
+                    K::raw_serialize_signature_kes(&read),
+                    K::raw_serialize_signature_kes(sig)
+                );
+                K::verify_kes(&(), &read_vk, 0, message, &read).unwrap();
+            }
+            assert_eq!(
+                K::raw_deserialize_signature_from_reader(&mut cursor).err(),
+                Some(CryptoError::Io(ErrorKind::UnexpectedEof))
+            );
+            K::forget_signing_key_kes(sk);
+        }
+    }
+
+    #[test]
+    fn truncated_stream_is_an_io_error() {
+        let vk = Sum3Kes::verification_key_from_seed(&[0x38u8; 32]).unwrap();
+        let mut bytes = Vec::new();
+        Sum3Kes::raw_serialize_verification_key_to_writer(&vk, &mut bytes).unwrap();
+        bytes.pop();
+        assert_eq!(
+            Sum3Kes::raw_deserialize_verification_key_from_reader(&bytes[..]).err(),
+            Some(CryptoError::Io(ErrorKind::UnexpectedEof))
+        );
+    }
+}
@@ -6,15 +6,34 @@
 //! SumKES composes two KES schemes to create a scheme with double the periods.
 //! The signing key contains keys for both subtrees, and the verification key
 //! is the hash of both subtree verification keys.
+//!
+//! The `Sum1Kes`..`Sum7Kes` aliases hash with Blake2b-256 as Cardano does; the
+//! `SumNKesWith<H>` aliases take any [`KesHashAlgorithm`], e.g. `Sum6KesWith<Sha256>`.
+//! Both are shorthands for [`GenericSumKes<DEPTH, H>`](GenericSumKes), which also
+//! reaches depths past 7.
 
 use core::marker::PhantomData;
 
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
 use crate::common::error::{CryptoError, Result};
-use crate::kes::hash::KesHashAlgorithm;
-use crate::kes::{KesAlgorithm, KesError, Period};
+use crate::common::secure_mem::SecretBytes;
+use crate::kes::hash::{hash_concat_matches, KesHashAlgorithm};
+use crate::kes::merkle::{MerklePath, MerkleStep};
+use crate::kes::period::check_period;
+use crate::kes::session::SessionKes;
+use crate::kes::{
+    check_seed_expansion, checked_tree_depth, gen_subtrees, output_prefix, signature_from_bytes,
+    subtree_verification_keys, BranchCache, Ed25519LeafKes, Ed25519LeafVerifier, KesAlgorithm,
+    KesError, Period, SeedExpansion, UnsoundKesAlgorithm,
+};
+use crate::metrics::{self, Operation};
+
+#[cfg(feature = "constant-time")]
+use crate::kes::pad_subtree_generation;
 
 /// SumKES composes two KES schemes to create a scheme with double the periods
 ///
@@ -72,7 +91,6 @@ where
 /// // Sum2Kes supports 2^2 = 4 periods
 /// assert_eq!(Sum2Kes::total_periods(), 4);
 /// ```
-#[derive(Debug)]
 pub struct SumSigningKey<D, H>
 where
     D: KesAlgorithm,
@@ -81,14 +99,56 @@ where
     /// Current signing key
     pub(crate) sk: D::SigningKey,
     /// Seed for right subtree (None after transition)
-    pub(crate) r1_seed: Option<Vec<u8>>,
+    pub(crate) r1_seed: Option<SecretBytes>,
     /// Left subtree verification key
     pub(crate) vk0: D::VerificationKey,
     /// Right subtree verification key
     pub(crate) vk1: D::VerificationKey,
+    /// Cached verification key, `H(vk0 || vk1)`
+    pub(crate) vk: Vec<u8>,
     _phantom: PhantomData<H>,
 }
 
+/// Shows only the algorithm and current period, never key or seed bytes
+impl<D, H> core::fmt::Debug for SumSigningKey<D, H>
+where
+    D: KesAlgorithm,
+    D::VerificationKey: Clone,
+    H: KesHashAlgorithm,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SumSigningKey")
+            .field("algorithm", &SumKes::<D, H>::ALGORITHM_NAME)
+            .field("period", &SumKes::<D, H>::current_period_kes(self))
+            .finish_non_exhaustive()
+    }
+}
+
+/// Wipes the active subtree key and the right-subtree seed
+///
+/// The verification keys are public and left untouched.
+impl<D, H> Zeroize for SumSigningKey<D, H>
+where
+    D: KesAlgorithm,
+    D::SigningKey: Zeroize,
+    H: KesHashAlgorithm,
+{
+    fn zeroize(&mut self) {
+        self.sk.zeroize();
+        self.r1_seed.zeroize();
+    }
+}
+
+/// The right-subtree seed is held in a self-wiping buffer, so dropping the key wipes
+/// every secret byte as long as the subtree key does the same.
+impl<D, H> ZeroizeOnDrop for SumSigningKey<D, H>
+where
+    D: KesAlgorithm,
+    D::SigningKey: ZeroizeOnDrop,
+    H: KesHashAlgorithm,
+{
+}
+
 /// Signature for SumKES includes child signature and both verification keys
 ///
 /// # Example
@@ -139,6 +199,148 @@ where
     }
 }
 
+/// Lowercase hex of the raw serialization
+impl<D, H> core::fmt::Display for SumSignature<D, H>
+where
+    D: KesAlgorithm,
+    D::VerificationKey: Clone,
+    H: KesHashAlgorithm,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::common::hex::fmt(&SumKes::<D, H>::raw_serialize_signature_kes(self), f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<D, H> serde::Serialize for SumSignature<D, H>
+where
+    D: KesAlgorithm,
+    D::VerificationKey: Clone,
+    H: KesHashAlgorithm,
+{
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        crate::common::bytes_serde::serialize(
+            &SumKes::<D, H>::raw_serialize_signature_kes(self),
+            serializer,
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, D, H> serde::Deserialize<'de> for SumSignature<D, H>
+where
+    D: KesAlgorithm,
+    D::VerificationKey: Clone,
+    H: KesHashAlgorithm,
+{
+    fn deserialize<De: serde::Deserializer<'de>>(
+        deserializer: De,
+    ) -> core::result::Result<Self, De::Error> {
+        let bytes =
+            crate::common::bytes_serde::deserialize(deserializer, SumKes::<D, H>::SIGNATURE_SIZE)?;
+        SumKes::<D, H>::raw_deserialize_signature_kes(&bytes)
+            .ok_or_else(|| serde::de::Error::custom("invalid SumKES signature"))
+    }
+}
+
+impl<D, H> SumSignature<D, H>
+where
+    D: KesAlgorithm,
+    D::VerificationKey: Clone,
+    H: KesHashAlgorithm,
+{
+    /// Raw serialization, the same bytes as
+    /// [`raw_serialize_signature_kes`](KesAlgorithm::raw_serialize_signature_kes)
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        SumKes::<D, H>::raw_serialize_signature_kes(self)
+    }
+
+    /// Parse a signature from its raw serialization
+    ///
+    /// # Errors
+    ///
+    /// - [`KesError::WrongLength`] unless `bytes` is exactly
+    ///   [`SIGNATURE_SIZE`](KesAlgorithm::SIGNATURE_SIZE) long
+    /// - [`KesError::InvalidEncoding`] if the bytes do not decode
+    pub fn from_bytes(bytes: &[u8]) -> core::result::Result<Self, KesError> {
+        signature_from_bytes::<SumKes<D, H>>(bytes)
+    }
+}
+
+impl<D, H> SumKes<D, H>
+where
+    D: KesAlgorithm,
+    D::VerificationKey: Clone,
+    H: KesHashAlgorithm,
+{
+    /// Number of periods covered by each subtree `level` steps below the root
+    ///
+    /// Level 0 is the whole tree and level `TREE_DEPTH` a single period;
+    /// deeper levels return `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{Sum6Kes, KesAlgorithm};
+    ///
+    /// assert_eq!(Sum6Kes::TREE_DEPTH, 6);
+    /// assert_eq!(Sum6Kes::periods_per_level(0), Some(64));
+    /// assert_eq!(Sum6Kes::periods_per_level(1), Some(32));
+    /// assert_eq!(Sum6Kes::periods_per_level(6), Some(1));
+    /// assert_eq!(Sum6Kes::periods_per_level(7), None);
+    /// ```
+    pub const fn periods_per_level(level: u32) -> Option<Period> {
+        match <Self as KesAlgorithm>::TREE_DEPTH.checked_sub(level) {
+            Some(height) => Some(1 << height),
+            None => None,
+        }
+    }
+
+    /// Root verification key of the [`CompactSumKes`](super::CompactSumKes) tree
+    /// built from the same seed
+    ///
+    /// Both families split seeds the same way and hash `vk0 || vk1` with `H`
+    /// at every level, so the roots are identical bytes and this is a copy.
+    /// A compact signature of the same depth and hash verifies against the
+    /// result; keys from a different depth or hash do not.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{CompactSum6Kes, KesAlgorithm, Sum6Kes};
+    ///
+    /// let seed = [7u8; 32];
+    /// let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&seed)?;
+    /// let vk = Sum6Kes::derive_verification_key(&sk)?;
+    ///
+    /// let compact_sk = CompactSum6Kes::gen_key_kes_from_seed_bytes(&seed)?;
+    /// let compact_vk = CompactSum6Kes::derive_verification_key(&compact_sk)?;
+    /// assert_eq!(Sum6Kes::to_compact_vk(&vk), compact_vk);
+    /// # Ok::<(), cardano_crypto::common::error::CryptoError>(())
+    /// ```
+    #[must_use]
+    pub fn to_compact_vk(verification_key: &[u8]) -> Vec<u8> {
+        verification_key.to_vec()
+    }
+
+    /// Subtree key and period within it that `period` selects
+    fn active_subtree(
+        signature: &SumSignature<D, H>,
+        period: Period,
+    ) -> (&D::VerificationKey, Period) {
+        let t_half = D::total_periods();
+        if period < t_half {
+            (&signature.vk0, period)
+        } else {
+            (&signature.vk1, period - t_half)
+        }
+    }
+}
+
 impl<D, H> KesAlgorithm for SumKes<D, H>
 where
     D: KesAlgorithm,
@@ -150,22 +352,38 @@ where
     type Signature = SumSignature<D, H>;
     type Context = D::Context;
 
-    const ALGORITHM_NAME: &'static str = D::ALGORITHM_NAME;
+    const ALGORITHM_NAME: &'static str = super::sum_algorithm_name(D::TREE_DEPTH + 1);
+    const TREE_HASH: Option<(&'static str, SeedExpansion)> =
+        Some((H::ALGORITHM_NAME, H::SEED_EXPANSION));
     const SEED_SIZE: usize = D::SEED_SIZE;
     const VERIFICATION_KEY_SIZE: usize = H::OUTPUT_SIZE;
     const SIGNING_KEY_SIZE: usize =
         D::SIGNING_KEY_SIZE + D::SEED_SIZE + 2 * D::VERIFICATION_KEY_SIZE;
     const SIGNATURE_SIZE: usize = D::SIGNATURE_SIZE + 2 * D::VERIFICATION_KEY_SIZE;
+    const TREE_DEPTH: u32 = checked_tree_depth(D::TREE_DEPTH + 1);
 
     fn total_periods() -> Period {
-        2 * D::total_periods()
+        Self::TOTAL_PERIODS
+    }
+
+    fn verification_key_from_seed(seed: &[u8]) -> Result<Self::VerificationKey> {
+        if seed.len() != Self::SEED_SIZE {
+            return Err(CryptoError::KesError(KesError::InvalidSeedLength {
+                expected: Self::SEED_SIZE,
+                actual: seed.len(),
+            }));
+        }
+        check_seed_expansion::<D, H>()?;
+
+        let (r0_bytes, r1_bytes) = H::expand_seed(seed);
+        let (r0_bytes, r1_bytes) = (Zeroizing::new(r0_bytes), Zeroizing::new(r1_bytes));
+        let (vk0, vk1) = subtree_verification_keys::<D>(&r0_bytes, &r1_bytes)?;
+        Ok(H::hash_concat(&vk0, &vk1))
     }
 
     fn derive_verification_key(signing_key: &Self::SigningKey) -> Result<Self::VerificationKey> {
-        // vk = H(vk0 || vk1)
-        let vk0_bytes = D::raw_serialize_verification_key_kes(&signing_key.vk0);
-        let vk1_bytes = D::raw_serialize_verification_key_kes(&signing_key.vk1);
-        Ok(H::hash_concat(&vk0_bytes, &vk1_bytes))
+        // vk = H(vk0 || vk1), computed once at key generation
+        Ok(signing_key.vk.clone())
     }
 
     fn sign_kes(
@@ -174,6 +392,8 @@ where
         message: &[u8],
         signing_key: &Self::SigningKey,
     ) -> Result<Self::Signature> {
+        let scope = metrics::operation_scope(Self::ALGORITHM_NAME, Operation::Sign);
+        check_period::<Self>(period).map_err(CryptoError::KesError)?;
         let t_half = D::total_periods();
 
         let sigma = if period < t_half {
@@ -184,6 +404,7 @@ where
             D::sign_kes(context, period - t_half, message, &signing_key.sk)?
         };
 
+        scope.record_signatures(1);
         Ok(SumSignature {
             sigma,
             vk0: signing_key.vk0.clone(),
@@ -192,6 +413,34 @@ where
         })
     }
 
+    fn sign_batch_at_period(
+        context: &Self::Context,
+        period: Period,
+        messages: &[&[u8]],
+        signing_key: &Self::SigningKey,
+    ) -> Result<Vec<Self::Signature>> {
+        let scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
+        check_period::<Self>(period).map_err(CryptoError::KesError)?;
+        let t_half = D::total_periods();
+        let child_period = if period < t_half {
+            period
+        } else {
+            period - t_half
+        };
+
+        let sigmas = D::sign_batch_at_period(context, child_period, messages, &signing_key.sk)?;
+        scope.record_signatures(sigmas.len() as u64);
+        Ok(sigmas
+            .into_iter()
+            .map(|sigma| SumSignature {
+                sigma,
+                vk0: signing_key.vk0.clone(),
+                vk1: signing_key.vk1.clone(),
+                _phantom: PhantomData,
+            })
+            .collect())
+    }
+
     fn verify_kes(
         context: &Self::Context,
         verification_key: &Self::VerificationKey,
@@ -199,29 +448,80 @@ where
         message: &[u8],
         signature: &Self::Signature,
     ) -> Result<()> {
+        let _scope = metrics::operation_scope(Self::ALGORITHM_NAME, Operation::Verify);
+        if let Err(err) = check_period::<Self>(period) {
+            metrics::record_verification(false);
+            return Err(CryptoError::KesError(err));
+        }
         // Verify that H(vk0 || vk1) matches the provided verification key
         let vk0_bytes = D::raw_serialize_verification_key_kes(&signature.vk0);
         let vk1_bytes = D::raw_serialize_verification_key_kes(&signature.vk1);
-        let computed_vk = H::hash_concat(&vk0_bytes, &vk1_bytes);
+        if !hash_concat_matches::<H>(&vk0_bytes, &vk1_bytes, verification_key) {
+            metrics::record_verification(false);
+            return Err(CryptoError::KesError(KesError::BadMerklePath));
+        }
+
+        let (vk_active, child_period) = Self::active_subtree(signature, period);
+        D::verify_kes(context, vk_active, child_period, message, &signature.sigma)
+    }
 
-        if &computed_vk != verification_key {
-            return Err(CryptoError::KesError(KesError::VerificationFailed));
+    fn signed_period_kes(
+        verification_key: &Self::VerificationKey,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Option<Period> {
+        let vk0_bytes = D::raw_serialize_verification_key_kes(&signature.vk0);
+        let vk1_bytes = D::raw_serialize_verification_key_kes(&signature.vk1);
+        if !hash_concat_matches::<H>(&vk0_bytes, &vk1_bytes, verification_key) {
+            return None;
         }
+        D::signed_period_kes(&signature.vk0, message, &signature.sigma).or_else(|| {
+            D::signed_period_kes(&signature.vk1, message, &signature.sigma)
+                .map(|period| period + D::total_periods())
+        })
+    }
 
-        let t_half = D::total_periods();
+    fn verify_kes_cached(
+        context: &Self::Context,
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        message: &[u8],
+        signature: &Self::Signature,
+        cache: &mut BranchCache,
+    ) -> Result<()> {
+        let _scope = metrics::operation_scope(Self::ALGORITHM_NAME, Operation::Verify);
+        if let Err(err) = check_period::<Self>(period) {
+            metrics::record_verification(false);
+            return Err(CryptoError::KesError(err));
+        }
+        let vk0_bytes = D::raw_serialize_verification_key_kes(&signature.vk0);
+        let vk1_bytes = D::raw_serialize_verification_key_kes(&signature.vk1);
+        if !cache.contains(verification_key, &vk0_bytes, &vk1_bytes) {
+            if !hash_concat_matches::<H>(&vk0_bytes, &vk1_bytes, verification_key) {
+                metrics::record_verification(false);
+                return Err(CryptoError::KesError(KesError::BadMerklePath));
+            }
+            cache.insert(verification_key, &vk0_bytes, &vk1_bytes);
+        }
 
-        if period < t_half {
-            // Verify against left subtree
-            D::verify_kes(context, &signature.vk0, period, message, &signature.sigma)
+        let (vk_active, child_period) = Self::active_subtree(signature, period);
+        D::verify_kes_cached(
+            context,
+            vk_active,
+            child_period,
+            message,
+            &signature.sigma,
+            cache,
+        )
+    }
+
+    fn current_period_kes(signing_key: &Self::SigningKey) -> Period {
+        // The right subtree seed is consumed exactly when evolving into the right half
+        let child_period = D::current_period_kes(&signing_key.sk);
+        if signing_key.r1_seed.is_some() {
+            child_period
         } else {
-            // Verify against right subtree
-            D::verify_kes(
-                context,
-                &signature.vk1,
-                period - t_half,
-                message,
-                &signature.sigma,
-            )
+            D::total_periods() + child_period
         }
     }
 
@@ -230,15 +530,18 @@ where
         mut signing_key: Self::SigningKey,
         period: Period,
     ) -> Result<Option<Self::SigningKey>> {
+        let scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
         let t_half = D::total_periods();
 
         if period + 1 >= 2 * t_half {
             // Key has expired
             D::forget_signing_key_kes(signing_key.sk);
+            #[cfg(feature = "constant-time")]
+            Self::pad_update_kes();
             return Ok(None);
         }
 
-        if period + 1 == t_half {
+        let updated = if period + 1 == t_half {
             // Transition from left to right subtree
             let r1_seed = signing_key
                 .r1_seed
@@ -247,71 +550,188 @@ where
 
             // Generate right subtree key
             let sk1 = D::gen_key_kes_from_seed_bytes(&r1_seed)?;
+            #[cfg(feature = "constant-time")]
+            D::pad_update_kes();
 
             // Forget left subtree key
             D::forget_signing_key_kes(signing_key.sk);
 
-            Ok(Some(SumSigningKey {
+            Some(SumSigningKey {
                 sk: sk1,
                 r1_seed: None, // Seed consumed
                 vk0: signing_key.vk0,
                 vk1: signing_key.vk1,
+                vk: signing_key.vk,
                 _phantom: PhantomData,
-            }))
+            })
         } else if period + 1 < t_half {
+            #[cfg(feature = "constant-time")]
+            pad_subtree_generation::<D>();
             // Still in left subtree
-            let updated_sk = D::update_kes(context, signing_key.sk, period)?;
-            match updated_sk {
-                Some(sk) => Ok(Some(SumSigningKey {
-                    sk,
-                    r1_seed: signing_key.r1_seed,
-                    vk0: signing_key.vk0,
-                    vk1: signing_key.vk1,
-                    _phantom: PhantomData,
-                })),
-                None => Ok(None),
-            }
+            D::update_kes(context, signing_key.sk, period)?.map(|sk| SumSigningKey {
+                sk,
+                r1_seed: signing_key.r1_seed,
+                vk0: signing_key.vk0,
+                vk1: signing_key.vk1,
+                vk: signing_key.vk,
+                _phantom: PhantomData,
+            })
         } else {
             // In right subtree
+            #[cfg(feature = "constant-time")]
+            pad_subtree_generation::<D>();
             let adjusted_period = period - t_half;
-            let updated_sk = D::update_kes(context, signing_key.sk, adjusted_period)?;
-            match updated_sk {
-                Some(sk) => Ok(Some(SumSigningKey {
-                    sk,
-                    r1_seed: None,
-                    vk0: signing_key.vk0,
-                    vk1: signing_key.vk1,
-                    _phantom: PhantomData,
-                })),
-                None => Ok(None),
-            }
+            D::update_kes(context, signing_key.sk, adjusted_period)?.map(|sk| SumSigningKey {
+                sk,
+                r1_seed: None,
+                vk0: signing_key.vk0,
+                vk1: signing_key.vk1,
+                vk: signing_key.vk,
+                _phantom: PhantomData,
+            })
+        };
+        if updated.is_some() {
+            scope.record_updates(1);
+        }
+        Ok(updated)
+    }
+
+    fn update_kes_in_place(
+        context: &Self::Context,
+        signing_key: &mut Self::SigningKey,
+        period: Period,
+    ) -> Result<bool> {
+        let scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
+        let t_half = D::total_periods();
+
+        if period + 1 >= 2 * t_half {
+            // Expired: the right subtree wipes its own key in place
+            #[cfg(feature = "constant-time")]
+            pad_subtree_generation::<D>();
+            signing_key.r1_seed = None;
+            D::update_kes_in_place(context, &mut signing_key.sk, period - t_half)?;
+            return Ok(false);
         }
+
+        let evolved = if period + 1 == t_half {
+            // Transition from left to right subtree; the seed is only
+            // consumed once the right subtree key exists
+            let r1_seed = signing_key
+                .r1_seed
+                .as_ref()
+                .ok_or(CryptoError::KesError(KesError::KeyExpired))?;
+            let sk1 = D::gen_key_kes_from_seed_bytes(r1_seed)?;
+            #[cfg(feature = "constant-time")]
+            D::pad_update_kes();
+            D::forget_signing_key_kes(core::mem::replace(&mut signing_key.sk, sk1));
+            signing_key.r1_seed = None;
+            true
+        } else if period + 1 < t_half {
+            #[cfg(feature = "constant-time")]
+            pad_subtree_generation::<D>();
+            D::update_kes_in_place(context, &mut signing_key.sk, period)?
+        } else {
+            #[cfg(feature = "constant-time")]
+            pad_subtree_generation::<D>();
+            D::update_kes_in_place(context, &mut signing_key.sk, period - t_half)?
+        };
+        if evolved {
+            scope.record_updates(1);
+        }
+        Ok(evolved)
+    }
+
+    #[cfg(feature = "constant-time")]
+    fn pad_update_kes() {
+        // Each level either regenerates its right subtree or pads for it,
+        // and the level below does the same
+        pad_subtree_generation::<D>();
+        D::pad_update_kes();
+    }
+
+    fn update_kes_to(
+        context: &Self::Context,
+        mut signing_key: Self::SigningKey,
+        from: Period,
+        to: Period,
+    ) -> Result<Option<Self::SigningKey>> {
+        let scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
+        let current = Self::current_period_kes(&signing_key);
+        if from != current {
+            return Err(CryptoError::KesError(KesError::UpdateFromWrongPeriod {
+                from,
+                current,
+            }));
+        }
+        let t_half = D::total_periods();
+
+        if to >= 2 * t_half {
+            Self::forget_signing_key_kes(signing_key);
+            return Ok(None);
+        }
+        if to < from {
+            return Err(CryptoError::KesError(KesError::UpdateFailed));
+        }
+
+        let updated_sk = if to < t_half {
+            // Target still in left subtree
+            D::update_kes_to(context, signing_key.sk, from, to)?
+        } else if from >= t_half {
+            // Already in right subtree
+            D::update_kes_to(context, signing_key.sk, from - t_half, to - t_half)?
+        } else {
+            // Crossing over: skip the remaining left periods entirely
+            let r1_seed = signing_key
+                .r1_seed
+                .take()
+                .ok_or(CryptoError::KesError(KesError::KeyExpired))?;
+            let sk1 = D::gen_key_kes_from_seed_bytes(&r1_seed)?;
+            D::forget_signing_key_kes(signing_key.sk);
+            D::update_kes_to(context, sk1, 0, to - t_half)?
+        };
+
+        if updated_sk.is_some() {
+            scope.record_updates(to - from);
+        }
+        Ok(updated_sk.map(|sk| SumSigningKey {
+            sk,
+            r1_seed: signing_key.r1_seed,
+            vk0: signing_key.vk0,
+            vk1: signing_key.vk1,
+            vk: signing_key.vk,
+            _phantom: PhantomData,
+        }))
     }
 
     fn gen_key_kes_from_seed_bytes(seed: &[u8]) -> Result<Self::SigningKey> {
+        let scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
         if seed.len() != Self::SEED_SIZE {
             return Err(CryptoError::KesError(KesError::InvalidSeedLength {
                 expected: Self::SEED_SIZE,
                 actual: seed.len(),
             }));
         }
+        check_seed_expansion::<D, H>()?;
 
         // Expand seed into two seeds
         let (r0_bytes, r1_bytes) = H::expand_seed(seed);
+        let (r0_bytes, r1_bytes) = (Zeroizing::new(r0_bytes), Zeroizing::new(r1_bytes));
 
-        // Generate keys for both subtrees
-        let sk0 = D::gen_key_kes_from_seed_bytes(&r0_bytes)?;
-        let vk0 = D::derive_verification_key(&sk0)?;
+        // Keep the left key; the right subtree only contributes vk1 for now
+        let (sk0, vk0, vk1) = gen_subtrees::<D>(&r0_bytes, &r1_bytes)?;
 
-        let sk1 = D::gen_key_kes_from_seed_bytes(&r1_bytes)?;
-        let vk1 = D::derive_verification_key(&sk1)?;
-        D::forget_signing_key_kes(sk1); // Only keep left key initially
+        let vk = H::hash_concat(
+            &D::raw_serialize_verification_key_kes(&vk0),
+            &D::raw_serialize_verification_key_kes(&vk1),
+        );
 
+        scope.record_signing_key();
         Ok(SumSigningKey {
             sk: sk0,
-            r1_seed: Some(r1_bytes),
+            r1_seed: Some(SecretBytes::from_slice(&r1_bytes)),
             vk0,
             vk1,
+            vk,
             _phantom: PhantomData,
         })
     }
@@ -342,13 +762,12 @@ where
             return None;
         }
 
-        let sig_bytes = &bytes[0..D::SIGNATURE_SIZE];
-        let vk0_offset = D::SIGNATURE_SIZE;
-        let vk1_offset = vk0_offset + D::VERIFICATION_KEY_SIZE;
+        let (sig_bytes, rest) = bytes.split_at_checked(D::SIGNATURE_SIZE)?;
+        let (vk0_bytes, vk1_bytes) = rest.split_at_checked(D::VERIFICATION_KEY_SIZE)?;
 
         let sigma = D::raw_deserialize_signature_kes(sig_bytes)?;
-        let vk0 = D::raw_deserialize_verification_key_kes(&bytes[vk0_offset..vk1_offset])?;
-        let vk1 = D::raw_deserialize_verification_key_kes(&bytes[vk1_offset..])?;
+        let vk0 = D::raw_deserialize_verification_key_kes(vk0_bytes)?;
+        let vk1 = D::raw_deserialize_verification_key_kes(vk1_bytes)?;
 
         Some(SumSignature {
             sigma,
@@ -358,9 +777,209 @@ where
         })
     }
 
+    fn raw_serialize_verification_key_kes_into(
+        key: &Self::VerificationKey,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        output_prefix(out, Self::VERIFICATION_KEY_SIZE)?.copy_from_slice(key);
+        Ok(Self::VERIFICATION_KEY_SIZE)
+    }
+
+    fn raw_serialize_signature_kes_into(
+        signature: &Self::Signature,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        let out = output_prefix(out, Self::SIGNATURE_SIZE)?;
+        let (sigma_out, rest) = out.split_at_mut(D::SIGNATURE_SIZE);
+        let (vk0_out, vk1_out) = rest.split_at_mut(D::VERIFICATION_KEY_SIZE);
+        D::raw_serialize_signature_kes_into(&signature.sigma, sigma_out)?;
+        D::raw_serialize_verification_key_kes_into(&signature.vk0, vk0_out)?;
+        D::raw_serialize_verification_key_kes_into(&signature.vk1, vk1_out)?;
+        Ok(Self::SIGNATURE_SIZE)
+    }
+
+    /// Wipes the right-subtree seed kept for future evolution, then forgets
+    /// the active subtree key (recursively, down to the Ed25519 leaf)
     fn forget_signing_key_kes(signing_key: Self::SigningKey) {
-        D::forget_signing_key_kes(signing_key.sk);
-        // r1_seed will be dropped automatically
+        let SumSigningKey {
+            sk, mut r1_seed, ..
+        } = signing_key;
+        r1_seed.zeroize();
+        D::forget_signing_key_kes(sk);
+    }
+}
+
+/// Signing keys serialize as `sk || r1_seed || vk0 || vk1`.
+///
+/// Once the right subtree is active the seed has been consumed and is written
+/// as zeros, as in cardano-base; an all-zero seed deserializes back to the
+/// consumed state.
+impl<D, H> UnsoundKesAlgorithm for SumKes<D, H>
+where
+    D: UnsoundKesAlgorithm,
+    D::VerificationKey: Clone,
+    H: KesHashAlgorithm,
+{
+    fn raw_serialize_signing_key_kes(
+        signing_key: &Self::SigningKey,
+    ) -> zeroize::Zeroizing<Vec<u8>> {
+        let mut result = zeroize::Zeroizing::new(Vec::with_capacity(Self::SIGNING_KEY_SIZE));
+        result.extend_from_slice(&D::raw_serialize_signing_key_kes(&signing_key.sk));
+        match &signing_key.r1_seed {
+            Some(seed) => result.extend_from_slice(seed),
+            None => {
+                let len = result.len();
+                result.resize(len + D::SEED_SIZE, 0);
+            }
+        }
+        result.extend_from_slice(&D::raw_serialize_verification_key_kes(&signing_key.vk0));
+        result.extend_from_slice(&D::raw_serialize_verification_key_kes(&signing_key.vk1));
+        result
+    }
+
+    fn raw_serialize_signing_key_kes_into(
+        signing_key: &Self::SigningKey,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        let out = output_prefix(out, Self::SIGNING_KEY_SIZE)?;
+        let (sk_out, rest) = out.split_at_mut(D::SIGNING_KEY_SIZE);
+        let (seed_out, rest) = rest.split_at_mut(D::SEED_SIZE);
+        let (vk0_out, vk1_out) = rest.split_at_mut(D::VERIFICATION_KEY_SIZE);
+        D::raw_serialize_signing_key_kes_into(&signing_key.sk, sk_out)?;
+        match &signing_key.r1_seed {
+            Some(seed) => seed_out.copy_from_slice(seed),
+            None => seed_out.fill(0),
+        }
+        D::raw_serialize_verification_key_kes_into(&signing_key.vk0, vk0_out)?;
+        D::raw_serialize_verification_key_kes_into(&signing_key.vk1, vk1_out)?;
+        Ok(Self::SIGNING_KEY_SIZE)
+    }
+
+    fn raw_deserialize_signing_key_kes(bytes: &[u8]) -> Option<Self::SigningKey> {
+        if bytes.len() != Self::SIGNING_KEY_SIZE {
+            return None;
+        }
+
+        let (sk_bytes, rest) = bytes.split_at_checked(D::SIGNING_KEY_SIZE)?;
+        let (seed, rest) = rest.split_at_checked(D::SEED_SIZE)?;
+        let (vk0_bytes, vk1_bytes) = rest.split_at_checked(D::VERIFICATION_KEY_SIZE)?;
+
+        let sk = D::raw_deserialize_signing_key_kes(sk_bytes)?;
+        let r1_seed = if seed.iter().all(|&b| b == 0) {
+            None
+        } else {
+            Some(SecretBytes::from_slice(seed))
+        };
+        let vk0 = D::raw_deserialize_verification_key_kes(vk0_bytes)?;
+        let vk1 = D::raw_deserialize_verification_key_kes(vk1_bytes)?;
+
+        let vk = H::hash_concat(
+            &D::raw_serialize_verification_key_kes(&vk0),
+            &D::raw_serialize_verification_key_kes(&vk1),
+        );
+
+        Some(SumSigningKey {
+            sk,
+            r1_seed,
+            vk0,
+            vk1,
+            vk,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<D, H> Ed25519LeafKes for SumKes<D, H>
+where
+    D: Ed25519LeafKes,
+    D::VerificationKey: Clone,
+    H: KesHashAlgorithm,
+{
+    fn verify_kes_with_ed25519(
+        verifier: &Ed25519LeafVerifier<'_>,
+        context: &Self::Context,
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<()> {
+        let vk0_bytes = D::raw_serialize_verification_key_kes(&signature.vk0);
+        let vk1_bytes = D::raw_serialize_verification_key_kes(&signature.vk1);
+        if !hash_concat_matches::<H>(&vk0_bytes, &vk1_bytes, verification_key) {
+            return Err(CryptoError::KesError(KesError::BadMerklePath));
+        }
+
+        let (vk_active, child_period) = Self::active_subtree(signature, period);
+        D::verify_kes_with_ed25519(
+            verifier,
+            context,
+            vk_active,
+            child_period,
+            message,
+            &signature.sigma,
+        )
+    }
+
+    fn leaf_signature(signature: &Self::Signature) -> [u8; 64] {
+        D::leaf_signature(&signature.sigma)
+    }
+
+    fn merkle_path(
+        _verification_key: &Self::VerificationKey,
+        period: Period,
+        signature: &Self::Signature,
+    ) -> Result<MerklePath> {
+        let active_is_left = period < D::total_periods();
+        let (vk_active, child_period) = Self::active_subtree(signature, period);
+        let sibling = if active_is_left {
+            &signature.vk1
+        } else {
+            &signature.vk0
+        };
+
+        let mut path = D::merkle_path(vk_active, child_period, &signature.sigma)?;
+        path.steps.push(MerkleStep {
+            sibling: D::raw_serialize_verification_key_kes(sibling),
+            active_is_left,
+        });
+        Ok(path)
+    }
+}
+
+impl<D, H> SessionKes for SumKes<D, H>
+where
+    D: SessionKes,
+    D::VerificationKey: Clone,
+    H: KesHashAlgorithm,
+{
+    /// Child branch, then both subtree verification keys
+    type Branch = (D::Branch, D::VerificationKey, D::VerificationKey);
+
+    fn session_branch(
+        context: &Self::Context,
+        period: Period,
+        signing_key: &Self::SigningKey,
+    ) -> Result<Self::Branch> {
+        let t_half = D::total_periods();
+        let child_period = if period < t_half {
+            period
+        } else {
+            period - t_half
+        };
+        Ok((
+            D::session_branch(context, child_period, &signing_key.sk)?,
+            signing_key.vk0.clone(),
+            signing_key.vk1.clone(),
+        ))
+    }
+
+    fn sign_with_branch((child, vk0, vk1): &Self::Branch, message: &[u8]) -> Self::Signature {
+        SumSignature {
+            sigma: D::sign_with_branch(child, message),
+            vk0: vk0.clone(),
+            vk1: vk1.clone(),
+            _phantom: PhantomData,
+        }
     }
 }
 
@@ -391,10 +1010,10 @@ pub type Sum0Kes = SingleKes<Ed25519>;
 ///
 /// assert_eq!(Sum1Kes::total_periods(), 2);
 /// ```
-pub type Sum1Kes = SumKes<Sum0Kes, Blake2b256>;
+pub type Sum1Kes = GenericSumKes<1>;
 
 /// 2^2 = 4 periods
-pub type Sum2Kes = SumKes<Sum1Kes, Blake2b256>;
+pub type Sum2Kes = GenericSumKes<2>;
 
 /// 2^3 = 8 periods
 ///
@@ -405,7 +1024,7 @@ pub type Sum2Kes = SumKes<Sum1Kes, Blake2b256>;
 ///
 /// assert_eq!(Sum3Kes::total_periods(), 8);
 /// ```
-pub type Sum3Kes = SumKes<Sum2Kes, Blake2b256>;
+pub type Sum3Kes = GenericSumKes<3>;
 
 /// 2^4 = 16 periods
 ///
@@ -416,7 +1035,7 @@ pub type Sum3Kes = SumKes<Sum2Kes, Blake2b256>;
 ///
 /// assert_eq!(Sum4Kes::total_periods(), 16);
 /// ```
-pub type Sum4Kes = SumKes<Sum3Kes, Blake2b256>;
+pub type Sum4Kes = GenericSumKes<4>;
 
 /// 2^5 = 32 periods
 ///
@@ -427,10 +1046,10 @@ pub type Sum4Kes = SumKes<Sum3Kes, Blake2b256>;
 ///
 /// assert_eq!(Sum5Kes::total_periods(), 32);
 /// ```
-pub type Sum5Kes = SumKes<Sum4Kes, Blake2b256>;
+pub type Sum5Kes = GenericSumKes<5>;
 
 /// 2^6 = 64 periods
-pub type Sum6Kes = SumKes<Sum5Kes, Blake2b256>;
+pub type Sum6Kes = GenericSumKes<6>;
 
 /// 2^7 = 128 periods (standard Cardano KES)
 ///
@@ -442,12 +1061,122 @@ pub type Sum6Kes = SumKes<Sum5Kes, Blake2b256>;
 /// // Cardano uses Sum7Kes with 128 periods
 /// assert_eq!(Sum7Kes::total_periods(), 128);
 /// ```
-pub type Sum7Kes = SumKes<Sum6Kes, Blake2b256>;
+pub type Sum7Kes = GenericSumKes<7>;
+
+/// 2^1 = 2 periods over the hash `H`
+pub type Sum1KesWith<H> = GenericSumKes<1, H>;
+
+/// 2^2 = 4 periods over the hash `H`
+pub type Sum2KesWith<H> = GenericSumKes<2, H>;
+
+/// 2^3 = 8 periods over the hash `H`
+pub type Sum3KesWith<H> = GenericSumKes<3, H>;
+
+/// 2^4 = 16 periods over the hash `H`
+pub type Sum4KesWith<H> = GenericSumKes<4, H>;
+
+/// 2^5 = 32 periods over the hash `H`
+pub type Sum5KesWith<H> = GenericSumKes<5, H>;
+
+/// 2^6 = 64 periods over the hash `H`
+pub type Sum6KesWith<H> = GenericSumKes<6, H>;
+
+/// 2^7 = 128 periods over the hash `H`
+pub type Sum7KesWith<H> = GenericSumKes<7, H>;
+
+/// Type-level tree depth used to select a [`GenericSumKes`] composition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Depth<const DEPTH: usize>;
+
+/// Maps a [`Depth`] to the `SumKes` tree of that many levels over the hash `H`
+///
+/// Implemented for depths 0 through [`MAX_GENERIC_SUM_DEPTH`]. Stable Rust
+/// cannot recurse on `DEPTH - 1` directly, so each depth names the one below.
+pub trait SumDepth<H: KesHashAlgorithm> {
+    /// The composed scheme: `SingleKes<Ed25519>` at depth 0, otherwise
+    /// `SumKes` over the scheme one level down
+    type Kes: UnsoundKesAlgorithm<VerificationKey: Clone>;
+}
+
+/// Deepest tree [`GenericSumKes`] can build
+pub const MAX_GENERIC_SUM_DEPTH: usize = 16;
+
+/// `SumKes` tree of `DEPTH` levels over Ed25519 and the hash `H`
+///
+/// `GenericSumKes<7>` is the same type as [`Sum7Kes`]; the named aliases are
+/// shorthands for it. Periods, seed and signature sizes follow from the
+/// depth: `TOTAL_PERIODS` is `1 << DEPTH` and each level adds both subtree
+/// verification keys to the signature.
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::kes::{GenericSumKes, KesAlgorithm, Sum7Kes};
+///
+/// assert_eq!(GenericSumKes::<8>::total_periods(), 256);
+/// assert_eq!(GenericSumKes::<8>::SIGNATURE_SIZE, 64 + 8 * 64);
+/// assert_eq!(GenericSumKes::<7>::ALGORITHM_NAME, Sum7Kes::ALGORITHM_NAME);
+/// ```
+pub type GenericSumKes<const DEPTH: usize, H = Blake2b256> = <Depth<DEPTH> as SumDepth<H>>::Kes;
+
+impl<H: KesHashAlgorithm> SumDepth<H> for Depth<0> {
+    type Kes = SingleKes<Ed25519>;
+}
+
+macro_rules! impl_sum_depth {
+    ($($depth:literal => $child:literal),* $(,)?) => {
+        $(
+            impl<H: KesHashAlgorithm> SumDepth<H> for Depth<$depth> {
+                type Kes = SumKes<GenericSumKes<$child, H>, H>;
+            }
+        )*
+    };
+}
+
+impl_sum_depth! {
+    1 => 0, 2 => 1, 3 => 2, 4 => 3, 5 => 4, 6 => 5, 7 => 6, 8 => 7,
+    9 => 8, 10 => 9, 11 => 10, 12 => 11, 13 => 12, 14 => 13, 15 => 14, 16 => 15,
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn rejects_small_order_leaf_forgery() {
+        use crate::kes::{Blake2b256, Sum1Kes};
+
+        // Leaf key and R are the identity and S = 0, which satisfies the
+        // cofactorless Ed25519 equation for any message
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        let mut forged = identity.to_vec();
+        forged.extend_from_slice(&[0u8; 32]);
+        forged.extend_from_slice(&identity);
+        forged.extend_from_slice(&identity);
+        let signature = Sum1Kes::raw_deserialize_signature_kes(&forged).unwrap();
+        let verification_key = Blake2b256::hash_concat(&identity, &identity);
+
+        assert_eq!(
+            Sum1Kes::verify_kes(&(), &verification_key, 0, b"any block", &signature),
+            Err(CryptoError::KesError(KesError::BadEd25519Signature))
+        );
+    }
+
+    #[test]
+    fn leaf_signature_verifies_under_leaf_key() {
+        use crate::common::traits::DsignAlgorithm;
+
+        let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[14u8; 32]).unwrap();
+        let sk = Sum2Kes::update_kes_to(&(), sk, 0, 2).unwrap().unwrap();
+        let sig = Sum2Kes::sign_kes(&(), 2, b"leaf", &sk).unwrap();
+
+        let leaf_vk = Ed25519::derive_verification_key(&sk.sk.sk).unwrap();
+        let leaf_sig = Ed25519::deserialize_signature(&Sum2Kes::leaf_signature(&sig)).unwrap();
+        Ed25519::verify(b"leaf", &leaf_sig, &leaf_vk).unwrap();
+        assert!(Ed25519::verify(b"other", &leaf_sig, &leaf_vk).is_err());
+    }
+
     #[test]
     fn sum1_total_periods() {
         assert_eq!(Sum1Kes::total_periods(), 2);
@@ -468,6 +1197,77 @@ mod tests {
         assert_eq!(Sum4Kes::total_periods(), 16);
     }
 
+    #[test]
+    fn generic_depth_8_evolves_and_verifies() {
+        type Sum8Kes = GenericSumKes<8>;
+
+        assert_eq!(Sum8Kes::TREE_DEPTH, 8);
+        assert_eq!(Sum8Kes::total_periods(), 1 << 8);
+        assert_eq!(Sum8Kes::SEED_SIZE, Sum0Kes::SEED_SIZE);
+        assert_eq!(
+            Sum8Kes::SIGNATURE_SIZE,
+            super::super::expected_signature_size(8)
+        );
+
+        let seed = vec![8u8; Sum8Kes::SEED_SIZE];
+        let mut sk = Sum8Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = Sum8Kes::derive_verification_key(&sk).unwrap();
+
+        for period in 0..5 {
+            let msg = format!("period-{}", period);
+            let sig = Sum8Kes::sign_kes(&(), period, msg.as_bytes(), &sk).unwrap();
+            assert_eq!(
+                Sum8Kes::raw_serialize_signature_kes(&sig).len(),
+                Sum8Kes::SIGNATURE_SIZE
+            );
+            Sum8Kes::verify_kes(&(), &vk, period, msg.as_bytes(), &sig).unwrap();
+            sk = Sum8Kes::update_kes(&(), sk, period).unwrap().unwrap();
+        }
+    }
+
+    #[test]
+    fn generic_aliases_match_named_aliases() {
+        let seed = vec![9u8; Sum3Kes::SEED_SIZE];
+        let sk = GenericSumKes::<3>::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = GenericSumKes::<3>::derive_verification_key(&sk).unwrap();
+        let named_sk = Sum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        assert_eq!(vk, Sum3Kes::derive_verification_key(&named_sk).unwrap());
+    }
+
+    #[test]
+    fn seed_expansion_vectors_diverge() {
+        use crate::kes::hash::LegacySeedExpansion;
+        use crate::kes::{CompactSum2Kes, CompactSum2KesWith};
+
+        type LegacySum2Kes = Sum2KesWith<LegacySeedExpansion>;
+        type LegacyCompactSum2Kes = CompactSum2KesWith<LegacySeedExpansion>;
+
+        // Both keys agree with a Python model of the tree built on hashlib
+        // and pyca/cryptography
+        let seed: Vec<u8> = (0u8..32).collect();
+        let current = "a8d2054c49ecae30db583e634ac86e0e1a4b4e5fc317eb627599e8a8cef25821";
+        let legacy = "3b3ab48c675ca422a5c21398ba42d1153c2fc1f91769d5903595e954b9484b79";
+
+        fn vk_hex<K: KesAlgorithm>(seed: &[u8]) -> alloc::string::String {
+            let sk = K::gen_key_kes_from_seed_bytes(seed).unwrap();
+            let vk = K::derive_verification_key(&sk).unwrap();
+            crate::common::hex::encode(&K::raw_serialize_verification_key_kes(&vk))
+        }
+        assert_eq!(vk_hex::<Sum2Kes>(&seed), current);
+        assert_eq!(vk_hex::<CompactSum2Kes>(&seed), current);
+        assert_eq!(vk_hex::<LegacySum2Kes>(&seed), legacy);
+        assert_eq!(vk_hex::<LegacyCompactSum2Kes>(&seed), legacy);
+
+        // The right subtree is regenerated with the same expansion on update
+        let sk = LegacySum2Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = LegacySum2Kes::derive_verification_key(&sk).unwrap();
+        let sk = LegacySum2Kes::update_kes_to(&(), sk, 0, 3)
+            .unwrap()
+            .unwrap();
+        let sig = LegacySum2Kes::sign_kes(&(), 3, b"legacy", &sk).unwrap();
+        LegacySum2Kes::verify_kes(&(), &vk, 3, b"legacy", &sig).unwrap();
+    }
+
     #[test]
     fn sum1_key_generation_and_derivation() {
         let seed = vec![1u8; Sum1Kes::SEED_SIZE];
@@ -554,4 +1354,335 @@ mod tests {
         // Verify with restored signature
         Sum1Kes::verify_kes(&(), &vk, 0, msg, &sig_restored).unwrap();
     }
+
+    #[test]
+    fn sum2_signing_key_serialization_roundtrip() {
+        let seed = vec![7u8; Sum2Kes::SEED_SIZE];
+        let mut sk = Sum2Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = Sum2Kes::derive_verification_key(&sk).unwrap();
+
+        for period in 0..4 {
+            let bytes = Sum2Kes::raw_serialize_signing_key_kes(&sk);
+            assert_eq!(bytes.len(), Sum2Kes::SIGNING_KEY_SIZE);
+
+            let restored = Sum2Kes::raw_deserialize_signing_key_kes(&bytes).unwrap();
+            assert_eq!(*Sum2Kes::raw_serialize_signing_key_kes(&restored), *bytes);
+
+            let sig = Sum2Kes::sign_kes(&(), period, b"restored", &restored).unwrap();
+            Sum2Kes::verify_kes(&(), &vk, period, b"restored", &sig).unwrap();
+
+            if period < 3 {
+                sk = Sum2Kes::update_kes(&(), sk, period).unwrap().unwrap();
+            }
+        }
+
+        assert!(Sum2Kes::raw_deserialize_signing_key_kes(&[0u8; 10]).is_none());
+    }
+
+    #[test]
+    fn sum3_current_period_tracks_updates() {
+        let seed = vec![8u8; Sum3Kes::SEED_SIZE];
+        let mut sk = Sum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+
+        for period in 0..Sum3Kes::total_periods() {
+            assert_eq!(Sum3Kes::current_period_kes(&sk), period);
+            assert_eq!(Sum3Kes::remaining_periods_kes(&sk), 8 - period);
+            match Sum3Kes::update_kes(&(), sk, period).unwrap() {
+                Some(next) => sk = next,
+                None => {
+                    assert_eq!(period, Sum3Kes::total_periods() - 1);
+                    return;
+                }
+            }
+        }
+        panic!("Sum3Kes key should expire after its last period");
+    }
+
+    #[test]
+    fn sum3_sign_batch_at_period_verifies() {
+        let seed = vec![10u8; Sum3Kes::SEED_SIZE];
+        let mut sk = Sum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = Sum3Kes::derive_verification_key(&sk).unwrap();
+        for period in 0..5 {
+            sk = Sum3Kes::update_kes(&(), sk, period).unwrap().unwrap();
+        }
+
+        let messages: [&[u8]; 3] = [b"block", b"vote", b""];
+        let sigs = Sum3Kes::sign_batch_at_period(&(), 5, &messages, &sk).unwrap();
+        assert_eq!(sigs.len(), messages.len());
+
+        for (message, sig) in messages.iter().zip(&sigs) {
+            Sum3Kes::verify_kes(&(), &vk, 5, message, sig).unwrap();
+            let single = Sum3Kes::sign_kes(&(), 5, message, &sk).unwrap();
+            assert_eq!(
+                Sum3Kes::raw_serialize_signature_kes(sig),
+                Sum3Kes::raw_serialize_signature_kes(&single)
+            );
+        }
+    }
+
+    #[test]
+    fn sum4_update_kes_to_matches_stepwise_updates() {
+        let seed = vec![17u8; Sum4Kes::SEED_SIZE];
+        for (from, to) in [(0, 0), (0, 5), (0, 8), (3, 12), (9, 15)] {
+            let mut stepped = Sum4Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+            for period in 0..from {
+                stepped = Sum4Kes::update_kes(&(), stepped, period).unwrap().unwrap();
+            }
+            let start = Sum4Kes::raw_deserialize_signing_key_kes(
+                &Sum4Kes::raw_serialize_signing_key_kes(&stepped),
+            )
+            .unwrap();
+            for period in from..to {
+                stepped = Sum4Kes::update_kes(&(), stepped, period).unwrap().unwrap();
+            }
+
+            let jumped = Sum4Kes::update_kes_to(&(), start, from, to)
+                .unwrap()
+                .unwrap();
+            assert_eq!(Sum4Kes::current_period_kes(&jumped), to);
+            assert_eq!(
+                *Sum4Kes::raw_serialize_signing_key_kes(&jumped),
+                *Sum4Kes::raw_serialize_signing_key_kes(&stepped)
+            );
+        }
+
+        let sk = Sum4Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        assert!(Sum4Kes::update_kes_to(&(), sk, 0, 16).unwrap().is_none());
+
+        let sk = Sum4Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let sk = Sum4Kes::update_kes_to(&(), sk, 0, 4).unwrap().unwrap();
+        assert_eq!(
+            Sum4Kes::update_kes_to(&(), sk, 4, 2).err(),
+            Some(CryptoError::KesError(KesError::UpdateFailed))
+        );
+
+        // A `from` the key is not at is refused before anything is evolved
+        let sk = Sum4Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        assert_eq!(
+            Sum4Kes::update_kes_to(&(), sk, 9, 12).err(),
+            Some(CryptoError::KesError(KesError::UpdateFromWrongPeriod {
+                from: 9,
+                current: 0
+            }))
+        );
+    }
+
+    #[test]
+    fn sum3_verification_key_from_seed_matches_signing_key() {
+        let seed = vec![19u8; Sum3Kes::SEED_SIZE];
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        assert_eq!(
+            Sum3Kes::verification_key_from_seed(&seed).unwrap(),
+            Sum3Kes::derive_verification_key(&sk).unwrap()
+        );
+        assert!(Sum3Kes::verification_key_from_seed(&seed[1..]).is_err());
+    }
+
+    #[test]
+    fn sum3_cached_verification_key_survives_evolution() {
+        let seed = vec![23u8; Sum3Kes::SEED_SIZE];
+        let mut sk = Sum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        for period in 0..5 {
+            sk = Sum3Kes::update_kes(&(), sk, period).unwrap().unwrap();
+        }
+
+        let recomputed = Blake2b256::hash_concat(
+            &Sum2Kes::raw_serialize_verification_key_kes(&sk.vk0),
+            &Sum2Kes::raw_serialize_verification_key_kes(&sk.vk1),
+        );
+        assert_eq!(Sum3Kes::derive_verification_key(&sk).unwrap(), recomputed);
+        assert_eq!(
+            Sum3Kes::derive_verification_key(&sk).unwrap(),
+            Sum3Kes::verification_key_from_seed(&seed).unwrap()
+        );
+
+        // The cache is rebuilt on deserialization, not stored in the key bytes
+        let bytes = Sum3Kes::raw_serialize_signing_key_kes(&sk);
+        assert_eq!(bytes.len(), Sum3Kes::SIGNING_KEY_SIZE);
+        let restored = Sum3Kes::raw_deserialize_signing_key_kes(&bytes).unwrap();
+        assert_eq!(restored.vk, sk.vk);
+    }
+
+    #[test]
+    fn sum3_verify_with_ed25519_callback_matches_verify_kes() {
+        use crate::common::traits::DsignAlgorithm;
+
+        let verifier = |vk: &[u8; 32], message: &[u8], signature: &[u8; 64]| match (
+            Ed25519::deserialize_verification_key(vk),
+            Ed25519::deserialize_signature(signature),
+        ) {
+            (Ok(vk), Ok(signature)) => Ed25519::verify(message, &signature, &vk).is_ok(),
+            _ => false,
+        };
+
+        let seed = vec![24u8; Sum3Kes::SEED_SIZE];
+        let mut sk = Sum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = Sum3Kes::derive_verification_key(&sk).unwrap();
+        for period in 0..5 {
+            sk = Sum3Kes::update_kes(&(), sk, period).unwrap().unwrap();
+        }
+        let sig = Sum3Kes::sign_kes(&(), 5, b"hsm", &sk).unwrap();
+
+        for (period, message) in [(5, &b"hsm"[..]), (5, &b"other"[..]), (4, &b"hsm"[..])] {
+            assert_eq!(
+                Sum3Kes::verify_kes_with_ed25519(&verifier, &(), &vk, period, message, &sig)
+                    .is_ok(),
+                Sum3Kes::verify_kes(&(), &vk, period, message, &sig).is_ok()
+            );
+        }
+        Sum3Kes::verify_kes_with_ed25519(&verifier, &(), &vk, 5, b"hsm", &sig).unwrap();
+
+        // A rejecting verifier makes the whole check fail
+        assert_eq!(
+            Sum3Kes::verify_kes_with_ed25519(&|_, _, _| false, &(), &vk, 5, b"hsm", &sig),
+            Err(CryptoError::KesError(KesError::BadEd25519Signature))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn sum3_signature_serde_roundtrip() {
+        let seed = vec![27u8; Sum3Kes::SEED_SIZE];
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = Sum3Kes::derive_verification_key(&sk).unwrap();
+        let sig = Sum3Kes::sign_kes(&(), 0, b"serde", &sk).unwrap();
+        let raw = Sum3Kes::raw_serialize_signature_kes(&sig);
+
+        // Human-readable formats use hex
+        let json = serde_json::to_string(&sig).unwrap();
+        assert_eq!(json, format!("\"{}\"", hex::encode(&raw)));
+        let from_json: <Sum3Kes as KesAlgorithm>::Signature = serde_json::from_str(&json).unwrap();
+        Sum3Kes::verify_kes(&(), &vk, 0, b"serde", &from_json).unwrap();
+
+        // Binary formats use a plain byte string
+        let encoded = bincode::serialize(&sig).unwrap();
+        assert_eq!(&encoded[8..], raw.as_slice());
+        let from_bincode: <Sum3Kes as KesAlgorithm>::Signature =
+            bincode::deserialize(&encoded).unwrap();
+        assert_eq!(Sum3Kes::raw_serialize_signature_kes(&from_bincode), raw);
+
+        // Wrong lengths are rejected
+        let short = format!("\"{}\"", hex::encode(&raw[1..]));
+        assert!(serde_json::from_str::<<Sum3Kes as KesAlgorithm>::Signature>(&short).is_err());
+        let short = bincode::serialize(&raw[1..]).unwrap();
+        assert!(bincode::deserialize::<<Sum3Kes as KesAlgorithm>::Signature>(&short).is_err());
+
+        // The leaf Ed25519 types serialize the same way
+        let leaf_vk = &sig.sigma.sigma.vk0;
+        let json = serde_json::to_string(leaf_vk).unwrap();
+        assert_eq!(json.len(), 2 + 64);
+        assert_eq!(
+            &serde_json::from_str::<crate::dsign::ed25519::Ed25519VerificationKey>(&json).unwrap(),
+            leaf_vk
+        );
+    }
+
+    #[test]
+    fn sum3_self_test_detects_corrupted_key() {
+        let seed = vec![30u8; Sum3Kes::SEED_SIZE];
+        let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let sk = Sum3Kes::update_kes_to(&(), sk, 0, 3).unwrap().unwrap();
+        Sum3Kes::self_test_kes(&(), &sk, 3, b"health check").unwrap();
+
+        // Flip a bit of the active leaf's verification key in the innermost
+        // Sum1 level (layout: leaf sk || r1 seed || vk0 || vk1), to another
+        // valid point so the key still deserializes
+        let mut bytes = Sum3Kes::raw_serialize_signing_key_kes(&sk);
+        bytes[64 + 32 + 32] ^= 0x10;
+        let corrupted = Sum3Kes::raw_deserialize_signing_key_kes(&bytes).unwrap();
+        assert!(Sum3Kes::self_test_kes(&(), &corrupted, 3, b"health check").is_err());
+    }
+
+    #[test]
+    fn sum3_signing_key_zeroize_and_drop_wipe_secrets() {
+        let seed = vec![33u8; Sum3Kes::SEED_SIZE];
+
+        let mut sk = Sum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        sk.zeroize();
+        assert!(sk.r1_seed.is_none());
+        assert!(sk.sk.r1_seed.is_none());
+        assert!(sk.sk.sk.sk.compound_bytes().iter().all(|&b| b == 0));
+
+        // The leaf key is stored inline, so it can be inspected after the
+        // destructor runs on storage that is never freed. Under `secure-mem`
+        // it lives in a buffer that is freed on drop instead.
+        #[cfg(not(feature = "secure-mem"))]
+        {
+            let mut sk =
+                core::mem::ManuallyDrop::new(Sum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap());
+            let leaf = sk.sk.sk.sk.compound_bytes().as_ptr();
+            assert!(sk.sk.sk.sk.compound_bytes().iter().any(|&b| b != 0));
+            // SAFETY: the ManuallyDrop storage outlives the destructor call
+            unsafe {
+                core::mem::ManuallyDrop::drop(&mut sk);
+                assert!(core::slice::from_raw_parts(leaf, 64)
+                    .iter()
+                    .all(|&b| b == 0));
+            }
+        }
+    }
+
+    const _: () = assert!(Sum6Kes::TREE_DEPTH == 6);
+    const _: () = assert!(Sum6Kes::TOTAL_PERIODS == 64);
+    const _: () = assert!(Sum7Kes::TOTAL_PERIODS == 1 << Sum7Kes::TREE_DEPTH);
+
+    #[test]
+    fn sum_total_periods_sizes_arrays() {
+        let signatures: [Option<SumSignature<Sum2Kes, Blake2b256>>;
+            Sum3Kes::TOTAL_PERIODS as usize] = Default::default();
+        assert_eq!(signatures.len() as Period, Sum3Kes::total_periods());
+    }
+
+    typed_tests! {
+        fn sum_total_periods_follow_tree_depth<K>(depth: u32) for {
+            sum1: Sum1Kes => 1,
+            sum2: Sum2Kes => 2,
+            sum3: Sum3Kes => 3,
+            sum4: Sum4Kes => 4,
+            sum5: Sum5Kes => 5,
+            sum6: Sum6Kes => 6,
+            sum7: Sum7Kes => 7,
+        } {
+            assert_eq!(K::TREE_DEPTH, depth);
+            assert_eq!(K::total_periods(), 1 << K::TREE_DEPTH);
+            assert_eq!(K::TOTAL_PERIODS, K::total_periods());
+        }
+    }
+
+    #[test]
+    fn sum_total_periods_match_tree_depth() {
+        assert_eq!(Sum0Kes::TREE_DEPTH, 0);
+        assert_eq!(Sum0Kes::total_periods(), 1);
+        assert_eq!(Sum0Kes::TOTAL_PERIODS, 1);
+
+        assert_eq!(Sum6Kes::TOTAL_PERIODS, Sum6Kes::total_periods());
+        for level in 0..=Sum6Kes::TREE_DEPTH {
+            assert_eq!(
+                Sum6Kes::periods_per_level(level),
+                Some(Sum6Kes::total_periods() >> level)
+            );
+        }
+        assert_eq!(Sum6Kes::periods_per_level(7), None);
+    }
+
+    #[test]
+    fn sum3_with_sha256() {
+        use crate::kes::hash::Sha256;
+
+        let seed = [12u8; 32];
+        let mut sk = Sum3KesWith::<Sha256>::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = Sum3KesWith::<Sha256>::derive_verification_key(&sk).unwrap();
+        assert_eq!(vk.len(), Sum3KesWith::<Sha256>::VERIFICATION_KEY_SIZE);
+        assert_ne!(vk, Sum3Kes::verification_key_from_seed(&seed).unwrap());
+
+        for period in 0..Sum3KesWith::<Sha256>::total_periods() {
+            let sig = Sum3KesWith::<Sha256>::sign_kes(&(), period, b"sha2", &sk).unwrap();
+            Sum3KesWith::<Sha256>::verify_kes(&(), &vk, period, b"sha2", &sig).unwrap();
+            match Sum3KesWith::<Sha256>::update_kes(&(), sk, period).unwrap() {
+                Some(next) => sk = next,
+                None => break,
+            }
+        }
+    }
 }
@@ -2,16 +2,33 @@
 //!
 //! This module implements CompactSumKES, an optimized version of SumKES that stores
 //! fewer verification keys by embedding them in signatures.
+//!
+//! `CompactSum1Kes`..`CompactSum7Kes` hash with Blake2b-256; the
+//! `CompactSumNKesWith<H>` aliases take any hash, e.g. `CompactSum6KesWith<Sha256>`.
 
 use core::marker::PhantomData;
 
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
 use crate::common::error::{CryptoError, Result};
-use crate::kes::hash::KesHashAlgorithm;
+use crate::common::secure_mem::SecretBytes;
+use crate::kes::hash::{hash_concat_matches, order_children, KesHashAlgorithm};
+use crate::kes::merkle::{MerklePath, MerkleStep};
+use crate::kes::period::{check_period, split_period};
+use crate::kes::session::SessionKes;
 use crate::kes::single::compact::{CompactKesComponents, CompactSingleKes, OptimizedKesSignature};
-use crate::kes::{KesAlgorithm, Period};
+use crate::kes::{
+    check_seed_expansion, checked_tree_depth, gen_subtrees, output_prefix, signature_from_bytes,
+    subtree_verification_keys, Ed25519LeafKes, Ed25519LeafVerifier, KesAlgorithm, KesError, Period,
+    SeedExpansion, UnsoundKesAlgorithm,
+};
+use crate::metrics::{self, Operation};
+
+#[cfg(feature = "constant-time")]
+use crate::kes::pad_subtree_generation;
 
 /// CompactSumKES is an optimized version of SumKES that stores fewer verification keys.
 ///
@@ -81,7 +98,6 @@ where
 /// // CompactSum2Kes supports 2^2 = 4 periods
 /// assert_eq!(CompactSum2Kes::total_periods(), 4);
 /// ```
-#[derive(Debug)]
 pub struct CompactSumSigningKey<D, H>
 where
     D: KesAlgorithm,
@@ -89,12 +105,56 @@ where
     H: KesHashAlgorithm,
 {
     pub(crate) sk: D::SigningKey,
-    pub(crate) r1_seed: Option<Vec<u8>>,
+    pub(crate) r1_seed: Option<SecretBytes>,
     pub(crate) vk0: D::VerificationKey,
     pub(crate) vk1: D::VerificationKey,
+    pub(crate) vk: Vec<u8>,
     _phantom: PhantomData<H>,
 }
 
+/// Shows only the algorithm and current period, never key or seed bytes
+impl<D, H> core::fmt::Debug for CompactSumSigningKey<D, H>
+where
+    D: KesAlgorithm + CompactKesComponents,
+    D::VerificationKey: Clone,
+    D::Signature: OptimizedKesSignature + Clone,
+    H: KesHashAlgorithm,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CompactSumSigningKey")
+            .field("algorithm", &CompactSumKes::<D, H>::ALGORITHM_NAME)
+            .field("period", &CompactSumKes::<D, H>::current_period_kes(self))
+            .finish_non_exhaustive()
+    }
+}
+
+/// Wipes the active subtree key and the right-subtree seed
+///
+/// The verification keys are public and left untouched.
+impl<D, H> Zeroize for CompactSumSigningKey<D, H>
+where
+    D: KesAlgorithm,
+    D::Signature: OptimizedKesSignature,
+    D::SigningKey: Zeroize,
+    H: KesHashAlgorithm,
+{
+    fn zeroize(&mut self) {
+        self.sk.zeroize();
+        self.r1_seed.zeroize();
+    }
+}
+
+/// The right-subtree seed is held in a self-wiping buffer, so dropping the key wipes
+/// every secret byte as long as the subtree key does the same.
+impl<D, H> ZeroizeOnDrop for CompactSumSigningKey<D, H>
+where
+    D: KesAlgorithm,
+    D::Signature: OptimizedKesSignature,
+    D::SigningKey: ZeroizeOnDrop,
+    H: KesHashAlgorithm,
+{
+}
+
 /// Signature for CompactSumKES - only stores the "other" verification key.
 #[derive(Debug)]
 pub struct CompactSumSignature<D, H>
@@ -110,6 +170,75 @@ where
     _phantom: PhantomData<H>,
 }
 
+/// Lowercase hex of the raw serialization
+impl<D, H> core::fmt::Display for CompactSumSignature<D, H>
+where
+    D: KesAlgorithm + CompactKesComponents,
+    D::VerificationKey: Clone,
+    D::Signature: OptimizedKesSignature + Clone,
+    H: KesHashAlgorithm,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::common::hex::fmt(&CompactSumKes::<D, H>::raw_serialize_signature_kes(self), f)
+    }
+}
+
+impl<D, H> CompactSumSignature<D, H>
+where
+    D: KesAlgorithm + CompactKesComponents,
+    D::VerificationKey: Clone,
+    D::Signature: OptimizedKesSignature + Clone,
+    H: KesHashAlgorithm,
+{
+    /// Raw serialization, the same bytes as
+    /// [`raw_serialize_signature_kes`](KesAlgorithm::raw_serialize_signature_kes)
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        CompactSumKes::<D, H>::raw_serialize_signature_kes(self)
+    }
+
+    /// Parse a signature from its raw serialization
+    ///
+    /// # Errors
+    ///
+    /// - [`KesError::WrongLength`] unless `bytes` is exactly
+    ///   [`SIGNATURE_SIZE`](KesAlgorithm::SIGNATURE_SIZE) long
+    /// - [`KesError::InvalidEncoding`] if the bytes do not decode
+    pub fn from_bytes(bytes: &[u8]) -> core::result::Result<Self, KesError> {
+        signature_from_bytes::<CompactSumKes<D, H>>(bytes)
+    }
+
+    /// Period this signature was produced at, recovered from its Merkle path
+    ///
+    /// The path only fixes the period relative to a root, so the signer's
+    /// `verification_key` is needed. Like
+    /// [`CompactSumKes::signed_period`], this does not check the leaf
+    /// signature over the message.
+    ///
+    /// # Errors
+    ///
+    /// [`KesError::BadMerklePath`] if no period's path hashes up to
+    /// `verification_key`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{CompactSum2Kes, KesAlgorithm};
+    ///
+    /// let sk = CompactSum2Kes::gen_key_kes_from_seed_bytes(&[1u8; 32]).unwrap();
+    /// let vk = CompactSum2Kes::derive_verification_key(&sk).unwrap();
+    /// let sk = CompactSum2Kes::update_kes_to(&(), sk, 0, 3).unwrap().unwrap();
+    /// let sig = CompactSum2Kes::sign_kes(&(), 3, b"msg", &sk).unwrap();
+    /// assert_eq!(sig.period(&vk), Ok(3));
+    /// ```
+    pub fn period(
+        &self,
+        verification_key: &<CompactSumKes<D, H> as KesAlgorithm>::VerificationKey,
+    ) -> core::result::Result<Period, KesError> {
+        CompactSumKes::<D, H>::signed_period(verification_key, self).ok_or(KesError::BadMerklePath)
+    }
+}
+
 impl<D, H> Clone for CompactSumSignature<D, H>
 where
     D: KesAlgorithm,
@@ -140,6 +269,118 @@ where
     }
 }
 
+impl<D, H> CompactSumKes<D, H>
+where
+    D: KesAlgorithm + CompactKesComponents,
+    D::VerificationKey: Clone,
+    D::Signature: OptimizedKesSignature + Clone,
+    H: KesHashAlgorithm,
+{
+    /// Number of periods covered by each subtree `level` steps below the root
+    ///
+    /// Level 0 is the whole tree and level `TREE_DEPTH` a single period;
+    /// deeper levels return `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{CompactSum6Kes, KesAlgorithm};
+    ///
+    /// assert_eq!(CompactSum6Kes::TREE_DEPTH, 6);
+    /// assert_eq!(CompactSum6Kes::periods_per_level(0), Some(64));
+    /// assert_eq!(CompactSum6Kes::periods_per_level(1), Some(32));
+    /// assert_eq!(CompactSum6Kes::periods_per_level(6), Some(1));
+    /// assert_eq!(CompactSum6Kes::periods_per_level(7), None);
+    /// ```
+    pub const fn periods_per_level(level: u32) -> Option<Period> {
+        match <Self as KesAlgorithm>::TREE_DEPTH.checked_sub(level) {
+            Some(height) => Some(1 << height),
+            None => None,
+        }
+    }
+
+    /// Root verification key of the [`SumKes`](super::SumKes) tree built from
+    /// the same seed
+    ///
+    /// The inverse of [`SumKes::to_compact_vk`](super::SumKes::to_compact_vk);
+    /// the roots are identical bytes, so this is a copy.
+    #[must_use]
+    pub fn to_sum_vk(verification_key: &[u8]) -> Vec<u8> {
+        verification_key.to_vec()
+    }
+
+    /// Find the period whose Merkle path in `signature` leads to `verification_key`
+    ///
+    /// The "other" verification keys carried by a compact signature only hash
+    /// up to the root when combined in the order dictated by the signing
+    /// period. Which side the signer took at a level only shows once every
+    /// level below it is fixed, so no walk down from the root can prune a
+    /// branch; instead every candidate root is built bottom-up, each
+    /// intermediate hash computed once, for about `2 * total_periods` hashes.
+    /// This checks the path only, not the leaf signature over the message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use cardano_crypto::kes::{CompactSum2Kes, KesAlgorithm};
+    ///
+    /// let mut sk = CompactSum2Kes::gen_key_kes_from_seed_bytes(&[1u8; 32]).unwrap();
+    /// let vk = CompactSum2Kes::derive_verification_key(&sk).unwrap();
+    /// sk = CompactSum2Kes::update_kes(&(), sk, 0).unwrap().unwrap();
+    /// let sig = CompactSum2Kes::sign_kes(&(), 1, b"msg", &sk).unwrap();
+    /// assert_eq!(CompactSum2Kes::signed_period(&vk, &sig), Some(1));
+    /// ```
+    pub fn signed_period(
+        verification_key: &<Self as KesAlgorithm>::VerificationKey,
+        signature: &<Self as KesAlgorithm>::Signature,
+    ) -> Option<Period> {
+        Self::candidate_verification_keys(signature)
+            .into_iter()
+            .filter(|(_, candidate)| candidate == verification_key)
+            .map(|(period, _)| period)
+            .min()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<D, H> serde::Serialize for CompactSumSignature<D, H>
+where
+    D: KesAlgorithm + CompactKesComponents,
+    D::VerificationKey: Clone,
+    D::Signature: OptimizedKesSignature + Clone,
+    H: KesHashAlgorithm,
+{
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        crate::common::bytes_serde::serialize(
+            &CompactSumKes::<D, H>::raw_serialize_signature_kes(self),
+            serializer,
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, D, H> serde::Deserialize<'de> for CompactSumSignature<D, H>
+where
+    D: KesAlgorithm + CompactKesComponents,
+    D::VerificationKey: Clone,
+    D::Signature: OptimizedKesSignature + Clone,
+    H: KesHashAlgorithm,
+{
+    fn deserialize<De: serde::Deserializer<'de>>(
+        deserializer: De,
+    ) -> core::result::Result<Self, De::Error> {
+        let bytes = crate::common::bytes_serde::deserialize(
+            deserializer,
+            CompactSumKes::<D, H>::SIGNATURE_SIZE,
+        )?;
+        CompactSumKes::<D, H>::raw_deserialize_signature_kes(&bytes)
+            .ok_or_else(|| serde::de::Error::custom("invalid CompactSumKES signature"))
+    }
+}
+
 impl<D, H> KesAlgorithm for CompactSumKes<D, H>
 where
     D: KesAlgorithm + CompactKesComponents,
@@ -152,23 +393,39 @@ where
     type Signature = CompactSumSignature<D, H>;
     type Context = D::Context;
 
-    const ALGORITHM_NAME: &'static str = D::ALGORITHM_NAME;
+    const ALGORITHM_NAME: &'static str = super::compact_sum_algorithm_name(D::TREE_DEPTH + 1);
+    const TREE_HASH: Option<(&'static str, SeedExpansion)> =
+        Some((H::ALGORITHM_NAME, H::SEED_EXPANSION));
     const SEED_SIZE: usize = D::SEED_SIZE;
     const VERIFICATION_KEY_SIZE: usize = H::OUTPUT_SIZE;
     const SIGNING_KEY_SIZE: usize =
         D::SIGNING_KEY_SIZE + D::SEED_SIZE + 2 * D::VERIFICATION_KEY_SIZE;
     // Compact signature: constituent signature + only ONE verification key
     const SIGNATURE_SIZE: usize = D::SIGNATURE_SIZE + D::VERIFICATION_KEY_SIZE;
+    const TREE_DEPTH: u32 = checked_tree_depth(D::TREE_DEPTH + 1);
 
     fn total_periods() -> Period {
-        2 * D::total_periods()
+        Self::TOTAL_PERIODS
+    }
+
+    fn verification_key_from_seed(seed: &[u8]) -> Result<Self::VerificationKey> {
+        if seed.len() != Self::SEED_SIZE {
+            return Err(CryptoError::KesError(KesError::InvalidSeedLength {
+                expected: Self::SEED_SIZE,
+                actual: seed.len(),
+            }));
+        }
+        check_seed_expansion::<D, H>()?;
+
+        let (r0_bytes, r1_bytes) = H::expand_seed(seed);
+        let (r0_bytes, r1_bytes) = (Zeroizing::new(r0_bytes), Zeroizing::new(r1_bytes));
+        let (vk0, vk1) = subtree_verification_keys::<D>(&r0_bytes, &r1_bytes)?;
+        Ok(H::hash_concat(&vk0, &vk1))
     }
 
     fn derive_verification_key(signing_key: &Self::SigningKey) -> Result<Self::VerificationKey> {
-        // vk = H(vk0 || vk1)
-        let vk0_bytes = D::raw_serialize_verification_key_kes(&signing_key.vk0);
-        let vk1_bytes = D::raw_serialize_verification_key_kes(&signing_key.vk1);
-        Ok(H::hash_concat(&vk0_bytes, &vk1_bytes))
+        // vk = H(vk0 || vk1), computed once at key generation
+        Ok(signing_key.vk.clone())
     }
 
     fn sign_kes(
@@ -177,6 +434,8 @@ where
         message: &[u8],
         signing_key: &Self::SigningKey,
     ) -> Result<Self::Signature> {
+        let scope = metrics::operation_scope(Self::ALGORITHM_NAME, Operation::Sign);
+        check_period::<Self>(period).map_err(CryptoError::KesError)?;
         let t_half = D::total_periods();
 
         let (sigma, vk_other) = if period < t_half {
@@ -189,6 +448,7 @@ where
             (sig, signing_key.vk0.clone())
         };
 
+        scope.record_signatures(1);
         Ok(CompactSumSignature {
             sigma,
             vk_other,
@@ -196,6 +456,52 @@ where
         })
     }
 
+    fn raw_serialize_verification_key_kes_into(
+        key: &Self::VerificationKey,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        output_prefix(out, Self::VERIFICATION_KEY_SIZE)?.copy_from_slice(key);
+        Ok(Self::VERIFICATION_KEY_SIZE)
+    }
+
+    fn raw_serialize_signature_kes_into(
+        signature: &Self::Signature,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        let out = output_prefix(out, Self::SIGNATURE_SIZE)?;
+        let (sigma_out, vk_out) = out.split_at_mut(D::SIGNATURE_SIZE);
+        D::raw_serialize_signature_kes_into(&signature.sigma, sigma_out)?;
+        D::raw_serialize_verification_key_kes_into(&signature.vk_other, vk_out)?;
+        Ok(Self::SIGNATURE_SIZE)
+    }
+
+    fn sign_batch_at_period(
+        context: &Self::Context,
+        period: Period,
+        messages: &[&[u8]],
+        signing_key: &Self::SigningKey,
+    ) -> Result<Vec<Self::Signature>> {
+        let scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
+        check_period::<Self>(period).map_err(CryptoError::KesError)?;
+        let t_half = D::total_periods();
+        let (child_period, vk_other) = if period < t_half {
+            (period, &signing_key.vk1)
+        } else {
+            (period - t_half, &signing_key.vk0)
+        };
+
+        let sigmas = D::sign_batch_at_period(context, child_period, messages, &signing_key.sk)?;
+        scope.record_signatures(sigmas.len() as u64);
+        Ok(sigmas
+            .into_iter()
+            .map(|sigma| CompactSumSignature {
+                sigma,
+                vk_other: vk_other.clone(),
+                _phantom: PhantomData,
+            })
+            .collect())
+    }
+
     fn verify_kes(
         context: &Self::Context,
         verification_key: &Self::VerificationKey,
@@ -203,13 +509,12 @@ where
         message: &[u8],
         signature: &Self::Signature,
     ) -> Result<()> {
-        let t_half = D::total_periods();
-        let active_is_left = period < t_half;
-        let child_period = if active_is_left {
-            period
-        } else {
-            period - t_half
-        };
+        let _scope = metrics::operation_scope(Self::ALGORITHM_NAME, Operation::Verify);
+        if let Err(err) = check_period::<Self>(period) {
+            metrics::record_verification(false);
+            return Err(CryptoError::KesError(err));
+        }
+        let (active_is_right, child_period) = split_period(period, D::total_periods());
 
         // Derive the active subtree verification key (owned value so we can reuse it)
         let vk_active = <D as CompactKesComponents>::active_verification_key_from_signature(
@@ -217,104 +522,252 @@ where
             child_period,
         );
 
-        let vk_other = signature.vk_other.clone();
-
-        // Reconstruct both vk0 and vk1
-        let (vk0, vk1) = if active_is_left {
-            // Active is left, other is right
-            (vk_active.clone(), vk_other.clone())
-        } else {
-            // Active is right, other is left
-            (vk_other.clone(), vk_active.clone())
-        };
-
-        // Verify that H(vk0 || vk1) matches the provided verification key
-        let vk0_bytes = D::raw_serialize_verification_key_kes(&vk0);
-        let vk1_bytes = D::raw_serialize_verification_key_kes(&vk1);
-        let computed_vk = H::hash_concat(&vk0_bytes, &vk1_bytes);
-
-        if &computed_vk != verification_key {
-            return Err(CryptoError::VerificationFailed);
+        // Put vk0 and vk1 in tree order without branching on the period, and
+        // verify that H(vk0 || vk1) matches the provided verification key
+        let (vk0_bytes, vk1_bytes) = order_children(
+            D::raw_serialize_verification_key_kes(&vk_active),
+            D::raw_serialize_verification_key_kes(&signature.vk_other),
+            active_is_right,
+        );
+        if !hash_concat_matches::<H>(&vk0_bytes, &vk1_bytes, verification_key) {
+            metrics::record_verification(false);
+            // A wrong period also lands here, as it reorders the Merkle path;
+            // `diagnose_verify_failure` tells the two apart on request
+            return Err(CryptoError::KesError(KesError::BadMerklePath));
         }
 
         // Verify the signature against the active verification key
         D::verify_kes(context, &vk_active, child_period, message, &signature.sigma)
     }
 
+    fn signed_period_kes(
+        verification_key: &Self::VerificationKey,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Option<Period> {
+        // `signed_period` checks the path, leaving only the leaf signature
+        Self::signed_period(verification_key, signature)
+            .filter(|_| Self::leaf_signature_valid(message, signature))
+    }
+
+    fn current_period_kes(signing_key: &Self::SigningKey) -> Period {
+        // The right subtree seed is consumed exactly when evolving into the right half
+        let child_period = D::current_period_kes(&signing_key.sk);
+        if signing_key.r1_seed.is_some() {
+            child_period
+        } else {
+            D::total_periods() + child_period
+        }
+    }
+
     fn update_kes(
         context: &Self::Context,
         mut signing_key: Self::SigningKey,
         period: Period,
     ) -> Result<Option<Self::SigningKey>> {
+        let scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
         let t_half = D::total_periods();
 
         if period + 1 >= 2 * t_half {
+            // Key has expired
+            D::forget_signing_key_kes(signing_key.sk);
+            #[cfg(feature = "constant-time")]
+            Self::pad_update_kes();
             return Ok(None);
         }
 
-        if period + 1 == t_half {
+        let updated = if period + 1 == t_half {
             // Transition from left to right subtree
             let r1_seed = signing_key
                 .r1_seed
                 .take()
-                .ok_or(CryptoError::KesError(crate::kes::KesError::KeyExpired))?;
+                .ok_or(CryptoError::KesError(KesError::KeyExpired))?;
 
             let sk1 = D::gen_key_kes_from_seed_bytes(&r1_seed)?;
+            #[cfg(feature = "constant-time")]
+            D::pad_update_kes();
 
-            Ok(Some(CompactSumSigningKey {
+            // Forget left subtree key
+            D::forget_signing_key_kes(signing_key.sk);
+
+            Some(CompactSumSigningKey {
                 sk: sk1,
                 r1_seed: None,
                 vk0: signing_key.vk0,
                 vk1: signing_key.vk1,
+                vk: signing_key.vk,
                 _phantom: PhantomData,
-            }))
+            })
         } else if period + 1 < t_half {
+            #[cfg(feature = "constant-time")]
+            pad_subtree_generation::<D>();
             // Still in left subtree
-            let updated_sk = D::update_kes(context, signing_key.sk, period)?;
-            match updated_sk {
-                Some(sk) => Ok(Some(CompactSumSigningKey {
-                    sk,
-                    r1_seed: signing_key.r1_seed,
-                    vk0: signing_key.vk0,
-                    vk1: signing_key.vk1,
-                    _phantom: PhantomData,
-                })),
-                None => Ok(None),
-            }
+            D::update_kes(context, signing_key.sk, period)?.map(|sk| CompactSumSigningKey {
+                sk,
+                r1_seed: signing_key.r1_seed,
+                vk0: signing_key.vk0,
+                vk1: signing_key.vk1,
+                vk: signing_key.vk,
+                _phantom: PhantomData,
+            })
         } else {
             // In right subtree
+            #[cfg(feature = "constant-time")]
+            pad_subtree_generation::<D>();
             let adjusted_period = period - t_half;
-            let updated_sk = D::update_kes(context, signing_key.sk, adjusted_period)?;
-            match updated_sk {
-                Some(sk) => Ok(Some(CompactSumSigningKey {
+            D::update_kes(context, signing_key.sk, adjusted_period)?.map(|sk| {
+                CompactSumSigningKey {
                     sk,
                     r1_seed: None,
                     vk0: signing_key.vk0,
                     vk1: signing_key.vk1,
+                    vk: signing_key.vk,
                     _phantom: PhantomData,
-                })),
-                None => Ok(None),
-            }
+                }
+            })
+        };
+        if updated.is_some() {
+            scope.record_updates(1);
+        }
+        Ok(updated)
+    }
+
+    fn update_kes_in_place(
+        context: &Self::Context,
+        signing_key: &mut Self::SigningKey,
+        period: Period,
+    ) -> Result<bool> {
+        let scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
+        let t_half = D::total_periods();
+
+        if period + 1 >= 2 * t_half {
+            // Expired: the right subtree wipes its own key in place
+            #[cfg(feature = "constant-time")]
+            pad_subtree_generation::<D>();
+            signing_key.r1_seed = None;
+            D::update_kes_in_place(context, &mut signing_key.sk, period - t_half)?;
+            return Ok(false);
         }
+
+        let evolved = if period + 1 == t_half {
+            // Transition from left to right subtree; the seed is only
+            // consumed once the right subtree key exists
+            let r1_seed = signing_key
+                .r1_seed
+                .as_ref()
+                .ok_or(CryptoError::KesError(KesError::KeyExpired))?;
+            let sk1 = D::gen_key_kes_from_seed_bytes(r1_seed)?;
+            #[cfg(feature = "constant-time")]
+            D::pad_update_kes();
+            D::forget_signing_key_kes(core::mem::replace(&mut signing_key.sk, sk1));
+            signing_key.r1_seed = None;
+            true
+        } else if period + 1 < t_half {
+            #[cfg(feature = "constant-time")]
+            pad_subtree_generation::<D>();
+            D::update_kes_in_place(context, &mut signing_key.sk, period)?
+        } else {
+            #[cfg(feature = "constant-time")]
+            pad_subtree_generation::<D>();
+            D::update_kes_in_place(context, &mut signing_key.sk, period - t_half)?
+        };
+        if evolved {
+            scope.record_updates(1);
+        }
+        Ok(evolved)
+    }
+
+    #[cfg(feature = "constant-time")]
+    fn pad_update_kes() {
+        // Each level either regenerates its right subtree or pads for it,
+        // and the level below does the same
+        pad_subtree_generation::<D>();
+        D::pad_update_kes();
+    }
+
+    fn update_kes_to(
+        context: &Self::Context,
+        mut signing_key: Self::SigningKey,
+        from: Period,
+        to: Period,
+    ) -> Result<Option<Self::SigningKey>> {
+        let scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
+        let current = Self::current_period_kes(&signing_key);
+        if from != current {
+            return Err(CryptoError::KesError(KesError::UpdateFromWrongPeriod {
+                from,
+                current,
+            }));
+        }
+        let t_half = D::total_periods();
+
+        if to >= 2 * t_half {
+            Self::forget_signing_key_kes(signing_key);
+            return Ok(None);
+        }
+        if to < from {
+            return Err(CryptoError::KesError(KesError::UpdateFailed));
+        }
+
+        let updated_sk = if to < t_half {
+            // Target still in left subtree
+            D::update_kes_to(context, signing_key.sk, from, to)?
+        } else if from >= t_half {
+            // Already in right subtree
+            D::update_kes_to(context, signing_key.sk, from - t_half, to - t_half)?
+        } else {
+            // Crossing over: skip the remaining left periods entirely
+            let r1_seed = signing_key
+                .r1_seed
+                .take()
+                .ok_or(CryptoError::KesError(KesError::KeyExpired))?;
+            let sk1 = D::gen_key_kes_from_seed_bytes(&r1_seed)?;
+            D::forget_signing_key_kes(signing_key.sk);
+            D::update_kes_to(context, sk1, 0, to - t_half)?
+        };
+
+        if updated_sk.is_some() {
+            scope.record_updates(to - from);
+        }
+        Ok(updated_sk.map(|sk| CompactSumSigningKey {
+            sk,
+            r1_seed: signing_key.r1_seed,
+            vk0: signing_key.vk0,
+            vk1: signing_key.vk1,
+            vk: signing_key.vk,
+            _phantom: PhantomData,
+        }))
     }
 
     fn gen_key_kes_from_seed_bytes(seed: &[u8]) -> Result<Self::SigningKey> {
+        let scope = metrics::algorithm_scope(Self::ALGORITHM_NAME);
+        if seed.len() != Self::SEED_SIZE {
+            return Err(CryptoError::KesError(KesError::InvalidSeedLength {
+                expected: Self::SEED_SIZE,
+                actual: seed.len(),
+            }));
+        }
+        check_seed_expansion::<D, H>()?;
+
         // Split seed into r0 and r1 using the hash algorithm
         let (r0_bytes, r1_bytes) = H::expand_seed(seed);
+        let (r0_bytes, r1_bytes) = (Zeroizing::new(r0_bytes), Zeroizing::new(r1_bytes));
 
-        // Generate sk_0 from r0
-        let sk0 = D::gen_key_kes_from_seed_bytes(&r0_bytes)?;
-        let vk0 = D::derive_verification_key(&sk0)?;
+        // Generate sk_0 from r0, and from r1 only vk1
+        let (sk0, vk0, vk1) = gen_subtrees::<D>(&r0_bytes, &r1_bytes)?;
 
-        // Generate sk_1 from r1 (only to derive vk1, then forget)
-        let sk1 = D::gen_key_kes_from_seed_bytes(&r1_bytes)?;
-        let vk1 = D::derive_verification_key(&sk1)?;
+        let vk = H::hash_concat(
+            &D::raw_serialize_verification_key_kes(&vk0),
+            &D::raw_serialize_verification_key_kes(&vk1),
+        );
 
+        scope.record_signing_key();
         Ok(CompactSumSigningKey {
             sk: sk0,
-            r1_seed: Some(r1_bytes),
+            r1_seed: Some(SecretBytes::from_slice(&r1_bytes)),
             vk0,
             vk1,
+            vk,
             _phantom: PhantomData,
         })
     }
@@ -344,8 +797,7 @@ where
             return None;
         }
 
-        let sig_bytes = &bytes[0..D::SIGNATURE_SIZE];
-        let vk_bytes = &bytes[D::SIGNATURE_SIZE..];
+        let (sig_bytes, vk_bytes) = bytes.split_at_checked(D::SIGNATURE_SIZE)?;
 
         let sigma = D::raw_deserialize_signature_kes(sig_bytes)?;
         let vk_other = D::raw_deserialize_verification_key_kes(vk_bytes)?;
@@ -357,9 +809,197 @@ where
         })
     }
 
+    /// Wipes the right-subtree seed kept for future evolution, then forgets
+    /// the active subtree key (recursively, down to the Ed25519 leaf)
     fn forget_signing_key_kes(signing_key: Self::SigningKey) {
-        D::forget_signing_key_kes(signing_key.sk);
-        // vk0, vk1, r1_seed will be dropped automatically
+        let CompactSumSigningKey {
+            sk, mut r1_seed, ..
+        } = signing_key;
+        r1_seed.zeroize();
+        D::forget_signing_key_kes(sk);
+    }
+}
+
+/// Signing keys serialize as `sk || r1_seed || vk0 || vk1`.
+///
+/// Once the right subtree is active the seed has been consumed and is written
+/// as zeros, as in cardano-base; an all-zero seed deserializes back to the
+/// consumed state.
+impl<D, H> UnsoundKesAlgorithm for CompactSumKes<D, H>
+where
+    D: UnsoundKesAlgorithm + CompactKesComponents,
+    D::VerificationKey: Clone,
+    D::Signature: OptimizedKesSignature + Clone,
+    H: KesHashAlgorithm,
+{
+    fn raw_serialize_signing_key_kes(
+        signing_key: &Self::SigningKey,
+    ) -> zeroize::Zeroizing<Vec<u8>> {
+        let mut result = zeroize::Zeroizing::new(Vec::with_capacity(Self::SIGNING_KEY_SIZE));
+        result.extend_from_slice(&D::raw_serialize_signing_key_kes(&signing_key.sk));
+        match &signing_key.r1_seed {
+            Some(seed) => result.extend_from_slice(seed),
+            None => {
+                let len = result.len();
+                result.resize(len + D::SEED_SIZE, 0);
+            }
+        }
+        result.extend_from_slice(&D::raw_serialize_verification_key_kes(&signing_key.vk0));
+        result.extend_from_slice(&D::raw_serialize_verification_key_kes(&signing_key.vk1));
+        result
+    }
+
+    fn raw_serialize_signing_key_kes_into(
+        signing_key: &Self::SigningKey,
+        out: &mut [u8],
+    ) -> core::result::Result<usize, KesError> {
+        let out = output_prefix(out, Self::SIGNING_KEY_SIZE)?;
+        let (sk_out, rest) = out.split_at_mut(D::SIGNING_KEY_SIZE);
+        let (seed_out, rest) = rest.split_at_mut(D::SEED_SIZE);
+        let (vk0_out, vk1_out) = rest.split_at_mut(D::VERIFICATION_KEY_SIZE);
+        D::raw_serialize_signing_key_kes_into(&signing_key.sk, sk_out)?;
+        match &signing_key.r1_seed {
+            Some(seed) => seed_out.copy_from_slice(seed),
+            None => seed_out.fill(0),
+        }
+        D::raw_serialize_verification_key_kes_into(&signing_key.vk0, vk0_out)?;
+        D::raw_serialize_verification_key_kes_into(&signing_key.vk1, vk1_out)?;
+        Ok(Self::SIGNING_KEY_SIZE)
+    }
+
+    fn raw_deserialize_signing_key_kes(bytes: &[u8]) -> Option<Self::SigningKey> {
+        if bytes.len() != Self::SIGNING_KEY_SIZE {
+            return None;
+        }
+
+        let (sk_bytes, rest) = bytes.split_at_checked(D::SIGNING_KEY_SIZE)?;
+        let (seed, rest) = rest.split_at_checked(D::SEED_SIZE)?;
+        let (vk0_bytes, vk1_bytes) = rest.split_at_checked(D::VERIFICATION_KEY_SIZE)?;
+
+        let sk = D::raw_deserialize_signing_key_kes(sk_bytes)?;
+        let r1_seed = if seed.iter().all(|&b| b == 0) {
+            None
+        } else {
+            Some(SecretBytes::from_slice(seed))
+        };
+        let vk0 = D::raw_deserialize_verification_key_kes(vk0_bytes)?;
+        let vk1 = D::raw_deserialize_verification_key_kes(vk1_bytes)?;
+
+        let vk = H::hash_concat(
+            &D::raw_serialize_verification_key_kes(&vk0),
+            &D::raw_serialize_verification_key_kes(&vk1),
+        );
+
+        Some(CompactSumSigningKey {
+            sk,
+            r1_seed,
+            vk0,
+            vk1,
+            vk,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<D, H> Ed25519LeafKes for CompactSumKes<D, H>
+where
+    D: Ed25519LeafKes + CompactKesComponents,
+    D::VerificationKey: Clone,
+    D::Signature: OptimizedKesSignature + Clone,
+    H: KesHashAlgorithm,
+{
+    fn verify_kes_with_ed25519(
+        verifier: &Ed25519LeafVerifier<'_>,
+        context: &Self::Context,
+        verification_key: &Self::VerificationKey,
+        period: Period,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<()> {
+        let (active_is_right, child_period) = split_period(period, D::total_periods());
+
+        let vk_active = <D as CompactKesComponents>::active_verification_key_from_signature(
+            &signature.sigma,
+            child_period,
+        );
+        let (vk0_bytes, vk1_bytes) = order_children(
+            D::raw_serialize_verification_key_kes(&vk_active),
+            D::raw_serialize_verification_key_kes(&signature.vk_other),
+            active_is_right,
+        );
+        if !hash_concat_matches::<H>(&vk0_bytes, &vk1_bytes, verification_key) {
+            return Err(CryptoError::KesError(KesError::BadMerklePath));
+        }
+
+        D::verify_kes_with_ed25519(
+            verifier,
+            context,
+            &vk_active,
+            child_period,
+            message,
+            &signature.sigma,
+        )
+    }
+
+    fn leaf_signature(signature: &Self::Signature) -> [u8; 64] {
+        D::leaf_signature(&signature.sigma)
+    }
+
+    fn merkle_path(
+        _verification_key: &Self::VerificationKey,
+        period: Period,
+        signature: &Self::Signature,
+    ) -> Result<MerklePath> {
+        let (active_is_right, child_period) = split_period(period, D::total_periods());
+        let vk_active = <D as CompactKesComponents>::active_verification_key_from_signature(
+            &signature.sigma,
+            child_period,
+        );
+
+        let mut path = D::merkle_path(&vk_active, child_period, &signature.sigma)?;
+        path.steps.push(MerkleStep {
+            sibling: D::raw_serialize_verification_key_kes(&signature.vk_other),
+            active_is_left: !bool::from(active_is_right),
+        });
+        Ok(path)
+    }
+}
+
+impl<D, H> SessionKes for CompactSumKes<D, H>
+where
+    D: SessionKes + CompactKesComponents,
+    D::VerificationKey: Clone,
+    D::Signature: OptimizedKesSignature + Clone,
+    H: KesHashAlgorithm,
+{
+    /// Child branch, then the inactive subtree's verification key
+    type Branch = (D::Branch, D::VerificationKey);
+
+    fn session_branch(
+        context: &Self::Context,
+        period: Period,
+        signing_key: &Self::SigningKey,
+    ) -> Result<Self::Branch> {
+        let t_half = D::total_periods();
+        if period < t_half {
+            Ok((
+                D::session_branch(context, period, &signing_key.sk)?,
+                signing_key.vk1.clone(),
+            ))
+        } else {
+            Ok((
+                D::session_branch(context, period - t_half, &signing_key.sk)?,
+                signing_key.vk0.clone(),
+            ))
+        }
+    }
+
+    fn sign_with_branch((child, vk_other): &Self::Branch, message: &[u8]) -> Self::Signature {
+        CompactSumSignature {
+            sigma: D::sign_with_branch(child, message),
+            vk_other: vk_other.clone(),
+            _phantom: PhantomData,
+        }
     }
 }
 
@@ -433,6 +1073,27 @@ pub type CompactSum6Kes = CompactSumKes<CompactSum5Kes, Blake2b256>;
 /// ```
 pub type CompactSum7Kes = CompactSumKes<CompactSum6Kes, Blake2b256>;
 
+/// 2^1 = 2 periods over the hash `H`
+pub type CompactSum1KesWith<H> = CompactSumKes<CompactSum0Kes, H>;
+
+/// 2^2 = 4 periods over the hash `H`
+pub type CompactSum2KesWith<H> = CompactSumKes<CompactSum1KesWith<H>, H>;
+
+/// 2^3 = 8 periods over the hash `H`
+pub type CompactSum3KesWith<H> = CompactSumKes<CompactSum2KesWith<H>, H>;
+
+/// 2^4 = 16 periods over the hash `H`
+pub type CompactSum4KesWith<H> = CompactSumKes<CompactSum3KesWith<H>, H>;
+
+/// 2^5 = 32 periods over the hash `H`
+pub type CompactSum5KesWith<H> = CompactSumKes<CompactSum4KesWith<H>, H>;
+
+/// 2^6 = 64 periods over the hash `H`
+pub type CompactSum6KesWith<H> = CompactSumKes<CompactSum5KesWith<H>, H>;
+
+/// 2^7 = 128 periods over the hash `H`
+pub type CompactSum7KesWith<H> = CompactSumKes<CompactSum6KesWith<H>, H>;
+
 impl<D, H> CompactKesComponents for CompactSumKes<D, H>
 where
     D: KesAlgorithm + CompactKesComponents,
@@ -444,28 +1105,300 @@ where
         signature: &<Self as KesAlgorithm>::Signature,
         period: Period,
     ) -> <Self as KesAlgorithm>::VerificationKey {
-        let t_half = D::total_periods();
-        let active_is_left = period < t_half;
-        let child_period = if active_is_left {
-            period
-        } else {
-            period - t_half
-        };
+        let (active_is_right, child_period) = split_period(period, D::total_periods());
 
         let vk_active = <D as CompactKesComponents>::active_verification_key_from_signature(
             &signature.sigma,
             child_period,
         );
-        let vk_other = signature.vk_other.clone();
+        let (left_bytes, right_bytes) = order_children(
+            D::raw_serialize_verification_key_kes(&vk_active),
+            D::raw_serialize_verification_key_kes(&signature.vk_other),
+            active_is_right,
+        );
+        H::hash_concat(&left_bytes, &right_bytes)
+    }
 
-        let (vk_left, vk_right) = if active_is_left {
-            (vk_active.clone(), vk_other)
-        } else {
-            (vk_other.clone(), vk_active.clone())
+    fn candidate_verification_keys(
+        signature: &<Self as KesAlgorithm>::Signature,
+    ) -> Vec<(Period, <Self as KesAlgorithm>::VerificationKey)> {
+        // Signatures and keys are public, so no need to hide the order
+        let vk_other = D::raw_serialize_verification_key_kes(&signature.vk_other);
+        let mut candidates = Vec::with_capacity(2 * D::total_periods() as usize);
+        for (period, vk_active) in D::candidate_verification_keys(&signature.sigma) {
+            let vk_active = D::raw_serialize_verification_key_kes(&vk_active);
+            candidates.push((period, H::hash_concat(&vk_active, &vk_other)));
+            candidates.push((
+                period + D::total_periods(),
+                H::hash_concat(&vk_other, &vk_active),
+            ));
+        }
+        candidates
+    }
+
+    fn leaf_signature_valid(message: &[u8], signature: &<Self as KesAlgorithm>::Signature) -> bool {
+        D::leaf_signature_valid(message, &signature.sigma)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compact_sum3_current_period_tracks_updates() {
+        let seed = vec![9u8; CompactSum3Kes::SEED_SIZE];
+        let mut sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+
+        for period in 0..CompactSum3Kes::total_periods() - 1 {
+            assert_eq!(CompactSum3Kes::current_period_kes(&sk), period);
+            sk = CompactSum3Kes::update_kes(&(), sk, period)
+                .unwrap()
+                .unwrap();
+        }
+        assert_eq!(
+            CompactSum3Kes::current_period_kes(&sk),
+            CompactSum3Kes::total_periods() - 1
+        );
+    }
+
+    #[test]
+    fn compact_sum3_sign_batch_at_period_verifies() {
+        let seed = vec![11u8; CompactSum3Kes::SEED_SIZE];
+        let mut sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = CompactSum3Kes::derive_verification_key(&sk).unwrap();
+        for period in 0..6 {
+            sk = CompactSum3Kes::update_kes(&(), sk, period)
+                .unwrap()
+                .unwrap();
+        }
+
+        let messages: [&[u8]; 2] = [b"block", b"vote"];
+        let sigs = CompactSum3Kes::sign_batch_at_period(&(), 6, &messages, &sk).unwrap();
+        for (message, sig) in messages.iter().zip(&sigs) {
+            CompactSum3Kes::verify_kes(&(), &vk, 6, message, sig).unwrap();
+        }
+        assert!(CompactSum3Kes::sign_batch_at_period(&(), 8, &messages, &sk).is_err());
+    }
+
+    #[test]
+    fn compact_sum3_signature_period_matches_signing_period() {
+        let seed = vec![13u8; CompactSum3Kes::SEED_SIZE];
+        let mut sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = CompactSum3Kes::derive_verification_key(&sk).unwrap();
+        let other_vk = CompactSum3Kes::derive_verification_key(
+            &CompactSum3Kes::gen_key_kes_from_seed_bytes(&[14u8; 32]).unwrap(),
+        )
+        .unwrap();
+
+        for period in 0..CompactSum3Kes::total_periods() {
+            let sig = CompactSum3Kes::sign_kes(&(), period, b"block", &sk).unwrap();
+            assert_eq!(sig.period(&vk), Ok(period));
+            assert_eq!(sig.period(&other_vk), Err(KesError::BadMerklePath));
+
+            // Swapping in another key breaks the embedded path
+            let mut tampered = sig.clone();
+            tampered.vk_other = other_vk.clone();
+            assert_eq!(tampered.period(&vk), Err(KesError::BadMerklePath));
+
+            if let Some(next) = CompactSum3Kes::update_kes(&(), sk, period).unwrap() {
+                sk = next;
+            } else {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn compact_sum3_reports_period_mismatch() {
+        let seed = vec![12u8; CompactSum3Kes::SEED_SIZE];
+        let mut sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = CompactSum3Kes::derive_verification_key(&sk).unwrap();
+        for period in 0..7 {
+            sk = CompactSum3Kes::update_kes(&(), sk, period)
+                .unwrap()
+                .unwrap();
+        }
+        let sig = CompactSum3Kes::sign_kes(&(), 7, b"late block", &sk).unwrap();
+
+        // Verification alone sees a bad path; the diagnosis finds period 7
+        let err = CompactSum3Kes::verify_kes(&(), &vk, 5, b"late block", &sig).unwrap_err();
+        assert_eq!(err, CryptoError::KesError(KesError::BadMerklePath));
+        assert_eq!(
+            CompactSum3Kes::diagnose_verify_failure(&vk, 5, b"late block", &sig, err),
+            CryptoError::KesError(KesError::PeriodMismatch {
+                requested: 5,
+                signed: 7,
+            })
+        );
+
+        // A bad message at the wrong period stays a bad path
+        let err = CompactSum3Kes::verify_kes(&(), &vk, 5, b"forged", &sig).unwrap_err();
+        assert_eq!(
+            CompactSum3Kes::diagnose_verify_failure(&vk, 5, b"forged", &sig, err),
+            CryptoError::KesError(KesError::BadMerklePath)
+        );
+        assert_eq!(
+            CompactSum3Kes::verify_kes(&(), &vk, 7, b"forged", &sig),
+            Err(CryptoError::KesError(KesError::BadEd25519Signature))
+        );
+        CompactSum3Kes::verify_kes(&(), &vk, 7, b"late block", &sig).unwrap();
+    }
+
+    #[test]
+    fn compact_sum3_update_kes_to_matches_stepwise_updates() {
+        let seed = vec![18u8; CompactSum3Kes::SEED_SIZE];
+        let mut stepped = CompactSum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        for period in 0..6 {
+            stepped = CompactSum3Kes::update_kes(&(), stepped, period)
+                .unwrap()
+                .unwrap();
+        }
+
+        let fresh = CompactSum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let jumped = CompactSum3Kes::update_kes_to(&(), fresh, 0, 6)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            *CompactSum3Kes::raw_serialize_signing_key_kes(&jumped),
+            *CompactSum3Kes::raw_serialize_signing_key_kes(&stepped)
+        );
+
+        let fresh = CompactSum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        assert!(CompactSum3Kes::update_kes_to(&(), fresh, 0, 8)
+            .unwrap()
+            .is_none());
+
+        assert_eq!(
+            CompactSum3Kes::update_kes_to(&(), jumped, 2, 7).err(),
+            Some(CryptoError::KesError(KesError::UpdateFromWrongPeriod {
+                from: 2,
+                current: 6
+            }))
+        );
+        // The default implementation checks `from` the same way
+        let leaf = CompactSum0Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        assert_eq!(
+            CompactSum0Kes::update_kes_to(&(), leaf, 1, 1).err(),
+            Some(CryptoError::KesError(KesError::UpdateFromWrongPeriod {
+                from: 1,
+                current: 0
+            }))
+        );
+    }
+
+    #[test]
+    fn compact_sum3_verification_key_from_seed_matches_signing_key() {
+        let seed = vec![20u8; CompactSum3Kes::SEED_SIZE];
+        let sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        assert_eq!(
+            CompactSum3Kes::verification_key_from_seed(&seed).unwrap(),
+            CompactSum3Kes::derive_verification_key(&sk).unwrap()
+        );
+    }
+
+    #[test]
+    fn compact_sum3_verify_with_ed25519_callback_matches_verify_kes() {
+        use crate::common::traits::DsignAlgorithm;
+
+        let verifier = |vk: &[u8; 32], message: &[u8], signature: &[u8; 64]| match (
+            Ed25519::deserialize_verification_key(vk),
+            Ed25519::deserialize_signature(signature),
+        ) {
+            (Ok(vk), Ok(signature)) => Ed25519::verify(message, &signature, &vk).is_ok(),
+            _ => false,
         };
 
-        let left_bytes = D::raw_serialize_verification_key_kes(&vk_left);
-        let right_bytes = D::raw_serialize_verification_key_kes(&vk_right);
-        H::hash_concat(&left_bytes, &right_bytes)
+        let seed = vec![25u8; CompactSum3Kes::SEED_SIZE];
+        let sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = CompactSum3Kes::derive_verification_key(&sk).unwrap();
+        let sk = CompactSum3Kes::update_kes_to(&(), sk, 0, 6)
+            .unwrap()
+            .unwrap();
+        let sig = CompactSum3Kes::sign_kes(&(), 6, b"hsm", &sk).unwrap();
+
+        for (period, message) in [(6, &b"hsm"[..]), (6, &b"other"[..]), (2, &b"hsm"[..])] {
+            assert_eq!(
+                CompactSum3Kes::verify_kes_with_ed25519(&verifier, &(), &vk, period, message, &sig)
+                    .is_ok(),
+                CompactSum3Kes::verify_kes(&(), &vk, period, message, &sig).is_ok()
+            );
+        }
+        CompactSum3Kes::verify_kes_with_ed25519(&verifier, &(), &vk, 6, b"hsm", &sig).unwrap();
+    }
+
+    #[test]
+    fn compact_sum3_verification_key_ct_eq() {
+        let vk_a = CompactSum3Kes::verification_key_from_seed(&[31u8; 32]).unwrap();
+        let vk_a_again = CompactSum3Kes::derive_verification_key(
+            &CompactSum3Kes::gen_key_kes_from_seed_bytes(&[31u8; 32]).unwrap(),
+        )
+        .unwrap();
+        let vk_b = CompactSum3Kes::verification_key_from_seed(&[32u8; 32]).unwrap();
+
+        assert!(CompactSum3Kes::ct_eq_verification_key(&vk_a, &vk_a_again));
+        assert!(!CompactSum3Kes::ct_eq_verification_key(&vk_a, &vk_b));
+    }
+
+    const _: () = assert!(CompactSum6Kes::TREE_DEPTH == 6);
+    const _: () = assert!(CompactSum6Kes::TOTAL_PERIODS == 64);
+
+    #[test]
+    fn compact_sum_total_periods_match_tree_depth() {
+        assert_eq!(CompactSum0Kes::TREE_DEPTH, 0);
+        assert_eq!(CompactSum3Kes::TREE_DEPTH, 3);
+        assert_eq!(
+            CompactSum7Kes::total_periods(),
+            1 << CompactSum7Kes::TREE_DEPTH
+        );
+        assert_eq!(
+            CompactSum6Kes::TOTAL_PERIODS,
+            CompactSum6Kes::total_periods()
+        );
+        assert_eq!(CompactSum6Kes::periods_per_level(2), Some(16));
+    }
+
+    #[test]
+    fn compact_sum2_with_sha256() {
+        use crate::kes::hash::Sha256;
+
+        let seed = [12u8; 32];
+        let sk = CompactSum2KesWith::<Sha256>::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let vk = CompactSum2KesWith::<Sha256>::derive_verification_key(&sk).unwrap();
+        assert_ne!(
+            vk,
+            CompactSum2Kes::verification_key_from_seed(&seed).unwrap()
+        );
+
+        let sig = CompactSum2KesWith::<Sha256>::sign_kes(&(), 0, b"sha2", &sk).unwrap();
+        CompactSum2KesWith::<Sha256>::verify_kes(&(), &vk, 0, b"sha2", &sig).unwrap();
+    }
+
+    #[test]
+    fn sum6_verification_key_verifies_compact_sum6_signature() {
+        use crate::kes::{Sum5Kes, Sum6Kes};
+
+        let seed = [13u8; 32];
+        let sum_sk = Sum6Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let sum_vk = Sum6Kes::derive_verification_key(&sum_sk).unwrap();
+        let vk = Sum6Kes::to_compact_vk(&sum_vk);
+        assert_eq!(CompactSum6Kes::to_sum_vk(&vk), sum_vk);
+
+        let sk = CompactSum6Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let sk = CompactSum6Kes::update_kes(&(), sk, 0).unwrap().unwrap();
+        let sig = CompactSum6Kes::sign_kes(&(), 1, b"shared key", &sk).unwrap();
+        CompactSum6Kes::verify_kes(&(), &vk, 1, b"shared key", &sig).unwrap();
+
+        // Same seed at a different depth gives an unrelated root
+        let sum5_sk = Sum5Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+        let sum5_vk = Sum5Kes::derive_verification_key(&sum5_sk).unwrap();
+        assert!(CompactSum6Kes::verify_kes(
+            &(),
+            &Sum5Kes::to_compact_vk(&sum5_vk),
+            1,
+            b"shared key",
+            &sig
+        )
+        .is_err());
     }
 }
@@ -14,16 +14,302 @@
 //! assert_eq!(Sum2Kes::total_periods(), 4);
 //! assert_eq!(Sum6Kes::total_periods(), 64);
 //! ```
+//!
+//! [`expected_signature_size`] and [`expected_compact_signature_size`] give
+//! the signature size at each depth, and [`signature_size_savings`] the
+//! difference between them, for planning storage.
+//! [`detect_kes_depth_from_signature`] reads a CompactSum depth back from a
+//! signature's length.
+//!
+//! # Signature format
+//!
+//! A signature holds no period counter, matching cardano-base. A `SumKes`
+//! signature is the subtree signature followed by both subtree verification
+//! keys, and a `CompactSumKes` signature the subtree signature followed by the
+//! other subtree's key. The period only decides which subtree signed at each
+//! level, reading its bits from the most significant down, so the verifier
+//! must be told the period separately; in a block header it comes from the
+//! operational certificate. Where this crate does write a period as bytes,
+//! in [`opcert::signable_bytes`](crate::kes::opcert::signable_bytes), key
+//! files and sealed keys, it is a big-endian `u64`, as in cardano-node's
+//! `OCertSignable`.
 
 pub mod basic;
 pub mod compact;
 
 pub use basic::{
-    Sum0Kes, Sum1Kes, Sum2Kes, Sum3Kes, Sum4Kes, Sum5Kes, Sum6Kes, Sum7Kes, SumKes, SumSignature,
-    SumSigningKey,
+    Depth, GenericSumKes, Sum0Kes, Sum1Kes, Sum1KesWith, Sum2Kes, Sum2KesWith, Sum3Kes,
+    Sum3KesWith, Sum4Kes, Sum4KesWith, Sum5Kes, Sum5KesWith, Sum6Kes, Sum6KesWith, Sum7Kes,
+    Sum7KesWith, SumDepth, SumKes, SumSignature, SumSigningKey, MAX_GENERIC_SUM_DEPTH,
 };
 
 pub use compact::{
-    CompactSum0Kes, CompactSum1Kes, CompactSum2Kes, CompactSum3Kes, CompactSum4Kes, CompactSum5Kes,
-    CompactSum6Kes, CompactSum7Kes, CompactSumKes, CompactSumSignature, CompactSumSigningKey,
+    CompactSum0Kes, CompactSum1Kes, CompactSum1KesWith, CompactSum2Kes, CompactSum2KesWith,
+    CompactSum3Kes, CompactSum3KesWith, CompactSum4Kes, CompactSum4KesWith, CompactSum5Kes,
+    CompactSum5KesWith, CompactSum6Kes, CompactSum6KesWith, CompactSum7Kes, CompactSum7KesWith,
+    CompactSumKes, CompactSumSignature, CompactSumSigningKey,
 };
+
+use crate::common::traits::DsignAlgorithm;
+use crate::dsign::Ed25519;
+use crate::kes::hash::{Blake2b256, KesHashAlgorithm};
+
+/// Signature size of a `SumKes` tree of `depth` levels over Ed25519 and Blake2b-256
+///
+/// Every level carries both subtree verification keys next to the Ed25519
+/// leaf signature. Ed25519 keys and Blake2b-256 hashes are both 32 bytes, so
+/// a signature grows by 64 bytes per level.
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::kes::sum::expected_signature_size;
+/// use cardano_crypto::kes::{KesAlgorithm, Sum6Kes};
+///
+/// assert_eq!(expected_signature_size(6), 448);
+/// assert_eq!(expected_signature_size(6), Sum6Kes::SIGNATURE_SIZE);
+/// ```
+#[must_use]
+pub const fn expected_signature_size(depth: u32) -> usize {
+    Ed25519::SIGNATURE_SIZE + depth as usize * 2 * Blake2b256::OUTPUT_SIZE
+}
+
+/// Signature size of a `CompactSumKes` tree of `depth` levels over Ed25519 and Blake2b-256
+///
+/// The compact leaf carries its Ed25519 verification key, and every level
+/// adds only the other subtree's 32-byte key.
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::kes::sum::expected_compact_signature_size;
+/// use cardano_crypto::kes::{CompactSum6Kes, KesAlgorithm};
+///
+/// assert_eq!(expected_compact_signature_size(6), 288);
+/// assert_eq!(expected_compact_signature_size(6), CompactSum6Kes::SIGNATURE_SIZE);
+/// ```
+#[must_use]
+pub const fn expected_compact_signature_size(depth: u32) -> usize {
+    Ed25519::SIGNATURE_SIZE
+        + Ed25519::VERIFICATION_KEY_SIZE
+        + depth as usize * Blake2b256::OUTPUT_SIZE
+}
+
+/// Depth of the `CompactSumKes` tree that produces signatures of this length
+///
+/// CompactSum signatures are [`expected_compact_signature_size`] bytes long,
+/// 96 plus 32 per level, so their length alone names the depth. Returns
+/// `None` for any other length, including depths past
+/// [`MAX_TREE_DEPTH`](crate::kes::MAX_TREE_DEPTH). Only the length is read,
+/// so a match says which scheme to try, not that the bytes decode. Sum
+/// signature lengths overlap with these (a Sum1 and a CompactSum1 signature
+/// are both 128 bytes), so the caller must already know the bytes are a
+/// CompactSum signature.
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::kes::sum::detect_kes_depth_from_signature;
+/// use cardano_crypto::kes::KesScheme;
+///
+/// let signature = [0u8; 288];
+/// let depth = detect_kes_depth_from_signature(&signature).unwrap();
+/// assert_eq!(KesScheme::from_depth(depth, true), Some(KesScheme::CompactSum6));
+///
+/// assert_eq!(detect_kes_depth_from_signature(&signature[1..]), None);
+/// ```
+#[must_use]
+pub const fn detect_kes_depth_from_signature(bytes: &[u8]) -> Option<u32> {
+    let base = expected_compact_signature_size(0);
+    let per_level = Blake2b256::OUTPUT_SIZE;
+    if bytes.len() < base || !(bytes.len() - base).is_multiple_of(per_level) {
+        return None;
+    }
+    let depth = (bytes.len() - base) / per_level;
+    if depth > crate::kes::MAX_TREE_DEPTH as usize {
+        return None;
+    }
+    Some(depth as u32)
+}
+
+/// Bytes a `CompactSumKes` signature saves over a `SumKes` one of the same depth
+///
+/// A Sum level carries both subtree keys where a compact level carries one,
+/// saving 32 bytes per level, but the compact leaf spends 32 bytes on its
+/// embedded Ed25519 verification key (see
+/// [`OptimizedKesSignature`](crate::kes::OptimizedKesSignature)). The two
+/// break even at depth 1; at depth 0 the compact signature is the larger one
+/// and this returns 0.
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::kes::sum::signature_size_savings;
+/// use cardano_crypto::kes::{CompactSum6Kes, KesAlgorithm, Sum6Kes};
+///
+/// assert_eq!(signature_size_savings(6), 160);
+/// assert_eq!(
+///     Sum6Kes::SIGNATURE_SIZE - CompactSum6Kes::SIGNATURE_SIZE,
+///     signature_size_savings(6)
+/// );
+/// ```
+#[must_use]
+pub const fn signature_size_savings(depth: u32) -> usize {
+    expected_signature_size(depth).saturating_sub(expected_compact_signature_size(depth))
+}
+
+/// `ALGORITHM_NAME` of a `SumKes` tree of `depth` levels
+///
+/// Depths past the named aliases share the generic name.
+pub(crate) const fn sum_algorithm_name(depth: u32) -> &'static str {
+    match depth {
+        1 => "Sum1Kes",
+        2 => "Sum2Kes",
+        3 => "Sum3Kes",
+        4 => "Sum4Kes",
+        5 => "Sum5Kes",
+        6 => "Sum6Kes",
+        7 => "Sum7Kes",
+        _ => "SumKes",
+    }
+}
+
+/// `ALGORITHM_NAME` of a `CompactSumKes` tree of `depth` levels
+pub(crate) const fn compact_sum_algorithm_name(depth: u32) -> &'static str {
+    match depth {
+        1 => "CompactSum1Kes",
+        2 => "CompactSum2Kes",
+        3 => "CompactSum3Kes",
+        4 => "CompactSum4Kes",
+        5 => "CompactSum5Kes",
+        6 => "CompactSum6Kes",
+        7 => "CompactSum7Kes",
+        _ => "CompactSumKes",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kes::KesAlgorithm;
+
+    const _: () = assert!(Sum7Kes::SIGNATURE_SIZE == expected_signature_size(7));
+    const _: () = assert!(CompactSum7Kes::SIGNATURE_SIZE == expected_compact_signature_size(7));
+
+    typed_tests! {
+        fn signature_sizes_match_formula_at_every_depth<K>(expected: fn(u32) -> usize) for {
+            sum0: Sum0Kes => expected_signature_size,
+            sum1: Sum1Kes => expected_signature_size,
+            sum2: Sum2Kes => expected_signature_size,
+            sum3: Sum3Kes => expected_signature_size,
+            sum4: Sum4Kes => expected_signature_size,
+            sum5: Sum5Kes => expected_signature_size,
+            sum6: Sum6Kes => expected_signature_size,
+            sum7: Sum7Kes => expected_signature_size,
+            compact_sum0: CompactSum0Kes => expected_compact_signature_size,
+            compact_sum1: CompactSum1Kes => expected_compact_signature_size,
+            compact_sum2: CompactSum2Kes => expected_compact_signature_size,
+            compact_sum3: CompactSum3Kes => expected_compact_signature_size,
+            compact_sum4: CompactSum4Kes => expected_compact_signature_size,
+            compact_sum5: CompactSum5Kes => expected_compact_signature_size,
+            compact_sum6: CompactSum6Kes => expected_compact_signature_size,
+            compact_sum7: CompactSum7Kes => expected_compact_signature_size,
+        } {
+            assert_eq!(
+                K::SIGNATURE_SIZE,
+                expected(K::TREE_DEPTH),
+                "depth {}",
+                K::TREE_DEPTH
+            );
+            assert_eq!(K::VERIFICATION_KEY_SIZE, 32);
+        }
+    }
+
+    #[test]
+    fn compact_savings_match_serialized_signatures() {
+        assert_eq!(signature_size_savings(0), 0);
+        assert_eq!(signature_size_savings(1), 0);
+        assert_eq!(signature_size_savings(7), 192);
+
+        let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[2u8; 32]).unwrap();
+        let sum = Sum6Kes::sign_kes(&(), 0, b"size", &sk).unwrap();
+        let sk = CompactSum6Kes::gen_key_kes_from_seed_bytes(&[2u8; 32]).unwrap();
+        let compact = CompactSum6Kes::sign_kes(&(), 0, b"size", &sk).unwrap();
+        assert_eq!(
+            Sum6Kes::raw_serialize_signature_kes(&sum).len()
+                - CompactSum6Kes::raw_serialize_signature_kes(&compact).len(),
+            signature_size_savings(6)
+        );
+    }
+
+    typed_tests! {
+        fn signature_length_detects_compact_depth<K>() for {
+            compact_sum0: CompactSum0Kes,
+            compact_sum1: CompactSum1Kes,
+            compact_sum2: CompactSum2Kes,
+            compact_sum3: CompactSum3Kes,
+            compact_sum4: CompactSum4Kes,
+            compact_sum5: CompactSum5Kes,
+            compact_sum6: CompactSum6Kes,
+            compact_sum7: CompactSum7Kes,
+        } {
+            let sk = K::gen_key_kes_from_seed_bytes(&[3u8; 32]).unwrap();
+            let sig = K::sign_kes(&(), 0, b"depth", &sk).unwrap();
+            let bytes = K::raw_serialize_signature_kes(&sig);
+            assert_eq!(
+                detect_kes_depth_from_signature(&bytes),
+                Some(K::TREE_DEPTH),
+                "{}",
+                K::ALGORITHM_NAME
+            );
+            assert_eq!(detect_kes_depth_from_signature(&bytes[1..]), None);
+            K::forget_signing_key_kes(sk);
+        }
+    }
+
+    #[test]
+    fn signature_length_detection_spans_max_tree_depth() {
+        for len in [0, 64, 95, 97, 127, 129, 300] {
+            assert_eq!(
+                detect_kes_depth_from_signature(&vec![0u8; len]),
+                None,
+                "{len}"
+            );
+        }
+        let deepest = expected_compact_signature_size(crate::kes::MAX_TREE_DEPTH);
+        assert_eq!(
+            detect_kes_depth_from_signature(&vec![0u8; deepest]),
+            Some(crate::kes::MAX_TREE_DEPTH)
+        );
+        assert_eq!(
+            detect_kes_depth_from_signature(&vec![0u8; deepest + 32]),
+            None
+        );
+    }
+
+    #[test]
+    fn algorithm_names_tell_tree_shapes_apart() {
+        assert_eq!(Sum0Kes::ALGORITHM_NAME, "Ed25519");
+        assert_eq!(Sum6Kes::ALGORITHM_NAME, "Sum6Kes");
+        assert_eq!(CompactSum6Kes::ALGORITHM_NAME, "CompactSum6Kes");
+        assert_eq!(Sum7Kes::ALGORITHM_NAME, "Sum7Kes");
+        assert_eq!(SumKes::<Sum7Kes, Blake2b256>::ALGORITHM_NAME, "SumKes");
+    }
+
+    #[test]
+    fn serialized_signatures_have_expected_size() {
+        let sk = Sum4Kes::gen_key_kes_from_seed_bytes(&[1u8; 32]).unwrap();
+        let sig = Sum4Kes::sign_kes(&(), 0, b"size", &sk).unwrap();
+        assert_eq!(
+            Sum4Kes::raw_serialize_signature_kes(&sig).len(),
+            expected_signature_size(4)
+        );
+
+        let sk = CompactSum4Kes::gen_key_kes_from_seed_bytes(&[1u8; 32]).unwrap();
+        let sig = CompactSum4Kes::sign_kes(&(), 0, b"size", &sk).unwrap();
+        assert_eq!(
+            CompactSum4Kes::raw_serialize_signature_kes(&sig).len(),
+            expected_compact_signature_size(4)
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! `typed_tests!`: one test body, expanded once per type
+//!
+//! `fn name<K>() for { case: Type, .. } { body }` becomes a module `name`
+//! with one `#[test]` per case, `K` aliasing that case's type. With a
+//! parameter, `fn name<K>(arg: Ty) for { case: Type => value, .. }`, each
+//! case also binds `arg` to its own value. Attributes on a case, such as
+//! `#[ignore]`, apply to its test.
+//!
+//! The crate's unit tests get the macro through `#[macro_use]` in
+//! `kes/mod.rs`. Integration tests include this same file with
+//! `#[macro_use] #[path = "../src/kes/typed_tests.rs"] mod typed_tests;`.
+
+macro_rules! typed_tests {
+    (
+        $(#[$attr:meta])*
+        fn $name:ident<$t:ident>() for {
+            $($(#[$case_attr:meta])* $case:ident: $ty:ty),+ $(,)?
+        } $body:block
+    ) => {
+        $(#[$attr])*
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(
+                #[test]
+                $(#[$case_attr])*
+                fn $case() {
+                    type $t = $ty;
+                    $body
+                }
+            )+
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        fn $name:ident<$t:ident>($arg:ident: $arg_ty:ty) for {
+            $($(#[$case_attr:meta])* $case:ident: $ty:ty => $value:expr),+ $(,)?
+        } $body:block
+    ) => {
+        $(#[$attr])*
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(
+                #[test]
+                $(#[$case_attr])*
+                fn $case() {
+                    type $t = $ty;
+                    let $arg: $arg_ty = $value;
+                    $body
+                }
+            )+
+        }
+    };
+}
@@ -23,7 +23,13 @@
 //! - `cbor` - CBOR serialization
 //! - `serde` - Serde serialization for keys/signatures
 //! - `metrics` - Performance metrics collection
+//! - `kes-metrics` - KES signing and verification latency histograms (includes `metrics`, `std`)
 //! - `logging` - Debug logging support
+//! - `encrypted-storage` - ChaCha20-Poly1305 sealing of KES signing keys
+//! - `rand` - Generate KES signing keys directly from an RNG
+//! - `secure-mem` - Keep signing key secrets in `mlock`ed pages on Unix (includes `dsign`)
+//! - `constant-time` - Pad every Sum KES update to the same work so its timing does not reveal the period
+//! - `parallel` - Build the subtrees of deep Sum KES keys on the rayon thread pool (includes `kes` and `std`)
 //!
 //! # Examples
 //!
@@ -117,6 +123,8 @@ pub mod kes;
 #[cfg_attr(docsrs, doc(cfg(feature = "cbor")))]
 pub mod cbor;
 
+pub mod metrics;
+
 // ============================================================================
 // Re-exports for convenience
 // ============================================================================
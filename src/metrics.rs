@@ -0,0 +1,1085 @@
+//! Lightweight operation counters
+//!
+//! With the `metrics` feature enabled this module keeps process-wide atomic
+//! counters for KES operations. Without the feature every recording function
+//! is a no-op and [`snapshot`] always returns zeroed metrics, so callers never
+//! need to sprinkle `#[cfg]` attributes around their instrumentation.
+//!
+//! Hot paths that want to avoid contention on the shared atomics can count
+//! into a per-thread [`LocalMetrics`] and periodically merge it into the
+//! global counters with [`LocalMetrics::flush_to_global`].
+//!
+//! With `std` as well, counts are also kept per algorithm. The built-in KES
+//! schemes open an [`algorithm_scope`] named after the outermost
+//! [`ALGORITHM_NAME`](crate::kes::KesAlgorithm::ALGORITHM_NAME) around each
+//! operation, so `Sum6Kes` and `CompactSum6Kes` traffic can be told apart
+//! with [`snapshot_by_algorithm`]. [`snapshot`] still returns the aggregate.
+//! Each built-in scheme has a fixed slot of atomic counters, so attributing a
+//! count neither allocates nor locks.
+//! Those schemes count key generation, signing and key evolution once per
+//! outermost call, which needs the scope's thread-local: without `std` only
+//! verifications are counted for them.
+//!
+//! The `kes-metrics` feature adds signing and verification latency
+//! histograms, bucketed by powers of two nanoseconds (see [`latency_bucket`]).
+//! Buckets are atomics, so recording a latency neither allocates nor locks.
+//! [`prometheus_encode`] renders the aggregate snapshot for scraping.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cardano_crypto::metrics::{self, LocalMetrics};
+//!
+//! let mut local = LocalMetrics::new();
+//! local.record_signature();
+//! local.record_signature();
+//! local.flush_to_global();
+//!
+//! // Local counts are cleared once they have been merged
+//! assert_eq!(local.snapshot(), metrics::KesMetrics::default());
+//! ```
+
+use core::marker::PhantomData;
+#[cfg(feature = "metrics")]
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+#[cfg(all(feature = "metrics", feature = "std"))]
+use std::{cell::Cell, sync::OnceLock};
+#[cfg(feature = "kes-metrics")]
+use std::{fmt::Write, string::String, time::Instant};
+
+/// Number of buckets in each latency histogram
+pub const LATENCY_BUCKETS: usize = 32;
+
+#[cfg(feature = "metrics")]
+static SIGNING_KEYS: AtomicU64 = AtomicU64::new(0);
+#[cfg(feature = "metrics")]
+static SIGNATURES: AtomicU64 = AtomicU64::new(0);
+#[cfg(feature = "metrics")]
+static UPDATES: AtomicU64 = AtomicU64::new(0);
+#[cfg(feature = "metrics")]
+static VERIFICATIONS: AtomicU64 = AtomicU64::new(0);
+#[cfg(feature = "metrics")]
+static VERIFICATION_FAILURES: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(feature = "kes-metrics")]
+static SIGN_LATENCY: [AtomicU64; LATENCY_BUCKETS] = [const { AtomicU64::new(0) }; LATENCY_BUCKETS];
+#[cfg(feature = "kes-metrics")]
+static VERIFY_LATENCY: [AtomicU64; LATENCY_BUCKETS] =
+    [const { AtomicU64::new(0) }; LATENCY_BUCKETS];
+
+/// Names with a reserved slot in the per-algorithm table
+///
+/// The `ALGORITHM_NAME`s of the built-in schemes; a slot's index is the
+/// algorithm's id.
+#[cfg(all(feature = "metrics", feature = "std"))]
+const BUILTIN_ALGORITHMS: [&str; 17] = [
+    "Ed25519",
+    "Sum1Kes",
+    "Sum2Kes",
+    "Sum3Kes",
+    "Sum4Kes",
+    "Sum5Kes",
+    "Sum6Kes",
+    "Sum7Kes",
+    "SumKes",
+    "CompactSum1Kes",
+    "CompactSum2Kes",
+    "CompactSum3Kes",
+    "CompactSum4Kes",
+    "CompactSum5Kes",
+    "CompactSum6Kes",
+    "CompactSum7Kes",
+    "CompactSumKes",
+];
+
+/// Slots claimed, first come first served, by names outside
+/// [`BUILTIN_ALGORITHMS`]
+#[cfg(all(feature = "metrics", feature = "std"))]
+const CUSTOM_ALGORITHMS: usize = 16;
+
+#[cfg(all(feature = "metrics", feature = "std"))]
+const ALGORITHM_SLOTS: usize = BUILTIN_ALGORITHMS.len() + CUSTOM_ALGORITHMS;
+
+/// Id of a scope whose name found no free slot: it still hides inner names,
+/// but its counts only reach the aggregate
+#[cfg(all(feature = "metrics", feature = "std"))]
+const UNTRACKED: usize = ALGORITHM_SLOTS;
+
+#[cfg(all(feature = "metrics", feature = "std"))]
+static CUSTOM_NAMES: [OnceLock<&'static str>; CUSTOM_ALGORITHMS] =
+    [const { OnceLock::new() }; CUSTOM_ALGORITHMS];
+
+#[cfg(all(feature = "metrics", feature = "std"))]
+static BY_ALGORITHM: [AlgorithmCounters; ALGORITHM_SLOTS] =
+    [const { AlgorithmCounters::new() }; ALGORITHM_SLOTS];
+
+#[cfg(all(feature = "metrics", feature = "std"))]
+std::thread_local! {
+    static ACTIVE_ALGORITHM: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+#[cfg(feature = "kes-metrics")]
+std::thread_local! {
+    static TIMING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Atomic counters of one algorithm, laid out like [`KesMetrics`]
+#[cfg(all(feature = "metrics", feature = "std"))]
+struct AlgorithmCounters {
+    signing_keys: AtomicU64,
+    signatures: AtomicU64,
+    updates: AtomicU64,
+    verifications: AtomicU64,
+    verification_failures: AtomicU64,
+    #[cfg(feature = "kes-metrics")]
+    sign_latency_ns: [AtomicU64; LATENCY_BUCKETS],
+    #[cfg(feature = "kes-metrics")]
+    verify_latency_ns: [AtomicU64; LATENCY_BUCKETS],
+}
+
+#[cfg(all(feature = "metrics", feature = "std"))]
+impl AlgorithmCounters {
+    const fn new() -> Self {
+        Self {
+            signing_keys: AtomicU64::new(0),
+            signatures: AtomicU64::new(0),
+            updates: AtomicU64::new(0),
+            verifications: AtomicU64::new(0),
+            verification_failures: AtomicU64::new(0),
+            #[cfg(feature = "kes-metrics")]
+            sign_latency_ns: [const { AtomicU64::new(0) }; LATENCY_BUCKETS],
+            #[cfg(feature = "kes-metrics")]
+            verify_latency_ns: [const { AtomicU64::new(0) }; LATENCY_BUCKETS],
+        }
+    }
+
+    fn add(&self, counts: &KesMetrics) {
+        self.signing_keys
+            .fetch_add(counts.signing_keys, Ordering::Relaxed);
+        self.signatures
+            .fetch_add(counts.signatures, Ordering::Relaxed);
+        self.updates.fetch_add(counts.updates, Ordering::Relaxed);
+        self.verifications
+            .fetch_add(counts.verifications, Ordering::Relaxed);
+        self.verification_failures
+            .fetch_add(counts.verification_failures, Ordering::Relaxed);
+        #[cfg(feature = "kes-metrics")]
+        for (bucket, (sign, verify)) in counts
+            .sign_latency_ns
+            .iter()
+            .zip(&counts.verify_latency_ns)
+            .enumerate()
+        {
+            self.sign_latency_ns[bucket].fetch_add(*sign, Ordering::Relaxed);
+            self.verify_latency_ns[bucket].fetch_add(*verify, Ordering::Relaxed);
+        }
+    }
+
+    fn load(&self) -> KesMetrics {
+        #[allow(unused_mut)]
+        let mut metrics = KesMetrics {
+            signing_keys: self.signing_keys.load(Ordering::Relaxed),
+            signatures: self.signatures.load(Ordering::Relaxed),
+            updates: self.updates.load(Ordering::Relaxed),
+            verifications: self.verifications.load(Ordering::Relaxed),
+            verification_failures: self.verification_failures.load(Ordering::Relaxed),
+            ..KesMetrics::default()
+        };
+        #[cfg(feature = "kes-metrics")]
+        {
+            metrics.sign_latency_ns =
+                core::array::from_fn(|i| self.sign_latency_ns[i].load(Ordering::Relaxed));
+            metrics.verify_latency_ns =
+                core::array::from_fn(|i| self.verify_latency_ns[i].load(Ordering::Relaxed));
+        }
+        metrics
+    }
+
+    fn reset(&self) {
+        let counters = [
+            &self.signing_keys,
+            &self.signatures,
+            &self.updates,
+            &self.verifications,
+            &self.verification_failures,
+        ];
+        #[cfg(feature = "kes-metrics")]
+        let counters = counters
+            .into_iter()
+            .chain(&self.sign_latency_ns)
+            .chain(&self.verify_latency_ns);
+        for counter in counters {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Id of the built-in algorithm called `name`
+#[cfg(all(feature = "metrics", feature = "std"))]
+const fn builtin_algorithm_id(name: &str) -> Option<usize> {
+    let mut id = 0;
+    while id < BUILTIN_ALGORITHMS.len() {
+        if str_eq(BUILTIN_ALGORITHMS[id], name) {
+            return Some(id);
+        }
+        id += 1;
+    }
+    None
+}
+
+#[cfg(all(feature = "metrics", feature = "std"))]
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Id of `name`, claiming a custom slot for it on first use
+///
+/// Returns [`UNTRACKED`] once every custom slot belongs to another name.
+#[cfg(all(feature = "metrics", feature = "std"))]
+fn algorithm_id(name: &'static str) -> usize {
+    if let Some(id) = builtin_algorithm_id(name) {
+        return id;
+    }
+    for (slot, claimed) in CUSTOM_NAMES.iter().enumerate() {
+        if *claimed.get_or_init(|| name) == name {
+            return BUILTIN_ALGORITHMS.len() + slot;
+        }
+    }
+    UNTRACKED
+}
+
+/// Name of the algorithm with id `id`, if the slot is in use
+#[cfg(all(feature = "metrics", feature = "std"))]
+fn algorithm_name(id: usize) -> Option<&'static str> {
+    match BUILTIN_ALGORITHMS.get(id) {
+        Some(name) => Some(name),
+        None => CUSTOM_NAMES
+            .get(id - BUILTIN_ALGORITHMS.len())?
+            .get()
+            .copied(),
+    }
+}
+
+/// Apply `update` to the counters of the algorithm in scope on this thread
+#[cfg(all(feature = "metrics", feature = "std"))]
+fn attribute<R>(update: impl FnOnce(&AlgorithmCounters) -> R) {
+    if let Some(counters) = ACTIVE_ALGORITHM
+        .with(Cell::get)
+        .and_then(|id| BY_ALGORITHM.get(id))
+    {
+        update(counters);
+    }
+}
+
+/// Point-in-time view of the KES operation counters
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::metrics::KesMetrics;
+///
+/// let metrics = KesMetrics::default();
+/// assert_eq!(metrics.signatures, 0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KesMetrics {
+    /// Number of signing keys generated
+    pub signing_keys: u64,
+    /// Number of signatures produced
+    pub signatures: u64,
+    /// Number of key evolutions performed
+    pub updates: u64,
+    /// Number of KES signatures verified, successfully or not
+    pub verifications: u64,
+    /// Number of KES signatures that failed verification
+    pub verification_failures: u64,
+    /// `sign_kes` calls per latency bucket; all zero without `kes-metrics`
+    pub sign_latency_ns: [u64; LATENCY_BUCKETS],
+    /// `verify_kes` calls per latency bucket; all zero without `kes-metrics`
+    pub verify_latency_ns: [u64; LATENCY_BUCKETS],
+}
+
+impl KesMetrics {
+    /// Field-wise sum of two snapshots, saturating at `u64::MAX`
+    ///
+    /// Aggregates counters collected on different threads or processes,
+    /// including each latency bucket.
+    ///
+    /// ```rust
+    /// use cardano_crypto::metrics::KesMetrics;
+    ///
+    /// let a = KesMetrics { signatures: 3, ..KesMetrics::default() };
+    /// let b = KesMetrics { signatures: 4, updates: 1, ..KesMetrics::default() };
+    /// let total = a.merge(&b);
+    /// assert_eq!((total.signatures, total.updates), (7, 1));
+    /// ```
+    #[must_use]
+    pub fn merge(&self, other: &Self) -> Self {
+        self.zip_with(other, u64::saturating_add)
+    }
+
+    /// Field-wise difference from an `earlier` snapshot, saturating at zero
+    ///
+    /// The counts recorded between the two snapshots, for per-interval rates.
+    /// A field that went down, as after [`reset`], reads 0 rather than
+    /// wrapping.
+    ///
+    /// ```rust
+    /// use cardano_crypto::metrics::KesMetrics;
+    ///
+    /// let earlier = KesMetrics { verifications: 10, ..KesMetrics::default() };
+    /// let now = KesMetrics { verifications: 25, ..KesMetrics::default() };
+    /// assert_eq!(now.saturating_sub(&earlier).verifications, 15);
+    /// assert_eq!(earlier.saturating_sub(&now).verifications, 0);
+    /// ```
+    #[must_use]
+    pub fn saturating_sub(&self, earlier: &Self) -> Self {
+        self.zip_with(earlier, u64::saturating_sub)
+    }
+
+    fn zip_with(&self, other: &Self, op: fn(u64, u64) -> u64) -> Self {
+        let buckets = |a: [u64; LATENCY_BUCKETS], b: [u64; LATENCY_BUCKETS]| {
+            core::array::from_fn(|i| op(a[i], b[i]))
+        };
+        Self {
+            signing_keys: op(self.signing_keys, other.signing_keys),
+            signatures: op(self.signatures, other.signatures),
+            updates: op(self.updates, other.updates),
+            verifications: op(self.verifications, other.verifications),
+            verification_failures: op(self.verification_failures, other.verification_failures),
+            sign_latency_ns: buckets(self.sign_latency_ns, other.sign_latency_ns),
+            verify_latency_ns: buckets(self.verify_latency_ns, other.verify_latency_ns),
+        }
+    }
+}
+
+/// KES operation timed by [`operation_scope`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// Producing a signature
+    Sign,
+    /// Checking a signature
+    Verify,
+}
+
+/// Histogram bucket for a latency of `nanos` nanoseconds
+///
+/// Bucket `i` holds latencies in `[2^i, 2^(i+1))` ns, with zero in bucket 0
+/// and everything from `2^31` ns (about two seconds) up in the last bucket.
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::metrics::{latency_bucket, LATENCY_BUCKETS};
+///
+/// assert_eq!(latency_bucket(1), 0);
+/// assert_eq!(latency_bucket(1_000), 9);
+/// assert_eq!(latency_bucket(u64::MAX), LATENCY_BUCKETS - 1);
+/// ```
+#[must_use]
+pub const fn latency_bucket(nanos: u64) -> usize {
+    if nanos == 0 {
+        return 0;
+    }
+    let bucket = (u64::BITS - 1 - nanos.leading_zeros()) as usize;
+    if bucket < LATENCY_BUCKETS {
+        bucket
+    } else {
+        LATENCY_BUCKETS - 1
+    }
+}
+
+fn duration_bucket(elapsed: Duration) -> usize {
+    latency_bucket(u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX))
+}
+
+/// Guard returned by [`algorithm_scope`]
+///
+/// Tied to the thread that created it, since the scope lives in a
+/// thread-local.
+#[derive(Debug)]
+#[must_use = "counts are only attributed while the scope is held"]
+pub struct AlgorithmScope {
+    #[cfg(all(feature = "metrics", feature = "std"))]
+    outermost: bool,
+    #[cfg(feature = "kes-metrics")]
+    timer: Option<(Operation, Instant)>,
+    _not_send: PhantomData<*const ()>,
+}
+
+#[cfg_attr(not(feature = "kes"), allow(dead_code))]
+impl AlgorithmScope {
+    /// Whether this is the outermost scope on the thread
+    ///
+    /// The built-in schemes count key generation, signing and evolution
+    /// through their outermost scope only, so a Sum tree's recursion into
+    /// its subtrees is counted once. Without `std` there is no thread-local
+    /// to tell scopes apart and these operations are not counted.
+    fn counts(&self) -> bool {
+        #[cfg(all(feature = "metrics", feature = "std"))]
+        {
+            self.outermost
+        }
+        #[cfg(not(all(feature = "metrics", feature = "std")))]
+        {
+            false
+        }
+    }
+
+    /// Record a generated signing key if this is the outermost scope
+    pub(crate) fn record_signing_key(&self) {
+        if self.counts() {
+            add_signing_keys(1);
+        }
+    }
+
+    /// Record `count` signatures if this is the outermost scope
+    pub(crate) fn record_signatures(&self, count: u64) {
+        if self.counts() {
+            add_signatures(count);
+        }
+    }
+
+    /// Record `count` periods of key evolution if this is the outermost scope
+    pub(crate) fn record_updates(&self, count: u64) {
+        if self.counts() {
+            add_updates(count);
+        }
+    }
+}
+
+impl Drop for AlgorithmScope {
+    fn drop(&mut self) {
+        // Record before the name is released so the latency is attributed
+        #[cfg(feature = "kes-metrics")]
+        if let Some((operation, started)) = self.timer.take() {
+            record_latency(operation, started.elapsed());
+            TIMING.with(|timing| timing.set(false));
+        }
+        #[cfg(all(feature = "metrics", feature = "std"))]
+        if self.outermost {
+            ACTIVE_ALGORITHM.with(|active| active.set(None));
+        }
+    }
+}
+
+/// Attribute counts recorded on this thread to `name` until the guard drops
+///
+/// Scopes nest: while one is open, inner scopes keep the outer name. A
+/// `Sum6Kes` verification therefore counts under `"Sum6Kes"` even though the
+/// leaf that settles it opens its own scope. Counts recorded outside any
+/// scope only reach the aggregate.
+///
+/// The built-in algorithm names have reserved slots. Up to 16 other names
+/// each claim a slot the first time they are used; scopes of any name past
+/// those only count towards the aggregate.
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::metrics;
+///
+/// let _scope = metrics::algorithm_scope("MyKes");
+/// metrics::record_signature();
+/// ```
+pub fn algorithm_scope(name: &'static str) -> AlgorithmScope {
+    #[cfg(all(feature = "metrics", feature = "std"))]
+    {
+        let outermost = ACTIVE_ALGORITHM.with(|active| {
+            if active.get().is_none() {
+                active.set(Some(algorithm_id(name)));
+                true
+            } else {
+                false
+            }
+        });
+        AlgorithmScope {
+            outermost,
+            #[cfg(feature = "kes-metrics")]
+            timer: None,
+            _not_send: PhantomData,
+        }
+    }
+    #[cfg(not(all(feature = "metrics", feature = "std")))]
+    {
+        let _ = name;
+        AlgorithmScope {
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// Like [`algorithm_scope`], also timing `operation` until the guard drops
+///
+/// With `kes-metrics`, the outermost timed scope on the thread records its
+/// duration with [`record_latency`]; nested ones are not timed, so a Sum
+/// tree's recursion is measured once. Without the feature this is just
+/// [`algorithm_scope`].
+pub fn operation_scope(name: &'static str, operation: Operation) -> AlgorithmScope {
+    #[allow(unused_mut)]
+    let mut scope = algorithm_scope(name);
+    #[cfg(feature = "kes-metrics")]
+    if !TIMING.with(|timing| timing.replace(true)) {
+        scope.timer = Some((operation, Instant::now()));
+    }
+    #[cfg(not(feature = "kes-metrics"))]
+    let _ = operation;
+    scope
+}
+
+/// Record how long a sign or verify call took
+///
+/// A no-op without the `kes-metrics` feature.
+#[inline]
+pub fn record_latency(operation: Operation, elapsed: Duration) {
+    #[cfg(feature = "kes-metrics")]
+    {
+        let bucket = duration_bucket(elapsed);
+        match operation {
+            Operation::Sign => SIGN_LATENCY[bucket].fetch_add(1, Ordering::Relaxed),
+            Operation::Verify => VERIFY_LATENCY[bucket].fetch_add(1, Ordering::Relaxed),
+        };
+        attribute(|m| match operation {
+            Operation::Sign => m.sign_latency_ns[bucket].fetch_add(1, Ordering::Relaxed),
+            Operation::Verify => m.verify_latency_ns[bucket].fetch_add(1, Ordering::Relaxed),
+        });
+    }
+    #[cfg(not(feature = "kes-metrics"))]
+    let _ = (operation, elapsed);
+}
+
+/// Record the generation of a signing key
+#[inline]
+pub fn record_signing_key() {
+    add_signing_keys(1);
+}
+
+/// Record the production of a signature
+#[inline]
+pub fn record_signature() {
+    add_signatures(1);
+}
+
+/// Record a key evolution
+#[inline]
+pub fn record_update() {
+    add_updates(1);
+}
+
+fn add_signing_keys(count: u64) {
+    #[cfg(feature = "metrics")]
+    {
+        SIGNING_KEYS.fetch_add(count, Ordering::Relaxed);
+        #[cfg(feature = "std")]
+        attribute(|m| m.signing_keys.fetch_add(count, Ordering::Relaxed));
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = count;
+}
+
+fn add_signatures(count: u64) {
+    #[cfg(feature = "metrics")]
+    {
+        SIGNATURES.fetch_add(count, Ordering::Relaxed);
+        #[cfg(feature = "std")]
+        attribute(|m| m.signatures.fetch_add(count, Ordering::Relaxed));
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = count;
+}
+
+fn add_updates(count: u64) {
+    #[cfg(feature = "metrics")]
+    {
+        UPDATES.fetch_add(count, Ordering::Relaxed);
+        #[cfg(feature = "std")]
+        attribute(|m| m.updates.fetch_add(count, Ordering::Relaxed));
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = count;
+}
+
+/// Record a KES signature verification and whether it succeeded
+///
+/// Called once per [`verify_kes`](crate::kes::KesAlgorithm::verify_kes) call,
+/// by the built-in schemes, however deep the tree.
+#[inline]
+pub fn record_verification(success: bool) {
+    #[cfg(feature = "metrics")]
+    {
+        VERIFICATIONS.fetch_add(1, Ordering::Relaxed);
+        if !success {
+            VERIFICATION_FAILURES.fetch_add(1, Ordering::Relaxed);
+        }
+        #[cfg(feature = "std")]
+        attribute(|m| {
+            m.verifications.fetch_add(1, Ordering::Relaxed);
+            if !success {
+                m.verification_failures.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = success;
+}
+
+/// Read the current global counters, summed over all algorithms
+#[must_use]
+pub fn snapshot() -> KesMetrics {
+    #[cfg(feature = "metrics")]
+    {
+        #[allow(unused_mut)]
+        let mut metrics = KesMetrics {
+            signing_keys: SIGNING_KEYS.load(Ordering::Relaxed),
+            signatures: SIGNATURES.load(Ordering::Relaxed),
+            updates: UPDATES.load(Ordering::Relaxed),
+            verifications: VERIFICATIONS.load(Ordering::Relaxed),
+            verification_failures: VERIFICATION_FAILURES.load(Ordering::Relaxed),
+            ..KesMetrics::default()
+        };
+        #[cfg(feature = "kes-metrics")]
+        {
+            metrics.sign_latency_ns =
+                core::array::from_fn(|i| SIGN_LATENCY[i].load(Ordering::Relaxed));
+            metrics.verify_latency_ns =
+                core::array::from_fn(|i| VERIFY_LATENCY[i].load(Ordering::Relaxed));
+        }
+        metrics
+    }
+    #[cfg(not(feature = "metrics"))]
+    {
+        KesMetrics::default()
+    }
+}
+
+/// Read the counters of every algorithm that has recorded anything
+///
+/// Keyed by the name passed to [`algorithm_scope`]. Empty without the
+/// `metrics` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::metrics;
+///
+/// for (algorithm, counts) in metrics::snapshot_by_algorithm() {
+///     println!("{algorithm}: {} verifications", counts.verifications);
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn snapshot_by_algorithm() -> BTreeMap<&'static str, KesMetrics> {
+    #[cfg(feature = "metrics")]
+    {
+        (0..ALGORITHM_SLOTS)
+            .filter_map(|id| {
+                let counts = BY_ALGORITHM[id].load();
+                let recorded = counts != KesMetrics::default();
+                Some((algorithm_name(id)?, counts)).filter(|_| recorded)
+            })
+            .collect()
+    }
+    #[cfg(not(feature = "metrics"))]
+    {
+        BTreeMap::new()
+    }
+}
+
+/// Read the counters of a single algorithm
+#[cfg(feature = "std")]
+#[must_use]
+pub fn snapshot_for(algorithm: &str) -> KesMetrics {
+    #[cfg(feature = "metrics")]
+    {
+        (0..ALGORITHM_SLOTS)
+            .find(|&id| algorithm_name(id) == Some(algorithm))
+            .map(|id| BY_ALGORITHM[id].load())
+            .unwrap_or_default()
+    }
+    #[cfg(not(feature = "metrics"))]
+    {
+        let _ = algorithm;
+        KesMetrics::default()
+    }
+}
+
+/// Render the aggregate [`snapshot`] in the Prometheus text exposition format
+///
+/// Counters are named `cardano_kes_<counter>_total`. The latency histograms
+/// become `cardano_kes_sign_duration_seconds` and
+/// `cardano_kes_verify_duration_seconds`, with cumulative `le` buckets at the
+/// power-of-two bucket bounds. Only counts are tracked, so the histograms
+/// have no `_sum` series.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "kes-metrics")]
+/// # {
+/// let text = cardano_crypto::metrics::prometheus_encode();
+/// assert!(text.contains("# TYPE cardano_kes_signatures_total counter"));
+/// # }
+/// ```
+#[cfg(feature = "kes-metrics")]
+#[must_use]
+pub fn prometheus_encode() -> String {
+    let metrics = snapshot();
+    let mut out = String::new();
+    for (name, help, value) in [
+        (
+            "cardano_kes_signing_keys_total",
+            "KES signing keys generated",
+            metrics.signing_keys,
+        ),
+        (
+            "cardano_kes_signatures_total",
+            "KES signatures produced",
+            metrics.signatures,
+        ),
+        (
+            "cardano_kes_updates_total",
+            "KES key evolutions performed",
+            metrics.updates,
+        ),
+        (
+            "cardano_kes_verifications_total",
+            "KES signatures verified, successfully or not",
+            metrics.verifications,
+        ),
+        (
+            "cardano_kes_verification_failures_total",
+            "KES signatures that failed verification",
+            metrics.verification_failures,
+        ),
+    ] {
+        // Writing to a String cannot fail
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} counter");
+        let _ = writeln!(out, "{name} {value}");
+    }
+    for (name, help, buckets) in [
+        (
+            "cardano_kes_sign_duration_seconds",
+            "Time spent in sign_kes",
+            &metrics.sign_latency_ns,
+        ),
+        (
+            "cardano_kes_verify_duration_seconds",
+            "Time spent in verify_kes",
+            &metrics.verify_latency_ns,
+        ),
+    ] {
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} histogram");
+        let mut cumulative = 0;
+        for (bucket, count) in buckets.iter().enumerate() {
+            cumulative += count;
+            if bucket + 1 < LATENCY_BUCKETS {
+                let le = (1u64 << (bucket + 1)) as f64 / 1e9;
+                let _ = writeln!(out, "{name}_bucket{{le=\"{le}\"}} {cumulative}");
+            }
+        }
+        let _ = writeln!(out, "{name}_bucket{{le=\"+Inf\"}} {cumulative}");
+        let _ = writeln!(out, "{name}_count {cumulative}");
+    }
+    out
+}
+
+/// Reset all global and per-algorithm counters to zero
+pub fn reset() {
+    #[cfg(feature = "metrics")]
+    {
+        SIGNING_KEYS.store(0, Ordering::Relaxed);
+        SIGNATURES.store(0, Ordering::Relaxed);
+        UPDATES.store(0, Ordering::Relaxed);
+        VERIFICATIONS.store(0, Ordering::Relaxed);
+        VERIFICATION_FAILURES.store(0, Ordering::Relaxed);
+        #[cfg(feature = "kes-metrics")]
+        for bucket in SIGN_LATENCY.iter().chain(&VERIFY_LATENCY) {
+            bucket.store(0, Ordering::Relaxed);
+        }
+        #[cfg(feature = "std")]
+        for counters in &BY_ALGORITHM {
+            counters.reset();
+        }
+    }
+}
+
+/// Non-atomic counters for a single thread
+///
+/// Counting into a `LocalMetrics` costs a plain integer increment. The counts
+/// only become visible in [`snapshot`] after [`flush_to_global`](Self::flush_to_global),
+/// which adds them to the global atomics in one batch, and to the algorithm
+/// in [`algorithm_scope`] if one is open.
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::metrics::LocalMetrics;
+///
+/// let mut local = LocalMetrics::new();
+/// local.record_signing_key();
+/// local.record_update();
+/// assert_eq!(local.snapshot().signing_keys, 1);
+/// assert_eq!(local.snapshot().updates, 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LocalMetrics {
+    counts: KesMetrics,
+}
+
+impl LocalMetrics {
+    /// Create an empty collector
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the generation of a signing key
+    #[inline]
+    pub fn record_signing_key(&mut self) {
+        self.counts.signing_keys += 1;
+    }
+
+    /// Record the production of a signature
+    #[inline]
+    pub fn record_signature(&mut self) {
+        self.counts.signatures += 1;
+    }
+
+    /// Record a key evolution
+    #[inline]
+    pub fn record_update(&mut self) {
+        self.counts.updates += 1;
+    }
+
+    /// Record a KES signature verification and whether it succeeded
+    #[inline]
+    pub fn record_verification(&mut self, success: bool) {
+        self.counts.verifications += 1;
+        if !success {
+            self.counts.verification_failures += 1;
+        }
+    }
+
+    /// Record how long a sign or verify call took
+    #[inline]
+    pub fn record_latency(&mut self, operation: Operation, elapsed: Duration) {
+        let bucket = duration_bucket(elapsed);
+        match operation {
+            Operation::Sign => self.counts.sign_latency_ns[bucket] += 1,
+            Operation::Verify => self.counts.verify_latency_ns[bucket] += 1,
+        }
+    }
+
+    /// Counts recorded since the last flush
+    #[must_use]
+    pub fn snapshot(&self) -> KesMetrics {
+        self.counts
+    }
+
+    /// Add the local counts to the global counters and clear them
+    pub fn flush_to_global(&mut self) {
+        let counts = core::mem::take(&mut self.counts);
+        #[cfg(feature = "metrics")]
+        {
+            SIGNING_KEYS.fetch_add(counts.signing_keys, Ordering::Relaxed);
+            SIGNATURES.fetch_add(counts.signatures, Ordering::Relaxed);
+            UPDATES.fetch_add(counts.updates, Ordering::Relaxed);
+            VERIFICATIONS.fetch_add(counts.verifications, Ordering::Relaxed);
+            VERIFICATION_FAILURES.fetch_add(counts.verification_failures, Ordering::Relaxed);
+            #[cfg(feature = "kes-metrics")]
+            for (i, (sign, verify)) in counts
+                .sign_latency_ns
+                .iter()
+                .zip(&counts.verify_latency_ns)
+                .enumerate()
+            {
+                SIGN_LATENCY[i].fetch_add(*sign, Ordering::Relaxed);
+                VERIFY_LATENCY[i].fetch_add(*verify, Ordering::Relaxed);
+            }
+            #[cfg(feature = "std")]
+            attribute(|m| m.add(&counts));
+        }
+        #[cfg(not(feature = "metrics"))]
+        let _ = counts;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_metrics_count_and_clear_on_flush() {
+        let mut local = LocalMetrics::new();
+        local.record_signing_key();
+        local.record_signature();
+        local.record_signature();
+        local.record_update();
+        local.record_verification(true);
+        local.record_verification(false);
+        local.record_latency(Operation::Sign, Duration::from_nanos(1_500));
+
+        let counts = local.snapshot();
+        assert_eq!(counts.signing_keys, 1);
+        assert_eq!(counts.signatures, 2);
+        assert_eq!(counts.updates, 1);
+        assert_eq!(counts.verifications, 2);
+        assert_eq!(counts.verification_failures, 1);
+        assert_eq!(counts.sign_latency_ns[latency_bucket(1_500)], 1);
+
+        local.flush_to_global();
+        assert_eq!(local.snapshot(), KesMetrics::default());
+    }
+
+    #[test]
+    fn merge_and_saturating_sub_are_field_wise() {
+        let mut earlier = KesMetrics {
+            signing_keys: 1,
+            signatures: 10,
+            updates: 4,
+            verifications: 20,
+            verification_failures: 2,
+            ..KesMetrics::default()
+        };
+        earlier.sign_latency_ns[3] = 5;
+        let mut later = KesMetrics {
+            signing_keys: 1,
+            signatures: 15,
+            updates: 3,
+            verifications: 29,
+            verification_failures: 2,
+            ..KesMetrics::default()
+        };
+        later.sign_latency_ns[3] = 9;
+        later.verify_latency_ns[0] = u64::MAX;
+
+        let delta = later.saturating_sub(&earlier);
+        assert_eq!(delta.signing_keys, 0);
+        assert_eq!(delta.signatures, 5);
+        assert_eq!(delta.updates, 0, "a count that went down saturates at zero");
+        assert_eq!(delta.verifications, 9);
+        assert_eq!(delta.sign_latency_ns[3], 4);
+        assert_eq!(delta.verify_latency_ns[0], u64::MAX);
+        assert_eq!(earlier.merge(&delta).signatures, later.signatures);
+
+        let total = earlier.merge(&later);
+        assert_eq!(total.signatures, 25);
+        assert_eq!(total.verification_failures, 4);
+        assert_eq!(total.sign_latency_ns[3], 14);
+        assert_eq!(total.verify_latency_ns[0], u64::MAX);
+        assert_eq!(total.merge(&later).verify_latency_ns[0], u64::MAX);
+        assert_eq!(total.merge(&KesMetrics::default()), total);
+        assert_eq!(total.saturating_sub(&total), KesMetrics::default());
+    }
+
+    #[cfg(all(feature = "metrics", feature = "std"))]
+    #[test]
+    fn flushing_two_local_collectors_sums_into_global() {
+        // Other tests record into the aggregate concurrently, so exact counts
+        // are checked under a name only this test uses, and the aggregate
+        // only for having grown by at least as much
+        let before = snapshot();
+        {
+            let _scope = algorithm_scope("FlushTestKes");
+            let mut first = LocalMetrics::new();
+            first.record_signing_key();
+            first.record_signature();
+            first.record_signature();
+
+            let mut second = LocalMetrics::new();
+            second.record_signature();
+            second.record_update();
+            second.record_update();
+            second.record_update();
+
+            first.flush_to_global();
+            second.flush_to_global();
+        }
+
+        let flushed = snapshot_for("FlushTestKes");
+        assert_eq!(flushed.signing_keys, 1);
+        assert_eq!(flushed.signatures, 3);
+        assert_eq!(flushed.updates, 3);
+
+        let grown = snapshot().saturating_sub(&before);
+        assert!(grown.signing_keys >= 1);
+        assert!(grown.signatures >= 3);
+        assert!(grown.updates >= 3);
+    }
+
+    #[cfg(all(feature = "metrics", feature = "std"))]
+    #[test]
+    fn nested_scopes_count_under_outermost_name() {
+        {
+            let _outer = algorithm_scope("OuterTestKes");
+            let _inner = algorithm_scope("InnerTestKes");
+            record_verification(false);
+            let mut local = LocalMetrics::new();
+            local.record_signature();
+            local.flush_to_global();
+        }
+        // Outside any scope: aggregate only
+        record_verification(true);
+
+        let outer = snapshot_for("OuterTestKes");
+        assert_eq!(outer.verifications, 1);
+        assert_eq!(outer.verification_failures, 1);
+        assert_eq!(outer.signatures, 1);
+        assert_eq!(snapshot_for("InnerTestKes"), KesMetrics::default());
+        assert_eq!(snapshot_by_algorithm().get("OuterTestKes"), Some(&outer));
+    }
+
+    #[cfg(all(feature = "metrics", feature = "std", feature = "kes"))]
+    #[test]
+    fn builtin_schemes_have_reserved_slots() {
+        use crate::kes::*;
+
+        for name in [
+            Sum0Kes::ALGORITHM_NAME,
+            Sum1Kes::ALGORITHM_NAME,
+            Sum2Kes::ALGORITHM_NAME,
+            Sum3Kes::ALGORITHM_NAME,
+            Sum4Kes::ALGORITHM_NAME,
+            Sum5Kes::ALGORITHM_NAME,
+            Sum6Kes::ALGORITHM_NAME,
+            Sum7Kes::ALGORITHM_NAME,
+            CompactSum1Kes::ALGORITHM_NAME,
+            CompactSum2Kes::ALGORITHM_NAME,
+            CompactSum3Kes::ALGORITHM_NAME,
+            CompactSum4Kes::ALGORITHM_NAME,
+            CompactSum5Kes::ALGORITHM_NAME,
+            CompactSum6Kes::ALGORITHM_NAME,
+            CompactSum7Kes::ALGORITHM_NAME,
+            <SumKes<Sum7Kes, Blake2b256>>::ALGORITHM_NAME,
+            <CompactSumKes<CompactSum7Kes, Blake2b256>>::ALGORITHM_NAME,
+        ] {
+            assert!(builtin_algorithm_id(name).is_some(), "{name}");
+        }
+    }
+
+    #[cfg(not(feature = "metrics"))]
+    #[test]
+    fn snapshot_is_zero_without_feature() {
+        record_signature();
+        let mut local = LocalMetrics::new();
+        local.record_signature();
+        local.flush_to_global();
+        assert_eq!(snapshot(), KesMetrics::default());
+    }
+}
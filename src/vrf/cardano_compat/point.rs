@@ -33,6 +33,7 @@ use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
 use sha2::{Digest, Sha512};
 
 use crate::common::{CryptoError, CryptoResult, ONE, SUITE_DRAFT03};
+use crate::vrf::VrfError;
 
 /// Clear the cofactor from an Edwards curve point (Cardano-compatible)
 ///
@@ -95,7 +96,7 @@ pub fn cardano_clear_cofactor(point: &EdwardsPoint) -> EdwardsPoint {
 ///
 /// # Errors
 ///
-/// Returns [`CryptoError::InvalidPoint`] if:
+/// Returns [`VrfError::HashToCurveFailed`] if:
 /// - No valid point can be found after 256 retry attempts (extremely unlikely)
 /// - This indicates a catastrophic hash function failure
 ///
@@ -158,7 +159,7 @@ pub fn cardano_hash_to_curve(pk: &[u8], message: &[u8]) -> CryptoResult<(Edwards
                 }
             }
 
-            Err(CryptoError::InvalidPoint)
+            Err(CryptoError::VrfError(VrfError::HashToCurveFailed))
         }
     }
 }
@@ -260,7 +261,7 @@ fn expand_message_xmd(dst: &[u8], msg: &[u8], len_in_bytes: usize) -> Vec<u8> {
 ///
 /// # Errors
 ///
-/// Returns [`CryptoError::InvalidPoint`] if no valid point found after 256 attempts
+/// Returns [`VrfError::HashToCurveFailed`] if no valid point found after 256 attempts
 ///
 /// # Examples
 ///
@@ -323,7 +324,7 @@ pub fn cardano_hash_to_curve_draft13(
                 }
             }
 
-            Err(CryptoError::InvalidPoint)
+            Err(CryptoError::VrfError(VrfError::HashToCurveFailed))
         }
     }
 }
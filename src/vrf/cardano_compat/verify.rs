@@ -27,6 +27,7 @@ use sha2::{Digest, Sha512};
 
 use super::point::{cardano_clear_cofactor, cardano_hash_to_curve};
 use crate::common::{point_to_bytes, CryptoError, CryptoResult, SUITE_DRAFT03, THREE, TWO};
+use crate::vrf::VrfError;
 
 /// Verify VRF proof using Cardano-compatible method
 ///
@@ -91,12 +92,12 @@ pub fn cardano_vrf_verify(
     // Parse public key
     let y_point = CompressedEdwardsY(*public_key)
         .decompress()
-        .ok_or(CryptoError::InvalidPublicKey)?;
+        .ok_or(CryptoError::VrfError(VrfError::InvalidPublicKey))?;
 
     // Parse Gamma
     let gamma = CompressedEdwardsY(gamma_bytes)
         .decompress()
-        .ok_or(CryptoError::InvalidProof)?;
+        .ok_or(CryptoError::VrfError(VrfError::MalformedProof))?;
 
     // Parse s
     let s = Scalar::from_bytes_mod_order(s_bytes);
@@ -143,7 +144,7 @@ pub fn cardano_vrf_verify(
     // This is a cryptographic best practice to prevent timing attacks
     let challenge_matches = c_hash[0..16] == c_bytes_short[..];
     if !challenge_matches {
-        return Err(CryptoError::VerificationFailed);
+        return Err(CryptoError::VrfError(VrfError::VerificationFailed));
     }
 
     // Step 6: Compute VRF output
@@ -209,23 +210,40 @@ mod tests {
         let invalid_proof = [0u8; 80];
 
         let result = cardano_vrf_verify(&pk, &invalid_proof, message);
-        assert!(result.is_err());
+        assert_eq!(
+            result,
+            Err(CryptoError::VrfError(VrfError::VerificationFailed))
+        );
     }
 
     #[test]
     fn test_verify_rejects_wrong_message() {
-        let mut sk = [0u8; 64];
-        sk[0..32].fill(1);
-        sk[32..64].copy_from_slice(&[2u8; 32]);
-
-        let pk = &sk[32..64];
+        let (sk, pk) = crate::vrf::VrfDraft03::keypair_from_seed(&[1u8; 32]);
         let message = b"test";
 
         let proof = cardano_vrf_prove(&sk, message).expect("prove failed");
 
         // Try to verify with wrong message
-        let result = cardano_vrf_verify(pk.try_into().unwrap(), &proof, b"wrong");
-
-        assert!(result.is_err());
+        let result = cardano_vrf_verify(&pk, &proof, b"wrong");
+
+        assert_eq!(
+            result,
+            Err(CryptoError::VrfError(VrfError::VerificationFailed))
+        );
+
+        // A public key that is not a curve point is reported as such
+        assert_eq!(
+            cardano_vrf_verify(&[2u8; 32], &proof, message),
+            Err(CryptoError::VrfError(VrfError::InvalidPublicKey))
+        );
+
+        // So is a Gamma that is not a curve point
+        let mut malformed = proof;
+        malformed[0..32].fill(0);
+        malformed[0] = 2;
+        assert_eq!(
+            cardano_vrf_verify(&pk, &malformed, message),
+            Err(CryptoError::VrfError(VrfError::MalformedProof))
+        );
     }
 }
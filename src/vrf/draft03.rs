@@ -67,12 +67,17 @@
 //! - Proof size (80 bytes) is acceptable
 //! - You're working with existing Cardano infrastructure
 
-use curve25519_dalek::{constants::ED25519_BASEPOINT_POINT, scalar::Scalar};
+use curve25519_dalek::{
+    constants::ED25519_BASEPOINT_POINT, edwards::CompressedEdwardsY, scalar::Scalar,
+};
 use sha2::{Digest, Sha512};
 use zeroize::Zeroizing;
 
-use crate::common::{clamp_scalar, point_to_bytes, CryptoResult, SUITE_DRAFT03, THREE};
+use crate::common::{
+    clamp_scalar, point_to_bytes, CryptoError, CryptoResult, SUITE_DRAFT03, THREE,
+};
 use crate::vrf::cardano_compat::{cardano_vrf_prove, cardano_vrf_verify};
+use crate::vrf::VrfError;
 
 /// VRF proof size for draft-03: 80 bytes
 ///
@@ -195,9 +200,8 @@ impl VrfDraft03 {
     ///
     /// # Errors
     ///
-    /// Returns [`crate::common::error::CryptoError`] if:
-    /// - Secret key is malformed or invalid
-    /// - Internal cryptographic operations fail (extremely rare)
+    /// Returns [`VrfError::HashToCurveFailed`] if hashing the message to the
+    /// curve fails (extremely rare).
     ///
     /// # Security
     ///
@@ -248,9 +252,10 @@ impl VrfDraft03 {
     ///
     /// # Errors
     ///
-    /// Returns [`crate::common::error::CryptoError`] if:
+    /// Returns a [`VrfError`] wrapped in [`CryptoError::VrfError`]:
     /// - `InvalidPublicKey`: Public key is malformed or not on the curve
-    /// - `InvalidProof`: Proof is malformed, wrong length, or contains invalid data
+    /// - `MalformedProof`: Gamma is not a curve point
+    /// - `HashToCurveFailed`: Hashing the message to the curve failed
     /// - `VerificationFailed`: Proof is well-formed but cryptographically invalid
     ///
     /// # Examples
@@ -279,6 +284,67 @@ impl VrfDraft03 {
         cardano_vrf_verify(public_key, proof, message)
     }
 
+    /// Like [`prove`](Self::prove), writing the proof into `out`
+    ///
+    /// Proving never touches the heap, so hot loops can reuse one buffer for
+    /// every slot. `out` is left unchanged on error.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`prove`](Self::prove).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cardano_crypto::vrf::draft03::{VrfDraft03, PROOF_SIZE};
+    ///
+    /// let (secret_key, _) = VrfDraft03::keypair_from_seed(&[4u8; 32]);
+    /// let mut proof = [0u8; PROOF_SIZE];
+    /// VrfDraft03::prove_into(&secret_key, b"slot_1", &mut proof).unwrap();
+    /// assert_eq!(proof, VrfDraft03::prove(&secret_key, b"slot_1").unwrap());
+    /// ```
+    pub fn prove_into(
+        secret_key: &[u8; SECRET_KEY_SIZE],
+        message: &[u8],
+        out: &mut [u8; PROOF_SIZE],
+    ) -> CryptoResult<()> {
+        *out = cardano_vrf_prove(secret_key, message)?;
+        Ok(())
+    }
+
+    /// Like [`verify`](Self::verify), writing the VRF output into `out`
+    ///
+    /// Verification never touches the heap, so a slot-leader check can reuse
+    /// one output buffer for every slot. `out` is left unchanged on error.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`verify`](Self::verify).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cardano_crypto::vrf::draft03::{VrfDraft03, OUTPUT_SIZE};
+    ///
+    /// let (secret_key, public_key) = VrfDraft03::keypair_from_seed(&[5u8; 32]);
+    /// let proof = VrfDraft03::prove(&secret_key, b"slot_2").unwrap();
+    ///
+    /// let mut output = [0u8; OUTPUT_SIZE];
+    /// VrfDraft03::verify_into(&public_key, &proof, b"slot_2", &mut output).unwrap();
+    /// assert_eq!(output, VrfDraft03::proof_to_hash(&proof).unwrap());
+    ///
+    /// assert!(VrfDraft03::verify_into(&public_key, &proof, b"slot_3", &mut output).is_err());
+    /// ```
+    pub fn verify_into(
+        public_key: &[u8; PUBLIC_KEY_SIZE],
+        proof: &[u8; PROOF_SIZE],
+        message: &[u8],
+        out: &mut [u8; OUTPUT_SIZE],
+    ) -> CryptoResult<()> {
+        *out = cardano_vrf_verify(public_key, proof, message)?;
+        Ok(())
+    }
+
     /// Extracts the VRF output hash directly from a proof without verification
     ///
     /// Computes the VRF output (SHA-512 hash of the Gamma point) from a proof
@@ -298,7 +364,7 @@ impl VrfDraft03 {
     ///
     /// # Errors
     ///
-    /// Returns [`crate::common::error::CryptoError::InvalidProof`] if the proof is malformed
+    /// Returns [`VrfError::MalformedProof`] if Gamma is not a curve point
     ///
     /// # Examples
     ///
@@ -326,7 +392,8 @@ impl VrfDraft03 {
             .try_into()
             .expect("proof gamma segment must be 32 bytes");
 
-        let gamma = bytes_to_point(&gamma_bytes)?;
+        let gamma = bytes_to_point(&gamma_bytes)
+            .map_err(|_| CryptoError::VrfError(VrfError::MalformedProof))?;
         let gamma_cleared = cardano_clear_cofactor(&gamma);
 
         let mut hasher = Sha512::new();
@@ -340,6 +407,46 @@ impl VrfDraft03 {
         Ok(output)
     }
 
+    /// Checks that a proof is well-formed, without verifying it
+    ///
+    /// Accepts exactly [`PROOF_SIZE`] bytes whose Gamma is a canonically
+    /// encoded curve point and whose response `s` is reduced modulo the group
+    /// order. The 16-byte challenge `c` is always below the group order. These
+    /// are the encodings libsodium's proof decoder rejects, so relays can drop
+    /// them before paying for [`verify`](Self::verify).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cardano_crypto::vrf::VrfDraft03;
+    ///
+    /// let (secret_key, _) = VrfDraft03::keypair_from_seed(&[6u8; 32]);
+    /// let proof = VrfDraft03::prove(&secret_key, b"slot").unwrap();
+    /// assert!(VrfDraft03::proof_is_valid_encoding(&proof));
+    ///
+    /// assert!(!VrfDraft03::proof_is_valid_encoding(&proof[..79]));
+    /// let mut unreduced = proof;
+    /// unreduced[79] |= 0xf0;
+    /// assert!(!VrfDraft03::proof_is_valid_encoding(&unreduced));
+    /// ```
+    #[must_use]
+    pub fn proof_is_valid_encoding(proof: &[u8]) -> bool {
+        let Ok(proof) = <&[u8; PROOF_SIZE]>::try_from(proof) else {
+            return false;
+        };
+        let gamma_bytes: [u8; 32] = proof[0..32]
+            .try_into()
+            .expect("proof gamma segment must be 32 bytes");
+        let s_bytes: [u8; 32] = proof[48..80]
+            .try_into()
+            .expect("proof scalar segment must be 32 bytes");
+
+        let gamma_is_canonical = CompressedEdwardsY(gamma_bytes)
+            .decompress()
+            .is_some_and(|gamma| gamma.compress().0 == gamma_bytes);
+        gamma_is_canonical && bool::from(Scalar::from_canonical_bytes(s_bytes).is_some())
+    }
+
     /// Generate keypair from seed
     ///
     /// Derives a deterministic Ed25519 keypair from a 32-byte seed using SHA-512
@@ -388,12 +495,73 @@ impl VrfDraft03 {
 
         (secret_key, public_key)
     }
+
+    /// Generate a keypair from a cryptographically secure RNG
+    ///
+    /// Draws a [`SEED_SIZE`]-byte seed, which is wiped once the keypair has
+    /// been derived with [`keypair_from_seed`](Self::keypair_from_seed).
+    ///
+    /// # Panics
+    ///
+    /// If `rng` fails to produce bytes, as
+    /// [`RngCore::fill_bytes`](rand_core::RngCore::fill_bytes) does.
+    #[cfg(feature = "rand")]
+    #[must_use]
+    pub fn generate<R: rand_core::RngCore + rand_core::CryptoRng>(
+        rng: &mut R,
+    ) -> ([u8; SECRET_KEY_SIZE], [u8; PUBLIC_KEY_SIZE]) {
+        let mut seed = Zeroizing::new([0u8; SEED_SIZE]);
+        rng.fill_bytes(seed.as_mut());
+        Self::keypair_from_seed(&seed)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_proof_encoding_validation() {
+        let (sk, _pk) = VrfDraft03::keypair_from_seed(&[21u8; SEED_SIZE]);
+        let proof = VrfDraft03::prove(&sk, b"relay").expect("prove failed");
+        assert!(VrfDraft03::proof_is_valid_encoding(&proof));
+
+        // Wrong lengths
+        assert!(!VrfDraft03::proof_is_valid_encoding(
+            &proof[..PROOF_SIZE - 1]
+        ));
+        assert!(!VrfDraft03::proof_is_valid_encoding(
+            &[proof.as_slice(), &[0]].concat()
+        ));
+        assert!(!VrfDraft03::proof_is_valid_encoding(&[]));
+
+        // Gamma with y = 2 is not on the curve
+        let mut off_curve = proof;
+        off_curve[0..32].fill(0);
+        off_curve[0] = 2;
+        assert!(!VrfDraft03::proof_is_valid_encoding(&off_curve));
+
+        // Gamma with y = p + 1 decodes like y = 1, but is not canonical
+        let mut non_canonical = proof;
+        non_canonical[0..32].fill(0xff);
+        non_canonical[0] = 0xee;
+        non_canonical[31] = 0x7f;
+        assert!(!VrfDraft03::proof_is_valid_encoding(&non_canonical));
+        non_canonical[0..32].fill(0);
+        non_canonical[0] = 1;
+        assert!(VrfDraft03::proof_is_valid_encoding(&non_canonical));
+
+        // s = L, the group order, is not reduced
+        let mut unreduced = proof;
+        unreduced[48..80].copy_from_slice(&[
+            0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9,
+            0xde, 0x14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10,
+        ]);
+        assert!(!VrfDraft03::proof_is_valid_encoding(&unreduced));
+        unreduced[48] -= 1;
+        assert!(VrfDraft03::proof_is_valid_encoding(&unreduced));
+    }
+
     #[test]
     fn test_prove_verify_roundtrip() {
         let seed = [42u8; SEED_SIZE];
@@ -415,7 +583,10 @@ mod tests {
         let invalid_proof = [0u8; PROOF_SIZE];
         let result = VrfDraft03::verify(&pk, &invalid_proof, message);
 
-        assert!(result.is_err());
+        assert_eq!(
+            result,
+            Err(CryptoError::VrfError(VrfError::VerificationFailed))
+        );
     }
 
     #[test]
@@ -0,0 +1,551 @@
+//! Slot leader check
+//!
+//! A stake pool with relative stake `σ` leads a slot when its VRF output,
+//! read as a fraction `p` of the output space, falls below the threshold
+//! `1 - (1 - f)^σ`, where `f` is the active slot coefficient. The ledger does
+//! not evaluate the threshold directly. With `q = 1 - p` and
+//! `c = ln(1 - f)`, it checks `1 / q < exp(-σ·c)` by summing the Taylor
+//! series of `exp` in 34-digit fixed point until the error bound decides the
+//! comparison. [`is_slot_leader_rational`] repeats that procedure step by
+//! step from the same rationals the ledger holds; [`is_slot_leader`] is a
+//! convenience for `f64` parameters.
+//!
+//! # Example
+//!
+//! ```rust
+//! use cardano_crypto::vrf::{is_slot_leader, VrfDraft03};
+//!
+//! let (secret_key, public_key) = VrfDraft03::keypair_from_seed(&[7u8; 32]);
+//! let proof = VrfDraft03::prove(&secret_key, b"slot 1042").unwrap();
+//! let output = VrfDraft03::verify(&public_key, &proof, b"slot 1042").unwrap();
+//!
+//! let leads = is_slot_leader(&output, 0.05, 0.001);
+//! # let _ = leads;
+//! // No stake never leads
+//! assert!(!is_slot_leader(&output, 0.05, 0.0));
+//! ```
+
+use core::cmp::Ordering;
+
+use crate::vrf::OUTPUT_SIZE;
+
+/// Check whether a VRF output wins the slot leader lottery
+///
+/// `output` is read as a big-endian natural number over `2^512`, the TPraos
+/// leader value. Praos-era callers pass whatever 64 bytes their era feeds
+/// to the check.
+///
+/// `active_slot_coeff` and `relative_stake` are `(numerator, denominator)`
+/// pairs, the rationals the ledger holds: `f` from the protocol parameters,
+/// e.g. `(1, 20)`, and `σ` as the pool's stake over the total active stake.
+/// Both are truncated to 34 decimal places, as the ledger's `fromRational`
+/// does, and so is `1 / q`. The comparison then follows the ledger's
+/// `taylorExpCmp`, with error bound 3 and at most 1000 terms. Running out of
+/// terms counts as not leading. The bound assumes `exp(x) <= 3`, which holds
+/// for `x = -σ·c <= 1`, so for any stake when `f <= 1 - 1/e`. Larger
+/// coefficients get the ledger's answer, not necessarily the exact one.
+///
+/// `ln(1 - f)` is computed to 34 decimal places. The ledger computes it
+/// with its own continued fraction, accurate to about 24 places. The two
+/// can disagree only for outputs within about `10^-24` of the threshold.
+///
+/// Returns `true` for every output when `f` is 1, and `false` when either
+/// denominator is 0, `f` is outside `(0, 1]` or `σ` is outside `[0, 1]`.
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::vrf::is_slot_leader_rational;
+///
+/// let mut output = [0u8; 64];
+/// // p = 0x0c/256 ≈ 0.047: below the 5% threshold of a pool with all stake
+/// output[0] = 0x0c;
+/// assert!(is_slot_leader_rational(&output, (1, 20), (1, 1)));
+/// // With half the stake the threshold is 1 - 0.95^0.5 ≈ 0.0253
+/// assert!(!is_slot_leader_rational(&output, (1, 20), (500, 1000)));
+/// ```
+#[must_use]
+pub fn is_slot_leader_rational(
+    output: &[u8; OUTPUT_SIZE],
+    active_slot_coeff: (u64, u64),
+    relative_stake: (u64, u64),
+) -> bool {
+    let ((f_num, f_den), (sigma_num, sigma_den)) = (active_slot_coeff, relative_stake);
+    if f_den == 0 || f_num == 0 || f_num > f_den {
+        return false;
+    }
+    if sigma_den == 0 || sigma_num > sigma_den {
+        return false;
+    }
+    if f_num == f_den {
+        return true;
+    }
+    leads(
+        output,
+        &fixed_from_ratio(f_num, f_den),
+        &fixed_from_ratio(sigma_num, sigma_den),
+    )
+}
+
+/// [`is_slot_leader_rational`] for parameters held as `f64`
+///
+/// `f` and `σ` are truncated to 34 decimal places exactly from their `f64`
+/// values, which are not the ledger's rationals: `0.05` is
+/// `0.05000000000000000277...`, so outputs within about `10^-18` of the
+/// threshold can be judged differently from the ledger. Use
+/// [`is_slot_leader_rational`] where the answer must match the node.
+///
+/// Returns `true` for every output when `active_slot_coeff` is 1, and
+/// `false` when `active_slot_coeff` is outside `(0, 1]` or `relative_stake`
+/// is outside `[0, 1]`.
+///
+/// # Example
+///
+/// ```rust
+/// use cardano_crypto::vrf::is_slot_leader;
+///
+/// let mut output = [0u8; 64];
+/// output[0] = 0x0c;
+/// assert!(is_slot_leader(&output, 0.05, 1.0));
+/// assert!(!is_slot_leader(&output, 0.05, 0.5));
+/// ```
+#[must_use]
+pub fn is_slot_leader(
+    output: &[u8; OUTPUT_SIZE],
+    active_slot_coeff: f64,
+    relative_stake: f64,
+) -> bool {
+    if active_slot_coeff.is_nan() || active_slot_coeff <= 0.0 || active_slot_coeff > 1.0 {
+        return false;
+    }
+    if !(0.0..=1.0).contains(&relative_stake) {
+        return false;
+    }
+    if active_slot_coeff == 1.0 {
+        return true;
+    }
+    leads(
+        output,
+        &fixed_from_f64(active_slot_coeff),
+        &fixed_from_f64(relative_stake),
+    )
+}
+
+/// The leader check for fixed-point `f` in `(0, 1)` and `σ` in `[0, 1]`
+fn leads(output: &[u8; OUTPUT_SIZE], active_slot_coeff: &Nat, relative_stake: &Nat) -> bool {
+    let scale = Nat::from_u128(FIXED_SCALE);
+
+    // recip_q = 2^512 / (2^512 - certNat)
+    let cert_nat = Nat::from_be_bytes(output);
+    let cert_nat_max = Nat::ONE.shl(OUTPUT_SIZE * 8);
+    let recip_q = scale.mul(&cert_nat_max).div(&cert_nat_max.sub(&cert_nat));
+
+    // x = -(σ * c) with c = floor(ln(1 - f)) < 0. Fixed-point products floor,
+    // so the magnitude of σ * c rounds up.
+    let ln_magnitude = neg_ln_fixed(&scale.sub(active_slot_coeff));
+    let x = relative_stake.mul(&ln_magnitude).div_ceil_fixed();
+
+    taylor_exp_below(&recip_q, &x)
+}
+
+/// Fixed-point resolution of the ledger's `FixedPoint` (`Fixed E34`)
+const FIXED_SCALE: u128 = 10u128.pow(34);
+
+/// Half of [`FIXED_SCALE`], which fits in a `u64`
+const HALF_SCALE: u64 = 10u64.pow(17);
+
+/// The ledger's `taylorExpCmp 3 cmp x`, answering `cmp < exp(x)`
+///
+/// The remainder after each term is taken to be at most 3 times the next
+/// term, which is sound for `x <= 1`.
+fn taylor_exp_below(cmp: &Nat, x: &Nat) -> bool {
+    const BOUND_X: u64 = 3;
+    const MAX_TERMS: u64 = 1000;
+
+    let mut acc = Nat::from_u128(FIXED_SCALE);
+    let mut err = *x;
+    let mut divisor = 1;
+    for _ in 0..MAX_TERMS {
+        divisor += 1;
+        acc = acc.add(&err);
+        err = err.mul(x).div_fixed().div_small(divisor);
+        let error_term = err.mul_small(BOUND_X);
+        if *cmp >= acc.add(&error_term) {
+            return false;
+        }
+        if acc > error_term && *cmp < acc.sub(&error_term) {
+            return true;
+        }
+    }
+    false
+}
+
+/// `floor(v * 10^34)`, exact for any finite `v` in `[0, 1]`
+fn fixed_from_f64(v: f64) -> Nat {
+    let bits = v.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    };
+    let scaled = Nat::from_u128(FIXED_SCALE).mul_small(mantissa);
+    if exponent >= 0 {
+        scaled.shl(exponent as usize)
+    } else {
+        scaled.shr(exponent.unsigned_abs() as usize)
+    }
+}
+
+/// `floor(num * 10^34 / den)`, the ledger's `fromRational`
+fn fixed_from_ratio(num: u64, den: u64) -> Nat {
+    Nat::from_u128(FIXED_SCALE)
+        .mul_small(num)
+        .div(&Nat::from_u128(u128::from(den)))
+}
+
+/// Fractional bits used while evaluating logarithms
+const LN_BITS: usize = 256;
+
+/// `ceil(-ln(a / 10^34) * 10^34)` for a fixed-point `a` in `(0, 1]`
+fn neg_ln_fixed(a: &Nat) -> Nat {
+    // y = 10^34 / a >= 1, with LN_BITS fractional bits
+    let y = Nat::from_u128(FIXED_SCALE).shl(LN_BITS).div(a);
+    // y = 2^k * m with m in [1, 2), so ln y = k ln 2 + ln m
+    let k = y.bits() - 1 - LN_BITS;
+    let m = y.shr(k);
+    let one = Nat::ONE.shl(LN_BITS);
+    let ln2 = ln_of_ratio(&one.mul_small(2), &one);
+    let ln_y = ln2.mul_small(k as u64).add(&ln_of_ratio(&m, &one));
+    ln_y.mul(&Nat::from_u128(FIXED_SCALE))
+        .div_ceil_pow2(LN_BITS)
+}
+
+/// `ln(num / den)` for `num >= den`, with [`LN_BITS`] fractional bits
+///
+/// Uses `ln(t) = 2 atanh((t - 1) / (t + 1))`, which converges quickly for
+/// `t` in `[1, 2]`.
+fn ln_of_ratio(num: &Nat, den: &Nat) -> Nat {
+    let z = num.sub(den).shl(LN_BITS).div(&num.add(den));
+    let z_squared = z.mul(&z).shr(LN_BITS);
+    let mut power = z;
+    let mut sum = Nat::ZERO;
+    let mut odd = 1;
+    while power != Nat::ZERO {
+        sum = sum.add(&power.div_small(odd));
+        power = power.mul(&z_squared).shr(LN_BITS);
+        odd += 2;
+    }
+    sum.shl(1)
+}
+
+/// Number of 64-bit limbs, enough for `10^34 * 2^512` and every
+/// intermediate product
+const LIMBS: usize = 12;
+
+/// Fixed-width unsigned integer, little-endian limbs
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Nat([u64; LIMBS]);
+
+impl Nat {
+    const ZERO: Self = Self([0; LIMBS]);
+    const ONE: Self = {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = 1;
+        Self(limbs)
+    };
+
+    fn from_u128(v: u128) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = v as u64;
+        limbs[1] = (v >> 64) as u64;
+        Self(limbs)
+    }
+
+    fn from_be_bytes(bytes: &[u8; OUTPUT_SIZE]) -> Self {
+        let mut limbs = [0; LIMBS];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks_exact(8)) {
+            *limb = u64::from_be_bytes(chunk.try_into().expect("8-byte chunk"));
+        }
+        Self(limbs)
+    }
+
+    fn bits(&self) -> usize {
+        match self.0.iter().rposition(|&limb| limb != 0) {
+            Some(top) => top * 64 + 64 - self.0[top].leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        (self.0[index / 64] >> (index % 64)) & 1 == 1
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let mut limbs = [0; LIMBS];
+        let mut carry = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (sum, c1) = self.0[i].overflowing_add(other.0[i]);
+            let (sum, c2) = sum.overflowing_add(u64::from(carry));
+            *limb = sum;
+            carry = c1 || c2;
+        }
+        debug_assert!(!carry, "Nat addition overflow");
+        Self(limbs)
+    }
+
+    /// `self - other`; `other` must not be larger
+    fn sub(&self, other: &Self) -> Self {
+        let mut limbs = [0; LIMBS];
+        let mut borrow = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (diff, b1) = self.0[i].overflowing_sub(other.0[i]);
+            let (diff, b2) = diff.overflowing_sub(u64::from(borrow));
+            *limb = diff;
+            borrow = b1 || b2;
+        }
+        debug_assert!(!borrow, "Nat subtraction underflow");
+        Self(limbs)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        let mut limbs = [0; LIMBS];
+        for i in 0..LIMBS {
+            if self.0[i] == 0 {
+                continue;
+            }
+            let mut carry = 0u128;
+            for j in 0..LIMBS - i {
+                let wide = u128::from(self.0[i]) * u128::from(other.0[j])
+                    + u128::from(limbs[i + j])
+                    + carry;
+                limbs[i + j] = wide as u64;
+                carry = wide >> 64;
+            }
+        }
+        debug_assert!(self.bits() + other.bits() <= LIMBS * 64, "Nat overflow");
+        Self(limbs)
+    }
+
+    fn mul_small(&self, factor: u64) -> Self {
+        self.mul(&Self::from_u128(u128::from(factor)))
+    }
+
+    fn div_small(&self, divisor: u64) -> Self {
+        let mut limbs = [0; LIMBS];
+        let mut rem = 0u128;
+        for i in (0..LIMBS).rev() {
+            let wide = (rem << 64) | u128::from(self.0[i]);
+            limbs[i] = (wide / u128::from(divisor)) as u64;
+            rem = wide % u128::from(divisor);
+        }
+        Self(limbs)
+    }
+
+    /// `floor(self / 10^34)`
+    fn div_fixed(&self) -> Self {
+        self.div_small(HALF_SCALE).div_small(HALF_SCALE)
+    }
+
+    /// `ceil(self / 10^34)`
+    fn div_ceil_fixed(&self) -> Self {
+        let quotient = self.div_fixed();
+        if quotient.mul(&Self::from_u128(FIXED_SCALE)) == *self {
+            quotient
+        } else {
+            quotient.add(&Self::ONE)
+        }
+    }
+
+    /// `ceil(self / 2^shift)`
+    fn div_ceil_pow2(&self, shift: usize) -> Self {
+        let quotient = self.shr(shift);
+        if quotient.shl(shift) == *self {
+            quotient
+        } else {
+            quotient.add(&Self::ONE)
+        }
+    }
+
+    fn shl(&self, shift: usize) -> Self {
+        let mut limbs = [0; LIMBS];
+        let (words, bits) = (shift / 64, shift % 64);
+        for i in (words..LIMBS).rev() {
+            limbs[i] = self.0[i - words] << bits;
+            if bits > 0 && i > words {
+                limbs[i] |= self.0[i - words - 1] >> (64 - bits);
+            }
+        }
+        Self(limbs)
+    }
+
+    fn shr(&self, shift: usize) -> Self {
+        let mut limbs = [0; LIMBS];
+        let (words, bits) = (shift / 64, shift % 64);
+        for (i, limb) in limbs
+            .iter_mut()
+            .enumerate()
+            .take(LIMBS.saturating_sub(words))
+        {
+            *limb = self.0[i + words] >> bits;
+            if bits > 0 && i + words + 1 < LIMBS {
+                *limb |= self.0[i + words + 1] << (64 - bits);
+            }
+        }
+        Self(limbs)
+    }
+
+    /// `floor(self / divisor)` by shift and subtract
+    fn div(&self, divisor: &Self) -> Self {
+        debug_assert!(*divisor != Self::ZERO, "Nat division by zero");
+        let mut quotient = Self::ZERO;
+        let mut rem = Self::ZERO;
+        for i in (0..self.bits()).rev() {
+            rem = rem.shl(1);
+            rem.0[0] |= u64::from(self.bit(i));
+            if rem >= *divisor {
+                rem = rem.sub(divisor);
+                quotient.0[i / 64] |= 1 << (i % 64);
+            }
+        }
+        quotient
+    }
+}
+
+impl Ord for Nat {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for Nat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Output whose leading 8 bytes are `p * 2^64`, i.e. `p` over `2^512`
+    fn output_at(p: f64) -> [u8; OUTPUT_SIZE] {
+        let mut output = [0u8; OUTPUT_SIZE];
+        output[..8].copy_from_slice(&((p * 2f64.powi(64)) as u64).to_be_bytes());
+        output
+    }
+
+    #[test]
+    fn full_stake_threshold_is_the_active_slot_coefficient() {
+        assert!(is_slot_leader(&[0u8; OUTPUT_SIZE], 0.05, 1.0));
+        assert!(is_slot_leader(&output_at(0.049_999), 0.05, 1.0));
+        assert!(!is_slot_leader(&output_at(0.050_001), 0.05, 1.0));
+        assert!(!is_slot_leader(&[0xff; OUTPUT_SIZE], 0.05, 1.0));
+    }
+
+    #[test]
+    fn boundary_stakes() {
+        // No stake never leads, not even with the smallest output
+        assert!(!is_slot_leader(&[0u8; OUTPUT_SIZE], 0.05, 0.0));
+
+        // Half the stake: 1 - 0.95^0.5 = 0.025320565519103...
+        assert!(is_slot_leader(&output_at(0.025_320_5), 0.05, 0.5));
+        assert!(!is_slot_leader(&output_at(0.025_320_6), 0.05, 0.5));
+
+        // A tiny pool: 1 - 0.95^1e-9 = 5.1293294...e-11
+        assert!(is_slot_leader(&output_at(5.129e-11), 0.05, 1e-9));
+        assert!(!is_slot_leader(&output_at(5.130e-11), 0.05, 1e-9));
+
+        // The largest x the error bound covers is ln 2 at f = 0.5
+        assert!(is_slot_leader(&output_at(0.499_999), 0.5, 1.0));
+        assert!(!is_slot_leader(&output_at(0.500_001), 0.5, 1.0));
+
+        // f = 1 always leads
+        assert!(is_slot_leader(&[0xff; OUTPUT_SIZE], 1.0, 0.0));
+    }
+
+    #[test]
+    fn out_of_range_parameters_never_lead() {
+        let output = [0u8; OUTPUT_SIZE];
+        assert!(!is_slot_leader(&output, 0.0, 1.0));
+        assert!(!is_slot_leader(&output, 1.5, 1.0));
+        assert!(!is_slot_leader(&output, f64::NAN, 1.0));
+        assert!(!is_slot_leader(&output, 0.05, 1.5));
+        assert!(!is_slot_leader(&output, 0.05, -0.1));
+        assert!(!is_slot_leader(&output, 0.05, f64::NAN));
+    }
+
+    #[test]
+    fn rational_parameters_match_the_ledger_exactly() {
+        // 0x0ccc...cd / 2^64 lies between 1/20 and the f64 0.05, so only the
+        // rational check sees it above the full-stake threshold of 1/20
+        let mut output = [0u8; OUTPUT_SIZE];
+        output[..8].copy_from_slice(&0x0ccc_cccc_cccc_cccd_u64.to_be_bytes());
+        assert!(!is_slot_leader_rational(&output, (1, 20), (1, 1)));
+        assert!(is_slot_leader(&output, 0.05, 1.0));
+
+        // Otherwise the two agree
+        for (p, sigma) in [
+            (0.025_320_5, (1, 2)),
+            (0.025_320_6, (1, 2)),
+            (0.049_999, (1, 1)),
+        ] {
+            let output = output_at(p);
+            let stake = sigma.0 as f64 / sigma.1 as f64;
+            assert_eq!(
+                is_slot_leader_rational(&output, (1, 20), sigma),
+                is_slot_leader(&output, 0.05, stake),
+                "p = {p}"
+            );
+        }
+
+        // A pool's share of the total stake, in lovelace
+        let total = 25_000_000_000_000_000;
+        assert!(is_slot_leader_rational(
+            &output_at(5.129e-11),
+            (1, 20),
+            (25_000_000, total)
+        ));
+        assert!(!is_slot_leader_rational(
+            &output_at(5.130e-11),
+            (1, 20),
+            (25_000_000, total)
+        ));
+    }
+
+    #[test]
+    fn out_of_range_rationals_never_lead() {
+        let output = [0u8; OUTPUT_SIZE];
+        assert!(!is_slot_leader_rational(&output, (0, 20), (1, 1)));
+        assert!(!is_slot_leader_rational(&output, (1, 0), (1, 1)));
+        assert!(!is_slot_leader_rational(&output, (21, 20), (1, 1)));
+        assert!(!is_slot_leader_rational(&output, (1, 20), (1, 0)));
+        assert!(!is_slot_leader_rational(&output, (1, 20), (3, 2)));
+        assert!(is_slot_leader_rational(
+            &[0xff; OUTPUT_SIZE],
+            (20, 20),
+            (0, 1)
+        ));
+    }
+
+    #[test]
+    fn fixed_point_conversions_are_exact() {
+        assert_eq!(fixed_from_f64(1.0), Nat::from_u128(FIXED_SCALE));
+        assert_eq!(fixed_from_f64(0.5), Nat::from_u128(FIXED_SCALE / 2));
+        assert_eq!(fixed_from_f64(0.0), Nat::ZERO);
+        assert_eq!(fixed_from_ratio(1, 20), Nat::from_u128(FIXED_SCALE / 20));
+        // 1/3 truncates
+        assert_eq!(fixed_from_ratio(1, 3), Nat::from_u128(FIXED_SCALE / 3));
+        // 0.05 is 0.05000000000000000277... as an f64
+        assert_eq!(
+            fixed_from_f64(0.05),
+            Nat::from_u128(500_000_000_000_000_027_755_575_615_628_913)
+        );
+        // -ln(0.5) = 0.6931471805599453094172321214581765...
+        assert_eq!(
+            neg_ln_fixed(&Nat::from_u128(FIXED_SCALE / 2)),
+            Nat::from_u128(6_931_471_805_599_453_094_172_321_214_581_766)
+        );
+    }
+}
@@ -42,6 +42,7 @@
 pub mod cardano_compat;
 pub mod draft03;
 pub mod draft13;
+pub mod leader;
 pub mod test_vectors;
 
 // Re-export main types
@@ -51,6 +52,58 @@ pub use draft03::{
 };
 
 pub use draft13::{VrfDraft13, PROOF_SIZE as DRAFT13_PROOF_SIZE};
+pub use leader::{is_slot_leader, is_slot_leader_rational};
+
+/// VRF-specific errors
+///
+/// VRF functions return these wrapped in
+/// [`CryptoError::VrfError`](crate::common::CryptoError::VrfError), as KES
+/// functions wrap [`KesError`](crate::kes::KesError). Both convert into
+/// [`CryptoError`](crate::common::CryptoError) with `From`, so `?` works
+/// across the two.
+///
+/// # Examples
+///
+/// ```rust
+/// use cardano_crypto::common::CryptoError;
+/// use cardano_crypto::vrf::{VrfDraft03, VrfError};
+///
+/// let (secret_key, public_key) = VrfDraft03::keypair_from_seed(&[1u8; 32]);
+/// let proof = VrfDraft03::prove(&secret_key, b"slot").unwrap();
+/// assert_eq!(
+///     VrfDraft03::verify(&public_key, &proof, b"other slot"),
+///     Err(CryptoError::VrfError(VrfError::VerificationFailed))
+/// );
+/// assert_eq!(
+///     CryptoError::from(VrfError::MalformedProof).to_string(),
+///     "VRF error: Malformed VRF proof"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VrfError {
+    /// Public key bytes are not a valid curve point
+    InvalidPublicKey,
+    /// Proof bytes do not decode: Gamma is not a valid curve point
+    MalformedProof,
+    /// Hash-to-curve found no point for the key and message
+    HashToCurveFailed,
+    /// Proof is well-formed but does not verify
+    VerificationFailed,
+}
+
+impl core::fmt::Display for VrfError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidPublicKey => write!(f, "Invalid VRF public key"),
+            Self::MalformedProof => write!(f, "Malformed VRF proof"),
+            Self::HashToCurveFailed => write!(f, "VRF hash-to-curve failed"),
+            Self::VerificationFailed => write!(f, "VRF proof verification failed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VrfError {}
 
 // Re-export Cardano compatibility functions for advanced usage
 pub use cardano_compat::{
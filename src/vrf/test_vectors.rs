@@ -25,8 +25,8 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::common::Result;
-    use crate::vrf::{VrfDraft03, VrfDraft13};
+    use crate::common::{CryptoError, Result};
+    use crate::vrf::{VrfDraft03, VrfDraft13, VrfError};
 
     /// Test VRF Draft-03 determinism
     #[test]
@@ -145,7 +145,10 @@ mod tests {
 
         // Verify with wrong public key should fail
         let result = VrfDraft03::verify(&pk2, &proof, message);
-        assert!(result.is_err());
+        assert_eq!(
+            result,
+            Err(CryptoError::VrfError(VrfError::VerificationFailed))
+        );
 
         Ok(())
     }
@@ -166,7 +169,10 @@ mod tests {
 
         // Verify with wrong message (should fail)
         let result = VrfDraft03::verify(&pk, &proof, message2);
-        assert!(result.is_err());
+        assert_eq!(
+            result,
+            Err(CryptoError::VrfError(VrfError::VerificationFailed))
+        );
 
         Ok(())
     }
@@ -304,7 +310,10 @@ mod tests {
 
         // Attempt to use same proof with different message (should fail)
         let result = VrfDraft03::verify(&pk, &proof, message2);
-        assert!(result.is_err());
+        assert_eq!(
+            result,
+            Err(CryptoError::VrfError(VrfError::VerificationFailed))
+        );
 
         Ok(())
     }
@@ -0,0 +1,67 @@
+//! Best-effort check that `update_kes` takes the same time at every period
+//!
+//! Without padding, a Sum4 update costs one leaf key generation at odd
+//! boundaries and a whole Sum3 subtree at the midpoint, and the final step
+//! does no work at all, so the period can be read off the timing. This test
+//! times every step several times and compares the per-period medians.
+//!
+//! Timing on a shared machine is noisy, so the tests are ignored by default.
+//! CI runs them in a dedicated job with `cargo test --release --features
+//! constant-time --test kes_constant_time_update -- --ignored`.
+
+#![cfg(feature = "constant-time")]
+
+use std::time::{Duration, Instant};
+
+use cardano_crypto::kes::{CompactSum4Kes, KesAlgorithm, Sum4Kes};
+
+#[macro_use]
+#[path = "../src/kes/typed_tests.rs"]
+mod typed_tests;
+
+const ROUNDS: usize = 15;
+
+/// Median time of `update_kes` at each period, over `ROUNDS` fresh keys
+fn median_update_times<K: KesAlgorithm<Context = ()>>() -> Vec<Duration> {
+    let periods = K::total_periods() as usize;
+    let mut samples = vec![Vec::with_capacity(ROUNDS); periods];
+
+    for round in 0..ROUNDS {
+        let mut sk = Some(K::gen_key_kes_from_seed_bytes(&[round as u8; 32]).unwrap());
+        for (period, times) in samples.iter_mut().enumerate() {
+            let key = sk.take().unwrap();
+            let start = Instant::now();
+            sk = K::update_kes(&(), key, period as u64).unwrap();
+            times.push(start.elapsed());
+        }
+        assert!(sk.is_none());
+    }
+
+    samples
+        .into_iter()
+        .map(|mut times| {
+            times.sort();
+            times[ROUNDS / 2]
+        })
+        .collect()
+}
+
+typed_tests! {
+    fn update_time_is_independent_of_period<K>() for {
+        #[ignore = "timing-sensitive; run with --ignored"]
+        sum4: Sum4Kes,
+        #[ignore = "timing-sensitive; run with --ignored"]
+        compact_sum4: CompactSum4Kes,
+    } {
+        let medians = median_update_times::<K>();
+        let fastest = *medians.iter().min().unwrap();
+        let slowest = *medians.iter().max().unwrap();
+
+        // Unpadded, the ratio is several hundred; padded steps do identical
+        // work, so only scheduling noise separates them
+        assert!(
+            slowest < fastest * 3,
+            "update times range from {fastest:?} to {slowest:?}: {medians:?}"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Checks that forgetting or evolving a KES signing key leaves no spent
+//! secret in freed memory
+//!
+//! A wrapping global allocator scans every block as it is freed for a known
+//! secret pattern. This binary holds a single test so no other thread
+//! allocates while the check is armed.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
+
+use cardano_crypto::kes::{CompactSum3Kes, KesAlgorithm, Sum2Kes, Sum3Kes, UnsoundKesAlgorithm};
+
+struct ScanOnFree;
+
+static ARMED: AtomicBool = AtomicBool::new(false);
+static LEAKS: AtomicUsize = AtomicUsize::new(0);
+static PATTERN: [AtomicU8; 32] = [const { AtomicU8::new(0) }; 32];
+
+unsafe impl GlobalAlloc for ScanOnFree {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ARMED.load(Ordering::SeqCst) && layout.size() >= PATTERN.len() {
+            let mut pattern = [0u8; 32];
+            for (byte, stored) in pattern.iter_mut().zip(PATTERN.iter()) {
+                *byte = stored.load(Ordering::SeqCst);
+            }
+            // SAFETY: the block is still allocated and `layout.size()` bytes long
+            let block = unsafe { std::slice::from_raw_parts(ptr, layout.size()) };
+            if block.windows(pattern.len()).any(|window| window == pattern) {
+                LEAKS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: ScanOnFree = ScanOnFree;
+
+fn arm(pattern: &[u8; 32]) {
+    for (stored, byte) in PATTERN.iter().zip(pattern) {
+        stored.store(*byte, Ordering::SeqCst);
+    }
+    LEAKS.store(0, Ordering::SeqCst);
+    ARMED.store(true, Ordering::SeqCst);
+}
+
+fn disarm() -> usize {
+    ARMED.store(false, Ordering::SeqCst);
+    LEAKS.load(Ordering::SeqCst)
+}
+
+#[test]
+fn forget_and_update_wipe_spent_secrets() {
+    // Control: a block still holding the pattern is detected
+    let canary = [0xA5u8; 32];
+    let copy = canary.to_vec();
+    arm(&canary);
+    drop(copy);
+    assert_eq!(disarm(), 1);
+
+    let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[0x3Cu8; 32]).unwrap();
+
+    // Layout: Sum2 signing key || r1_seed || vk0 || vk1
+    let mut r1_seed = [0u8; 32];
+    {
+        let bytes = Sum3Kes::raw_serialize_signing_key_kes(&sk);
+        let offset = Sum2Kes::SIGNING_KEY_SIZE;
+        r1_seed.copy_from_slice(&bytes[offset..offset + 32]);
+    }
+    assert_ne!(r1_seed, [0u8; 32]);
+
+    arm(&r1_seed);
+    Sum3Kes::forget_signing_key_kes(sk);
+    assert_eq!(disarm(), 0, "right-subtree seed left in freed memory");
+
+    // Evolving into the right subtree consumes the seed the same way
+    let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[0x3Cu8; 32]).unwrap();
+    let sk = Sum3Kes::update_kes_to(&(), sk, 0, 3).unwrap().unwrap();
+    arm(&r1_seed);
+    let sk = Sum3Kes::update_kes(&(), sk, 3).unwrap().unwrap();
+    assert_eq!(disarm(), 0, "consumed seed left in freed memory");
+    Sum3Kes::forget_signing_key_kes(sk);
+
+    // Evolving from period 0 to 1 wipes the period-0 leaf secret, which
+    // leads the serialized key, and the evolved key no longer holds it
+    let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[0x3Du8; 32]).unwrap();
+    let mut leaf0 = [0u8; 32];
+    leaf0.copy_from_slice(&Sum3Kes::raw_serialize_signing_key_kes(&sk)[..32]);
+    assert_ne!(leaf0, [0u8; 32]);
+
+    arm(&leaf0);
+    let sk = Sum3Kes::update_kes(&(), sk, 0).unwrap().unwrap();
+    assert_eq!(disarm(), 0, "period-0 leaf secret left in freed memory");
+    let evolved = Sum3Kes::raw_serialize_signing_key_kes(&sk);
+    assert!(!evolved.windows(32).any(|window| window == leaf0));
+    drop(evolved);
+    Sum3Kes::forget_signing_key_kes(sk);
+
+    // The same holds for CompactSum, whose bottom level moves from its left
+    // leaf to its right one at this step
+    let sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&[0x3Eu8; 32]).unwrap();
+    let mut leaf0 = [0u8; 32];
+    leaf0.copy_from_slice(&CompactSum3Kes::raw_serialize_signing_key_kes(&sk)[..32]);
+    assert_ne!(leaf0, [0u8; 32]);
+
+    arm(&leaf0);
+    let sk = CompactSum3Kes::update_kes(&(), sk, 0).unwrap().unwrap();
+    assert_eq!(
+        disarm(),
+        0,
+        "compact period-0 leaf secret left in freed memory"
+    );
+    let evolved = CompactSum3Kes::raw_serialize_signing_key_kes(&sk);
+    assert!(!evolved.windows(32).any(|window| window == leaf0));
+    drop(evolved);
+    CompactSum3Kes::forget_signing_key_kes(sk);
+}
@@ -1,7 +1,9 @@
 //! Golden test vectors for KES implementations (Cardano-compatible)
 
-use cardano_crypto::common::Result;
-use cardano_crypto::kes::{KesAlgorithm, SingleKes, Sum2Kes, Sum6Kes};
+use cardano_crypto::common::{CryptoError, Result};
+use cardano_crypto::kes::{
+    CompactSum6Kes, KesAlgorithm, KesError, SingleKes, Sum2Kes, Sum3Kes, Sum6Kes,
+};
 
 #[test]
 fn test_single_kes_basic() -> Result<()> {
@@ -15,7 +17,13 @@ fn test_single_kes_basic() -> Result<()> {
     let sig = TestKes::sign_kes(&(), 0, message, &sk)?;
 
     assert!(TestKes::verify_kes(&(), &vk, 0, message, &sig).is_ok());
-    assert!(TestKes::verify_kes(&(), &vk, 1, message, &sig).is_err());
+    assert_eq!(
+        TestKes::verify_kes(&(), &vk, 1, message, &sig),
+        Err(CryptoError::KesError(KesError::PeriodOutOfRange {
+            period: 1,
+            max_period: 0,
+        }))
+    );
     assert!(TestKes::update_kes(&(), sk, 0)?.is_none());
 
     Ok(())
@@ -76,7 +84,14 @@ fn test_sum6_kes_cardano_standard() -> Result<()> {
         let sig = Sum6Kes::sign_kes(&(), period, message, &sk)?;
         assert!(Sum6Kes::verify_kes(&(), &vk, period, message, &sig).is_ok());
         if period > 0 {
-            assert!(Sum6Kes::verify_kes(&(), &vk, period - 1, message, &sig).is_err());
+            let err = Sum6Kes::verify_kes(&(), &vk, period - 1, message, &sig).unwrap_err();
+            assert_eq!(
+                Sum6Kes::diagnose_verify_failure(&vk, period - 1, message, &sig, err),
+                CryptoError::KesError(KesError::PeriodMismatch {
+                    requested: period - 1,
+                    signed: period,
+                })
+            );
         }
     }
     Ok(())
@@ -114,7 +129,15 @@ fn test_cross_period_validation_failure() -> Result<()> {
     let vk = Sum2Kes::derive_verification_key(&sk)?;
     let sig = Sum2Kes::sign_kes(&(), 0, b"test", &sk)?;
     assert!(Sum2Kes::verify_kes(&(), &vk, 0, b"test", &sig).is_ok());
-    assert!(Sum2Kes::verify_kes(&(), &vk, 1, b"test", &sig).is_err());
+    let err = Sum2Kes::verify_kes(&(), &vk, 1, b"test", &sig).unwrap_err();
+    assert_eq!(err, CryptoError::KesError(KesError::BadEd25519Signature));
+    assert_eq!(
+        Sum2Kes::diagnose_verify_failure(&vk, 1, b"test", &sig, err),
+        CryptoError::KesError(KesError::PeriodMismatch {
+            requested: 1,
+            signed: 0,
+        })
+    );
     Ok(())
 }
 
@@ -135,3 +158,80 @@ fn test_deterministic_key_generation() -> Result<()> {
     assert_eq!(sig1_bytes, sig2_bytes);
     Ok(())
 }
+
+#[test]
+fn test_sum3_kes_full_evolution_loop() -> Result<()> {
+    let seed = vec![0x46u8; Sum3Kes::SEED_SIZE];
+    let mut sk = Sum3Kes::gen_key_kes_from_seed_bytes(&seed)?;
+    let vk = Sum3Kes::derive_verification_key(&sk)?;
+
+    for period in 0..Sum3Kes::total_periods() {
+        let msg = format!("Period {}", period);
+        let sig = Sum3Kes::sign_kes(&(), period, msg.as_bytes(), &sk)?;
+        assert!(Sum3Kes::verify_kes(&(), &vk, period, msg.as_bytes(), &sig).is_ok());
+
+        match Sum3Kes::update_kes(&(), sk, period)? {
+            Some(next) => sk = next,
+            None => {
+                assert_eq!(period, Sum3Kes::total_periods() - 1);
+                return Ok(());
+            }
+        }
+    }
+    panic!("Sum3Kes key should expire after period 7");
+}
+
+#[test]
+fn test_sum_kes_sizes_match_cardano() {
+    // Sizes from cardano-base's SumKES over Ed25519DSIGN / Blake2b_256
+    assert_eq!(Sum6Kes::SEED_SIZE, 32);
+    assert_eq!(Sum6Kes::VERIFICATION_KEY_SIZE, 32);
+    assert_eq!(Sum6Kes::SIGNATURE_SIZE, 448);
+    assert_eq!(Sum3Kes::SIGNATURE_SIZE, 64 + 3 * 64);
+}
+
+#[test]
+fn test_compact_sum6_signature_roundtrip_and_size() -> Result<()> {
+    let seed = [0x47u8; 32];
+    let sk = CompactSum6Kes::gen_key_kes_from_seed_bytes(&seed)?;
+    let vk = CompactSum6Kes::derive_verification_key(&sk)?;
+    let sk = CompactSum6Kes::update_kes_to(&(), sk, 0, 37)?.expect("Updated key");
+
+    let sig = CompactSum6Kes::sign_kes(&(), 37, b"compact", &sk)?;
+    let sig_bytes = CompactSum6Kes::raw_serialize_signature_kes(&sig);
+    assert_eq!(sig_bytes.len(), CompactSum6Kes::SIGNATURE_SIZE);
+
+    let sig_restored = CompactSum6Kes::raw_deserialize_signature_kes(&sig_bytes)
+        .expect("Deserialization should succeed");
+    assert_eq!(
+        CompactSum6Kes::raw_serialize_signature_kes(&sig_restored),
+        sig_bytes
+    );
+    assert!(CompactSum6Kes::verify_kes(&(), &vk, 37, b"compact", &sig_restored).is_ok());
+
+    // Compact signatures carry one verification key per level instead of two
+    assert_eq!(CompactSum6Kes::SIGNATURE_SIZE, 64 + 32 + 6 * 32);
+    let sum6_sig = Sum6Kes::sign_kes(
+        &(),
+        0,
+        b"compact",
+        &Sum6Kes::gen_key_kes_from_seed_bytes(&seed)?,
+    )?;
+    assert!(sig_bytes.len() < Sum6Kes::raw_serialize_signature_kes(&sum6_sig).len());
+    Ok(())
+}
+
+#[test]
+fn test_verification_key_hash() -> Result<()> {
+    // The Sum6 key for seed [0x5a; 32], as in test_vectors/kes_sum6.vkey
+    let vk = Sum6Kes::verification_key_from_seed(&[0x5au8; 32])?;
+    assert_eq!(
+        hex::encode(Sum6Kes::raw_serialize_verification_key_kes(&vk)),
+        "2b5c7dac4eb6d7bc2742b4fee46c119f71d635915a909cce024de51acf1a33a9"
+    );
+    assert_eq!(
+        hex::encode(Sum6Kes::verification_key_hash(&vk)),
+        "e0dd867d9a8b1cd416e2a1e329a16e73b0c3c4fd06f56247781cb235"
+    );
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! Signing and verification latencies land in the `kes-metrics` histograms
+//!
+//! Kept in its own binary so no other test records latencies concurrently.
+
+#![cfg(feature = "kes-metrics")]
+
+use cardano_crypto::kes::{KesAlgorithm, Sum4Kes};
+use cardano_crypto::metrics;
+
+fn total(buckets: &[u64]) -> u64 {
+    buckets.iter().sum()
+}
+
+#[test]
+fn signing_many_messages_populates_latency_buckets() {
+    let sk = Sum4Kes::gen_key_kes_from_seed_bytes(&[41u8; 32]).unwrap();
+    let vk = Sum4Kes::derive_verification_key(&sk).unwrap();
+
+    let before = metrics::snapshot();
+    for i in 0..64u32 {
+        let message = i.to_be_bytes();
+        let sig = Sum4Kes::sign_kes(&(), 0, &message, &sk).unwrap();
+        Sum4Kes::verify_kes(&(), &vk, 0, &message, &sig).unwrap();
+    }
+    let after = metrics::snapshot();
+
+    // The Sum recursion is timed once per call, not once per level
+    assert_eq!(
+        total(&after.sign_latency_ns) - total(&before.sign_latency_ns),
+        64
+    );
+    assert_eq!(
+        total(&after.verify_latency_ns) - total(&before.verify_latency_ns),
+        64
+    );
+    assert!(after.sign_latency_ns.iter().any(|&count| count > 0));
+
+    let per_algorithm = metrics::snapshot_for("Sum4Kes");
+    assert_eq!(total(&per_algorithm.sign_latency_ns), 64);
+    assert_eq!(total(&per_algorithm.verify_latency_ns), 64);
+}
@@ -0,0 +1,48 @@
+//! Allocation-free serialization, built as a `no_std` crate
+//!
+//! Run with `cargo test --no-default-features --features kes --test kes_no_std_serialize`
+//! to check it without the standard library.
+
+#![no_std]
+
+use cardano_crypto::kes::{KesAlgorithm, KesError, Sum6Kes, UnsoundKesAlgorithm};
+
+#[test]
+fn verification_key_fits_a_stack_buffer() {
+    let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[0x38u8; 32]).unwrap();
+    let vk = Sum6Kes::derive_verification_key(&sk).unwrap();
+
+    let mut out = [0u8; Sum6Kes::VERIFICATION_KEY_SIZE];
+    let written = Sum6Kes::raw_serialize_verification_key_kes_into(&vk, &mut out).unwrap();
+    assert_eq!(written, Sum6Kes::VERIFICATION_KEY_SIZE);
+    assert_eq!(
+        Sum6Kes::raw_deserialize_verification_key_kes(&out[..written]),
+        Some(vk)
+    );
+}
+
+#[test]
+fn signature_and_signing_key_fit_stack_buffers() {
+    let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[0x39u8; 32]).unwrap();
+    let vk = Sum6Kes::derive_verification_key(&sk).unwrap();
+    let sig = Sum6Kes::sign_kes(&(), 0, b"header", &sk).unwrap();
+
+    let mut sig_out = [0u8; Sum6Kes::SIGNATURE_SIZE];
+    Sum6Kes::raw_serialize_signature_kes_into(&sig, &mut sig_out).unwrap();
+    let decoded = Sum6Kes::raw_deserialize_signature_kes(&sig_out).unwrap();
+    Sum6Kes::verify_kes(&(), &vk, 0, b"header", &decoded).unwrap();
+
+    let mut sk_out = [0u8; Sum6Kes::SIGNING_KEY_SIZE];
+    Sum6Kes::raw_serialize_signing_key_kes_into(&sk, &mut sk_out).unwrap();
+    let restored = Sum6Kes::raw_deserialize_signing_key_kes(&sk_out).unwrap();
+    assert_eq!(Sum6Kes::derive_verification_key(&restored).unwrap(), vk);
+
+    let mut short = [0u8; 16];
+    assert_eq!(
+        Sum6Kes::raw_serialize_signature_kes_into(&sig, &mut short),
+        Err(KesError::BufferTooSmall {
+            required: Sum6Kes::SIGNATURE_SIZE,
+            available: 16,
+        })
+    );
+}
@@ -0,0 +1,36 @@
+//! Sum6 verification from raw bytes, built as a `no_std` crate
+//!
+//! This is the path the `examples/wasm_verify` crate exposes to the browser.
+//! Run with `cargo test --no-default-features --features kes --test kes_no_std_verify`
+//! to check it without the standard library.
+
+#![no_std]
+
+use cardano_crypto::kes::{KesAlgorithm, KesError, Sum6Kes};
+
+#[test]
+fn verifies_from_raw_bytes_with_an_explicit_seed() {
+    let seed = [0x3du8; 32];
+    let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&seed).unwrap();
+    let sig = Sum6Kes::sign_kes(&(), 0, b"header", &sk).unwrap();
+
+    let mut vk_bytes = [0u8; Sum6Kes::VERIFICATION_KEY_SIZE];
+    let vk = Sum6Kes::verification_key_from_seed(&seed).unwrap();
+    Sum6Kes::raw_serialize_verification_key_kes_into(&vk, &mut vk_bytes).unwrap();
+    let mut sig_bytes = [0u8; Sum6Kes::SIGNATURE_SIZE];
+    Sum6Kes::raw_serialize_signature_kes_into(&sig, &mut sig_bytes).unwrap();
+
+    let vk = Sum6Kes::verification_key_from_bytes(&vk_bytes).unwrap();
+    let sig = Sum6Kes::raw_deserialize_signature_kes(&sig_bytes).unwrap();
+    Sum6Kes::verify_kes(&(), &vk, 0, b"header", &sig).unwrap();
+    assert!(Sum6Kes::verify_kes(&(), &vk, 0, b"other", &sig).is_err());
+    assert!(Sum6Kes::verify_kes(&(), &vk, 1, b"header", &sig).is_err());
+
+    assert_eq!(
+        Sum6Kes::verification_key_from_bytes(&vk_bytes[1..]).err(),
+        Some(KesError::WrongLength {
+            expected: Sum6Kes::VERIFICATION_KEY_SIZE,
+            actual: Sum6Kes::VERIFICATION_KEY_SIZE - 1,
+        })
+    );
+}
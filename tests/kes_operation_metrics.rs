@@ -0,0 +1,51 @@
+//! Key generation, signing and key evolution are each counted once per call,
+//! under the outermost algorithm, however deep the tree recurses
+//!
+//! The counters are process-wide, so this binary holds a single test to keep
+//! other tests from generating or signing concurrently.
+
+#![cfg(all(feature = "metrics", feature = "std"))]
+
+use cardano_crypto::kes::{CompactSum6Kes, KesAlgorithm, Sum6Kes};
+use cardano_crypto::metrics::{self, KesMetrics};
+
+fn counts(metrics: KesMetrics) -> (u64, u64, u64) {
+    (metrics.signing_keys, metrics.signatures, metrics.updates)
+}
+
+macro_rules! exercise {
+    ($kes:ty) => {{
+        let sk = <$kes>::gen_key_kes_from_seed_bytes(&[61u8; 32]).unwrap();
+        <$kes>::sign_kes(&(), 0, b"block", &sk).unwrap();
+        <$kes>::sign_batch_at_period(&(), 0, &[b"a", b"b", b"c"], &sk).unwrap();
+
+        // Crosses the half-way point, generating the right subtree
+        let sk = <$kes>::update_kes_to(&(), sk, 0, 32).unwrap().unwrap();
+        let mut sk = <$kes>::update_kes(&(), sk, 32).unwrap().unwrap();
+        assert!(<$kes>::update_kes_in_place(&(), &mut sk, 33).unwrap());
+        <$kes>::forget_signing_key_kes(sk);
+
+        // Builds and discards a signing key per leaf, none of them counted
+        <$kes>::verification_key_from_seed(&[62u8; 32]).unwrap();
+    }};
+}
+
+#[test]
+fn operations_are_counted_once_under_the_outermost_algorithm() {
+    let before = metrics::snapshot();
+    exercise!(Sum6Kes);
+    exercise!(CompactSum6Kes);
+    let after = metrics::snapshot();
+
+    assert_eq!(counts(metrics::snapshot_for("Sum6Kes")), (1, 4, 34));
+    assert_eq!(counts(metrics::snapshot_for("CompactSum6Kes")), (1, 4, 34));
+    assert_eq!(
+        (
+            after.signing_keys - before.signing_keys,
+            after.signatures - before.signatures,
+            after.updates - before.updates,
+        ),
+        (2, 8, 68)
+    );
+    assert_eq!(counts(metrics::snapshot_for("Ed25519")), (0, 0, 0));
+}
@@ -0,0 +1,45 @@
+//! Sum7 key generation produces the same bytes with and without `parallel`
+//!
+//! The digests were recorded from the sequential build. This file runs in
+//! both configurations, so the `parallel` build must reproduce them exactly,
+//! including a subtree regenerated on the jump into the right half.
+
+use cardano_crypto::kes::{
+    Blake2b256, CompactSum7Kes, KesAlgorithm, KesHashAlgorithm, Sum7Kes, UnsoundKesAlgorithm,
+};
+
+#[macro_use]
+#[path = "../src/kes/typed_tests.rs"]
+mod typed_tests;
+
+const SEED: [u8; 32] = [7u8; 32];
+const VERIFICATION_KEY: &str = "99fcccf99db05af64b25c9952982381bde2ca99a7dd818eed5ee77f5dc865e13";
+const SIGNING_KEY_DIGEST: &str = "62886e6551d9942f15296d6b5590868225b746f918ca309c1a15dd689abca42e";
+const PERIOD_64_DIGEST: &str = "78a5601249c64faa1c2b1cb4a73b782ec1122c31fcc8a0180c69a721d3274590";
+
+fn signing_key_digest<K: UnsoundKesAlgorithm>(sk: &K::SigningKey) -> String {
+    hex::encode(Blake2b256::hash(&K::raw_serialize_signing_key_kes(sk)))
+}
+
+typed_tests! {
+    fn keygen_matches_sequential<K>() for {
+        sum7: Sum7Kes,
+        compact_sum7: CompactSum7Kes,
+    } {
+        let sk = K::gen_key_kes_from_seed_bytes(&SEED).unwrap();
+        let vk = K::derive_verification_key(&sk).unwrap();
+        assert_eq!(
+            hex::encode(K::raw_serialize_verification_key_kes(&vk)),
+            VERIFICATION_KEY
+        );
+        assert_eq!(signing_key_digest::<K>(&sk), SIGNING_KEY_DIGEST);
+        let from_seed = K::verification_key_from_seed(&SEED).unwrap();
+        assert_eq!(
+            K::raw_serialize_verification_key_kes(&from_seed),
+            K::raw_serialize_verification_key_kes(&vk)
+        );
+
+        let sk = K::update_kes_to(&(), sk, 0, 64).unwrap().unwrap();
+        assert_eq!(signing_key_digest::<K>(&sk), PERIOD_64_DIGEST);
+    }
+}
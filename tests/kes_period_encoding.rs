@@ -0,0 +1,61 @@
+//! Byte order of KES periods
+//!
+//! Signatures carry no period counter: the period only picks a subtree at
+//! each level, most significant bit first. Where a period is written out, in
+//! the operational certificate payload, it is a big-endian `u64`. Period
+//! 0x0102 tells the two byte orders apart, and 0x0201 is its byte swap.
+
+use cardano_crypto::kes::opcert::signable_bytes;
+use cardano_crypto::kes::sum::{expected_compact_signature_size, expected_signature_size};
+use cardano_crypto::kes::{
+    Blake2b256, CompactSum7Kes, CompactSumKes, Ed25519LeafKes, GenericSumKes, KesAlgorithm,
+};
+
+#[macro_use]
+#[path = "../src/kes/typed_tests.rs"]
+mod typed_tests;
+
+const PERIOD: u64 = 0x0102;
+const SWAPPED: u64 = 0x0201;
+
+type Sum10Kes = GenericSumKes<10>;
+type CompactSum10Kes =
+    CompactSumKes<CompactSumKes<CompactSumKes<CompactSum7Kes, Blake2b256>, Blake2b256>, Blake2b256>;
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+typed_tests! {
+    fn signatures_carry_no_period_counter<K>(signature_size: usize) for {
+        sum10: Sum10Kes => expected_signature_size(10),
+        compact_sum10: CompactSum10Kes => expected_compact_signature_size(10),
+    } {
+        assert!(SWAPPED < K::total_periods());
+        let sk = K::gen_key_kes_from_seed_bytes(&[0x5au8; 32]).unwrap();
+        let vk = K::derive_verification_key(&sk).unwrap();
+        let sk = K::update_kes_to(&(), sk, 0, PERIOD).unwrap().unwrap();
+        let sig = K::sign_kes(&(), PERIOD, b"endianness", &sk).unwrap();
+
+        // No counter field in either byte order
+        let bytes = K::raw_serialize_signature_kes(&sig);
+        assert_eq!(bytes.len(), signature_size);
+        assert!(!contains(&bytes, &PERIOD.to_be_bytes()));
+        assert!(!contains(&bytes, &PERIOD.to_le_bytes()));
+
+        // The path follows the period's bits, not those of its byte swap
+        let path = K::merkle_path(&vk, PERIOD, &sig).unwrap();
+        assert_eq!(path.period(), PERIOD);
+        K::verify_kes(&(), &vk, PERIOD, b"endianness", &sig).unwrap();
+        assert!(K::verify_kes(&(), &vk, SWAPPED, b"endianness", &sig).is_err());
+        K::forget_signing_key_kes(sk);
+    }
+}
+
+#[test]
+fn opcert_period_is_big_endian() {
+    let payload = signable_bytes(&[0u8; 32], 0, PERIOD);
+    assert_eq!(&payload[40..], &[0, 0, 0, 0, 0, 0, 0x01, 0x02]);
+}
@@ -0,0 +1,69 @@
+//! `prometheus_encode` renders well-formed text exposition output
+
+#![cfg(feature = "kes-metrics")]
+
+use std::collections::HashMap;
+
+use cardano_crypto::kes::{KesAlgorithm, Sum2Kes};
+use cardano_crypto::metrics;
+
+/// Parse the exposition text into sample values keyed by `name{labels}`,
+/// checking that every sample belongs to a family declared by `# TYPE`
+fn parse(text: &str) -> HashMap<String, f64> {
+    let mut families = HashMap::new();
+    let mut samples = HashMap::new();
+    for line in text.lines() {
+        if let Some(rest) = line.strip_prefix("# HELP ") {
+            let (name, help) = rest.split_once(' ').expect("HELP without text");
+            assert!(!help.is_empty(), "{name} has empty HELP");
+        } else if let Some(rest) = line.strip_prefix("# TYPE ") {
+            let (name, kind) = rest.split_once(' ').expect("TYPE without kind");
+            assert!(matches!(kind, "counter" | "histogram"), "{line}");
+            families.insert(name.to_owned(), kind.to_owned());
+        } else {
+            let (series, value) = line.rsplit_once(' ').expect("sample without value");
+            let name = series.split('{').next().unwrap();
+            assert!(
+                name.chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'),
+                "{name}"
+            );
+            let family = ["_bucket", "_count"]
+                .iter()
+                .find_map(|suffix| name.strip_suffix(suffix))
+                .filter(|family| families.get(*family).map(String::as_str) == Some("histogram"))
+                .unwrap_or(name);
+            assert!(families.contains_key(family), "undeclared {name}");
+            samples.insert(series.to_owned(), value.parse().expect("non-numeric value"));
+        }
+    }
+    samples
+}
+
+#[test]
+fn prometheus_output_parses_and_names_every_metric() {
+    let sk = Sum2Kes::gen_key_kes_from_seed_bytes(&[51u8; 32]).unwrap();
+    let vk = Sum2Kes::derive_verification_key(&sk).unwrap();
+    let sig = Sum2Kes::sign_kes(&(), 0, b"scrape", &sk).unwrap();
+    Sum2Kes::verify_kes(&(), &vk, 0, b"scrape", &sig).unwrap();
+
+    let samples = parse(&metrics::prometheus_encode());
+    for name in [
+        "cardano_kes_signing_keys_total",
+        "cardano_kes_signatures_total",
+        "cardano_kes_updates_total",
+        "cardano_kes_verifications_total",
+        "cardano_kes_verification_failures_total",
+    ] {
+        assert!(samples.contains_key(name), "missing {name}");
+    }
+    assert!(samples["cardano_kes_verifications_total"] >= 1.0);
+    for family in [
+        "cardano_kes_sign_duration_seconds",
+        "cardano_kes_verify_duration_seconds",
+    ] {
+        let count = samples[&format!("{family}_count")];
+        assert!(count >= 1.0);
+        assert_eq!(samples[&format!("{family}_bucket{{le=\"+Inf\"}}")], count);
+    }
+}
@@ -0,0 +1,164 @@
+//! Randomized evolution invariants for the Sum and CompactSum schemes
+//!
+//! The golden tests pin specific seeds and periods; these properties draw
+//! seeds, periods and evolution paths at random for every depth from 0 to 4:
+//!
+//! - a signature verifies at the period it was made for and at no other
+//! - evolution only moves forward, one period per `update_kes`
+//! - a key cannot produce a valid signature for a period it has left behind
+
+use cardano_crypto::kes::{
+    CompactSum0Kes, CompactSum1Kes, CompactSum2Kes, CompactSum3Kes, CompactSum4Kes, KesAlgorithm,
+    Period, Sum0Kes, Sum1Kes, Sum2Kes, Sum3Kes, Sum4Kes,
+};
+use proptest::prelude::*;
+
+/// Fresh key from `seed`, evolved one `update_kes` at a time to `period`
+fn evolve_to<K: KesAlgorithm<Context = ()>>(
+    seed: &[u8; 32],
+    period: Period,
+) -> Result<K::SigningKey, TestCaseError> {
+    let mut sk = K::gen_key_kes_from_seed_bytes(seed).unwrap();
+    prop_assert_eq!(K::current_period_kes(&sk), 0);
+
+    for from in 0..period {
+        sk = K::update_kes(&(), sk, from).unwrap().unwrap();
+        prop_assert_eq!(K::current_period_kes(&sk), from + 1);
+    }
+    Ok(sk)
+}
+
+fn signs_only_at_its_period<K: KesAlgorithm<Context = ()>>(
+    seed: [u8; 32],
+    period: Period,
+    message: &[u8],
+) -> Result<(), TestCaseError> {
+    let period = period % K::total_periods();
+    let vk = K::derive_verification_key(&K::gen_key_kes_from_seed_bytes(&seed).unwrap()).unwrap();
+    let sk = evolve_to::<K>(&seed, period)?;
+    let sig = K::sign_kes(&(), period, message, &sk).unwrap();
+
+    prop_assert!(K::verify_kes(&(), &vk, period, message, &sig).is_ok());
+    for other in (0..K::total_periods()).filter(|&other| other != period) {
+        prop_assert!(
+            K::verify_kes(&(), &vk, other, message, &sig).is_err(),
+            "signature from period {} verified at {}",
+            period,
+            other
+        );
+    }
+    Ok(())
+}
+
+fn evolution_is_monotonic<K: KesAlgorithm<Context = ()>>(
+    seed: [u8; 32],
+    jumps: &[Period],
+) -> Result<(), TestCaseError> {
+    let total = K::total_periods();
+    let mut sk = K::gen_key_kes_from_seed_bytes(&seed).unwrap();
+    let mut current = 0;
+
+    for &jump in jumps {
+        prop_assert_eq!(K::remaining_periods_kes(&sk), total - current);
+        if current > 0 {
+            prop_assert!(K::update_kes_to(&(), sk, current, current - 1).is_err());
+            // The failed call consumed the key; rebuild it at the same period
+            sk = evolve_to::<K>(&seed, current)?;
+        }
+
+        let target = current + jump % (total - current + 1);
+        match K::update_kes_to(&(), sk, current, target).unwrap() {
+            Some(next) => {
+                prop_assert!(target < total);
+                prop_assert_eq!(K::current_period_kes(&next), target);
+                sk = next;
+                current = target;
+            }
+            None => {
+                prop_assert_eq!(target, total);
+                return Ok(());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn cannot_sign_below_current_period<K: KesAlgorithm<Context = ()>>(
+    seed: [u8; 32],
+    current: Period,
+    past: Period,
+    message: &[u8],
+) -> Result<(), TestCaseError> {
+    let total = K::total_periods();
+    if total < 2 {
+        return Ok(());
+    }
+    let current = 1 + current % (total - 1);
+    let past = past % current;
+
+    let vk = K::derive_verification_key(&K::gen_key_kes_from_seed_bytes(&seed).unwrap()).unwrap();
+    let sk = evolve_to::<K>(&seed, current)?;
+
+    // Signing may fail outright; if it succeeds the signature must not verify
+    if let Ok(sig) = K::sign_kes(&(), past, message, &sk) {
+        prop_assert!(
+            K::verify_kes(&(), &vk, past, message, &sig).is_err(),
+            "key at period {} signed for period {}",
+            current,
+            past
+        );
+    }
+    Ok(())
+}
+
+macro_rules! kes_properties {
+    ($($module:ident => $kes:ty),* $(,)?) => {$(
+        mod $module {
+            use super::*;
+
+            proptest! {
+                #![proptest_config(ProptestConfig::with_cases(24))]
+
+                #[test]
+                fn signs_only_at_its_period(
+                    seed in any::<[u8; 32]>(),
+                    period in any::<Period>(),
+                    message in proptest::collection::vec(any::<u8>(), 0..64),
+                ) {
+                    super::signs_only_at_its_period::<$kes>(seed, period, &message)?;
+                }
+
+                #[test]
+                fn evolution_is_monotonic(
+                    seed in any::<[u8; 32]>(),
+                    jumps in proptest::collection::vec(any::<Period>(), 1..8),
+                ) {
+                    super::evolution_is_monotonic::<$kes>(seed, &jumps)?;
+                }
+
+                #[test]
+                fn cannot_sign_below_current_period(
+                    seed in any::<[u8; 32]>(),
+                    current in any::<Period>(),
+                    past in any::<Period>(),
+                    message in proptest::collection::vec(any::<u8>(), 0..64),
+                ) {
+                    super::cannot_sign_below_current_period::<$kes>(seed, current, past, &message)?;
+                }
+            }
+        }
+    )*};
+}
+
+kes_properties! {
+    sum0 => Sum0Kes,
+    sum1 => Sum1Kes,
+    sum2 => Sum2Kes,
+    sum3 => Sum3Kes,
+    sum4 => Sum4Kes,
+    compact_sum0 => CompactSum0Kes,
+    compact_sum1 => CompactSum1Kes,
+    compact_sum2 => CompactSum2Kes,
+    compact_sum3 => CompactSum3Kes,
+    compact_sum4 => CompactSum4Kes,
+}
@@ -0,0 +1,29 @@
+//! Golden test for cardano-cli KES verification key envelopes
+//!
+//! `test_vectors/kes_sum6.vkey` holds the Sum6 key for seed `[0x5a; 32]` in
+//! the byte layout `cardano-cli node key-gen-KES` writes: four-space indent,
+//! `type`/`description`/`cborHex` in that order, and a trailing newline.
+//!
+//! The file is written by `test_vectors/reference_envelopes.py`, which
+//! derives the key with the separate model in `test_vectors/kes/` rather than
+//! with this crate. It is not the output of a real cardano-cli run: a key
+//! generated by cardano-cli comes from a random seed, so such a file can only
+//! be checked by reading it and writing it back, as the second half of the
+//! test does.
+
+#![cfg(feature = "cbor")]
+
+use cardano_crypto::kes::envelope::{from_text_envelope, to_text_envelope};
+use cardano_crypto::kes::{KesAlgorithm, KesScheme, Sum6Kes};
+
+const SUM6_VKEY: &str = include_str!("test_vectors/kes_sum6.vkey");
+
+#[test]
+fn sum6_vkey_matches_golden_file() {
+    let vk = Sum6Kes::verification_key_from_seed(&[0x5au8; 32]).unwrap();
+    assert_eq!(to_text_envelope(KesScheme::Sum6, &vk).unwrap(), SUM6_VKEY);
+
+    let (scheme, parsed) = from_text_envelope(SUM6_VKEY).unwrap();
+    assert_eq!((scheme, &parsed), (KesScheme::Sum6, &vk));
+    assert_eq!(to_text_envelope(scheme, &parsed).unwrap(), SUM6_VKEY);
+}
@@ -0,0 +1,108 @@
+//! KES compatibility vectors loaded from JSON
+//!
+//! Each file under `test_vectors/kes/` fixes, for one scheme, a seed and a
+//! message, the verification key derived from the seed, and the signature at
+//! every period of a key evolved from it:
+//!
+//! ```text
+//! {
+//!   "scheme": "Sum6",                // SumN or CompactSumN
+//!   "algorithm": "Sum6Kes",          // KesAlgorithm::ALGORITHM_NAME
+//!   "source": string,                // what produced the file
+//!   "seed": hex,
+//!   "message": hex,
+//!   "verification_key": hex,
+//!   "vectors": [{ "period": integer, "signature": hex }]
+//! }
+//! ```
+//!
+//! This is the output of `examples/generate_vectors.rs` without the signing
+//! keys, so vectors produced by cardano-base for the same inputs can be
+//! converted and dropped in alongside. The files shipped here do not come
+//! from this crate: `test_vectors/kes/reference_sum_kes.py` writes them from
+//! a separate model of cardano-base's `SumKES` and `CompactSumKES` on top of
+//! Python's hashlib and pyca/cryptography, which signs each period from the
+//! root seed rather than by evolving a key. They are not cardano-base output.
+//! The checks are exact: the key and every signature must be reproduced byte
+//! for byte, and every listed signature must verify.
+
+use cardano_crypto::kes::{CompactSum6Kes, KesAlgorithm, Sum6Kes};
+use serde_json::Value;
+
+#[macro_use]
+#[path = "../src/kes/typed_tests.rs"]
+mod typed_tests;
+
+const SUM6: &str = include_str!("test_vectors/kes/sum6.json");
+const COMPACT_SUM6: &str = include_str!("test_vectors/kes/compact_sum6.json");
+
+fn hex_field(value: &Value, field: &str) -> Vec<u8> {
+    let text = value[field]
+        .as_str()
+        .unwrap_or_else(|| panic!("missing {field}"));
+    hex::decode(text).unwrap_or_else(|err| panic!("{field}: {err}"))
+}
+
+/// A vector file after its `algorithm` and `source` fields are checked
+struct Vectors {
+    seed: Vec<u8>,
+    message: Vec<u8>,
+    verification_key: Vec<u8>,
+    signatures: Vec<(u64, Vec<u8>)>,
+}
+
+fn load(json: &str, algorithm: &str) -> Vectors {
+    let file: Value = serde_json::from_str(json).unwrap();
+    assert_eq!(file["algorithm"], algorithm);
+    assert!(file["source"].is_string(), "vector files name their source");
+    let signatures: Vec<_> = file["vectors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|vector| {
+            let period = vector["period"].as_u64().unwrap();
+            (period, hex_field(vector, "signature"))
+        })
+        .collect();
+    assert!(!signatures.is_empty());
+    Vectors {
+        seed: hex_field(&file, "seed"),
+        message: hex_field(&file, "message"),
+        verification_key: hex_field(&file, "verification_key"),
+        signatures,
+    }
+}
+
+typed_tests! {
+    fn reproduces_vectors<K>(json: &str) for {
+        sum6: Sum6Kes => SUM6,
+        compact_sum6: CompactSum6Kes => COMPACT_SUM6,
+    } {
+        let file = load(json, K::ALGORITHM_NAME);
+        let mut sk = K::gen_key_kes_from_seed_bytes(&file.seed).unwrap();
+        let vk = K::derive_verification_key(&sk).unwrap();
+        assert_eq!(
+            hex::encode(K::raw_serialize_verification_key_kes(&vk)),
+            hex::encode(&file.verification_key),
+            "verification key"
+        );
+
+        let mut current = 0;
+        for &(period, ref expected) in &file.signatures {
+            sk = K::update_kes_to(&(), sk, current, period)
+                .unwrap()
+                .expect("period within the key's lifetime");
+            current = period;
+
+            let sig = K::sign_kes(&(), period, &file.message, &sk).unwrap();
+            assert_eq!(
+                hex::encode(K::raw_serialize_signature_kes(&sig)),
+                hex::encode(expected),
+                "signature at period {period}"
+            );
+            let decoded = K::raw_deserialize_signature_kes(expected).unwrap();
+            K::verify_kes(&(), &vk, period, &file.message, &decoded).unwrap();
+        }
+        K::forget_signing_key_kes(sk);
+    }
+}
@@ -0,0 +1,76 @@
+//! Every `verify_kes` call is counted exactly once in the global metrics,
+//! under the algorithm it was made on
+//!
+//! The counters are process-wide, so this binary holds a single test to keep
+//! other tests from verifying concurrently.
+
+#![cfg(feature = "metrics")]
+
+use cardano_crypto::common::CryptoError;
+use cardano_crypto::kes::{CompactSum3Kes, KesAlgorithm, KesError, Sum3Kes};
+use cardano_crypto::metrics;
+
+fn delta(before: metrics::KesMetrics) -> (u64, u64) {
+    let after = metrics::snapshot();
+    (
+        after.verifications - before.verifications,
+        after.verification_failures - before.verification_failures,
+    )
+}
+
+#[test]
+fn verify_kes_records_each_call_once() {
+    let sk = Sum3Kes::gen_key_kes_from_seed_bytes(&[31u8; 32]).unwrap();
+    let vk = Sum3Kes::derive_verification_key(&sk).unwrap();
+    let sig = Sum3Kes::sign_kes(&(), 0, b"block", &sk).unwrap();
+
+    let before = metrics::snapshot();
+    Sum3Kes::verify_kes(&(), &vk, 0, b"block", &sig).unwrap();
+    assert_eq!(delta(before), (1, 0));
+
+    // Fails at the leaf
+    let before = metrics::snapshot();
+    assert_eq!(
+        Sum3Kes::verify_kes(&(), &vk, 0, b"forged", &sig),
+        Err(CryptoError::KesError(KesError::BadEd25519Signature))
+    );
+    assert_eq!(delta(before), (1, 1));
+
+    // Fails at the root hash
+    let other_vk = Sum3Kes::verification_key_from_seed(&[32u8; 32]).unwrap();
+    let before = metrics::snapshot();
+    assert_eq!(
+        Sum3Kes::verify_kes(&(), &other_vk, 0, b"block", &sig),
+        Err(CryptoError::KesError(KesError::BadMerklePath))
+    );
+    assert_eq!(delta(before), (1, 1));
+
+    // The period-mismatch diagnosis is not counted as a second check
+    let sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&[33u8; 32]).unwrap();
+    let vk = CompactSum3Kes::derive_verification_key(&sk).unwrap();
+    let sig = CompactSum3Kes::sign_kes(&(), 0, b"block", &sk).unwrap();
+    let before = metrics::snapshot();
+    let err = CompactSum3Kes::verify_kes(&(), &vk, 5, b"block", &sig).unwrap_err();
+    assert_eq!(err, CryptoError::KesError(KesError::BadMerklePath));
+    assert_eq!(
+        CompactSum3Kes::diagnose_verify_failure(&vk, 5, b"block", &sig, err),
+        CryptoError::KesError(KesError::PeriodMismatch {
+            requested: 5,
+            signed: 0
+        })
+    );
+    assert_eq!(delta(before), (1, 1));
+
+    // Each call is attributed to the outermost algorithm, not the leaf
+    let sum = metrics::snapshot_for("Sum3Kes");
+    assert_eq!((sum.verifications, sum.verification_failures), (3, 2));
+    let compact = metrics::snapshot_for("CompactSum3Kes");
+    assert_eq!(
+        (compact.verifications, compact.verification_failures),
+        (1, 1)
+    );
+    assert_eq!(
+        metrics::snapshot_for("Ed25519"),
+        metrics::KesMetrics::default()
+    );
+}
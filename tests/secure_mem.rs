@@ -0,0 +1,42 @@
+//! KES keys behave the same with their secrets in locked pages
+
+#![cfg(all(feature = "secure-mem", feature = "kes"))]
+
+use cardano_crypto::kes::{
+    CompactSum3Kes, KesAlgorithm, KesSigningSession, Sum6Kes, UnsoundKesAlgorithm,
+};
+
+#[test]
+fn sum6_signs_and_evolves_with_locked_secrets() {
+    let sk = Sum6Kes::gen_key_kes_from_seed_bytes(&[11u8; 32]).unwrap();
+    let vk = Sum6Kes::derive_verification_key(&sk).unwrap();
+
+    let mut sk = Some(sk);
+    for period in 0..Sum6Kes::total_periods() {
+        let key = sk.take().unwrap();
+        let sig = Sum6Kes::sign_kes(&(), period, b"block", &key).unwrap();
+        Sum6Kes::verify_kes(&(), &vk, period, b"block", &sig).unwrap();
+        sk = Sum6Kes::update_kes(&(), key, period).unwrap();
+    }
+    assert!(sk.is_none());
+}
+
+#[test]
+fn raw_round_trip_and_session_with_locked_secrets() {
+    let sk = CompactSum3Kes::gen_key_kes_from_seed_bytes(&[12u8; 32]).unwrap();
+    let sk = CompactSum3Kes::update_kes_to(&(), sk, 0, 2)
+        .unwrap()
+        .unwrap();
+    let vk = CompactSum3Kes::derive_verification_key(&sk).unwrap();
+
+    let bytes = CompactSum3Kes::raw_serialize_signing_key_kes(&sk);
+    let restored = CompactSum3Kes::raw_deserialize_signing_key_kes(&bytes).unwrap();
+    assert_eq!(
+        *CompactSum3Kes::raw_serialize_signing_key_kes(&restored),
+        *bytes
+    );
+
+    let session = KesSigningSession::<CompactSum3Kes>::new(&(), restored).unwrap();
+    let sig = session.sign(b"tx");
+    CompactSum3Kes::verify_kes(&(), &vk, 2, b"tx", &sig).unwrap();
+}
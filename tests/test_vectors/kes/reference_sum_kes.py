@@ -0,0 +1,98 @@
+#!/usr/bin/env python3
+"""Reference Sum and CompactSum KES over Ed25519 and Blake2b-256
+
+A from-the-spec model of `Cardano.Crypto.KES.Sum` and
+`Cardano.Crypto.KES.CompactSum` in cardano-base, written against Python's
+hashlib and pyca/cryptography only, so it shares no code with the crate:
+
+- a seed is split into the subtree seeds Blake2b-256(0x01 || seed) and
+  Blake2b-256(0x02 || seed), as `expandHashWith`
+- a verification key is Blake2b-256(vk_left || vk_right), with Ed25519 public
+  keys at the leaves
+- the period selects the subtree most significant bit first
+- a Sum signature is sigma || vk_left || vk_right; a CompactSum signature is
+  sigma || vk_other, with the leaf's own key after its Ed25519 signature
+
+The signer derives each period's leaf straight from the root seed instead of
+evolving a key, so the crate's update path is checked against it too.
+
+Usage: reference_sum_kes.py > sum6.json (or --compact > compact_sum6.json)
+"""
+
+import hashlib
+import json
+import sys
+
+from cryptography.hazmat.primitives import serialization
+from cryptography.hazmat.primitives.asymmetric.ed25519 import Ed25519PrivateKey
+
+DEPTH = 6
+SEED = bytes(range(32))
+MESSAGE = b"Cardano KES test vector"
+
+
+def blake2b256(data):
+    return hashlib.blake2b(data, digest_size=32).digest()
+
+
+def expand(seed):
+    return blake2b256(b"\x01" + seed), blake2b256(b"\x02" + seed)
+
+
+def leaf_vk(seed):
+    public = Ed25519PrivateKey.from_private_bytes(seed).public_key()
+    return public.public_bytes(serialization.Encoding.Raw, serialization.PublicFormat.Raw)
+
+
+def vk(depth, seed):
+    if depth == 0:
+        return leaf_vk(seed)
+    left, right = expand(seed)
+    return blake2b256(vk(depth - 1, left) + vk(depth - 1, right))
+
+
+def sign_sum(depth, seed, period, message):
+    if depth == 0:
+        return Ed25519PrivateKey.from_private_bytes(seed).sign(message)
+    left, right = expand(seed)
+    half = 1 << (depth - 1)
+    active, offset = (left, period) if period < half else (right, period - half)
+    sigma = sign_sum(depth - 1, active, offset, message)
+    return sigma + vk(depth - 1, left) + vk(depth - 1, right)
+
+
+def sign_compact(depth, seed, period, message):
+    if depth == 0:
+        return Ed25519PrivateKey.from_private_bytes(seed).sign(message) + leaf_vk(seed)
+    left, right = expand(seed)
+    half = 1 << (depth - 1)
+    if period < half:
+        return sign_compact(depth - 1, left, period, message) + vk(depth - 1, right)
+    return sign_compact(depth - 1, right, period - half, message) + vk(depth - 1, left)
+
+
+def main():
+    compact = "--compact" in sys.argv[1:]
+    sign = sign_compact if compact else sign_sum
+    scheme = f"{'CompactSum' if compact else 'Sum'}{DEPTH}"
+    document = {
+        "scheme": scheme,
+        "algorithm": f"{scheme}Kes",
+        "source": "tests/test_vectors/kes/reference_sum_kes.py"
+        + (" --compact" if compact else "")
+        + ": independent model of cardano-base SumKES/CompactSumKES"
+        " (hashlib Blake2b-256, pyca/cryptography Ed25519)",
+        "seed": SEED.hex(),
+        "message": MESSAGE.hex(),
+        "verification_key": vk(DEPTH, SEED).hex(),
+        "vectors": [
+            {"period": period, "signature": sign(DEPTH, SEED, period, MESSAGE).hex()}
+            for period in range(1 << DEPTH)
+        ],
+    }
+    json.dump(document, sys.stdout, indent=2)
+    sys.stdout.write("\n")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,94 @@
+#!/usr/bin/env python3
+"""Reference cardano-cli text envelopes for a Sum6 KES key
+
+Writes, next to this script, the files `cardano-cli node key-gen-KES` and
+`cardano-cli node issue-op-cert` would for fixed keys, computed without the
+crate: the KES verification key comes from `kes/reference_sum_kes.py` and
+the cold signature from pyca/cryptography.
+
+- kes_sum6.vkey: the Sum6 verification key for seed [0x5a] * 32
+- node.cert: an operational certificate for that key, counter 3 and KES
+  period 412, signed by the Ed25519 cold key with seed [0x0c] * 32
+
+The certificate's cborHex is cardano-api's encoding,
+[[kes_vk, counter, period, cold_signature], cold_vk], and the cold key signs
+OCertSignable: kes_vk || counter || period, the integers as big-endian u64.
+Envelopes use cardano-cli's layout: four-space indent, type, description
+and cborHex in that order, and a trailing newline.
+
+Usage: reference_envelopes.py
+"""
+
+import os
+import sys
+
+from cryptography.hazmat.primitives import serialization
+from cryptography.hazmat.primitives.asymmetric.ed25519 import Ed25519PrivateKey
+
+HERE = os.path.dirname(os.path.abspath(__file__))
+sys.path.insert(0, os.path.join(HERE, "kes"))
+
+from reference_sum_kes import vk  # noqa: E402
+
+KES_SEED = bytes([0x5A] * 32)
+COLD_SEED = bytes([0x0C] * 32)
+COUNTER = 3
+KES_PERIOD = 412
+
+
+def cbor_uint(value):
+    if value < 24:
+        return bytes([value])
+    if value < 1 << 8:
+        return bytes([0x18, value])
+    if value < 1 << 16:
+        return bytes([0x19]) + value.to_bytes(2, "big")
+    if value < 1 << 32:
+        return bytes([0x1A]) + value.to_bytes(4, "big")
+    return bytes([0x1B]) + value.to_bytes(8, "big")
+
+
+def cbor_bytes(data):
+    header = cbor_uint(len(data))
+    return bytes([0x40 | header[0]]) + header[1:] + data
+
+
+def envelope(kind, description, cbor):
+    return (
+        "{\n"
+        f'    "type": "{kind}",\n'
+        f'    "description": "{description}",\n'
+        f'    "cborHex": "{cbor.hex()}"\n'
+        "}\n"
+    )
+
+
+def write(name, text):
+    with open(os.path.join(HERE, name), "w", encoding="ascii") as file:
+        file.write(text)
+
+
+def main():
+    kes_vk = vk(6, KES_SEED)
+    write(
+        "kes_sum6.vkey",
+        envelope("KesVerificationKey_ed25519_kes_2^6", "KES Verification Key", cbor_bytes(kes_vk)),
+    )
+
+    cold = Ed25519PrivateKey.from_private_bytes(COLD_SEED)
+    cold_vk = cold.public_key().public_bytes(
+        serialization.Encoding.Raw, serialization.PublicFormat.Raw
+    )
+    signable = kes_vk + COUNTER.to_bytes(8, "big") + KES_PERIOD.to_bytes(8, "big")
+    ocert = (
+        bytes([0x84])
+        + cbor_bytes(kes_vk)
+        + cbor_uint(COUNTER)
+        + cbor_uint(KES_PERIOD)
+        + cbor_bytes(cold.sign(signable))
+    )
+    write("node.cert", envelope("NodeOperationalCertificate", "", bytes([0x82]) + ocert + cbor_bytes(cold_vk)))
+
+
+if __name__ == "__main__":
+    main()
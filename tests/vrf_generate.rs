@@ -0,0 +1,67 @@
+//! VRF keypairs drawn from an RNG
+
+#![cfg(all(feature = "vrf", feature = "rand"))]
+
+use cardano_crypto::vrf::{VrfDraft03, VrfDraft13};
+use rand_core::{CryptoRng, RngCore};
+
+/// Deterministic xorshift generator, standing in for a real CSPRNG
+struct XorShiftRng(u64);
+
+impl RngCore for XorShiftRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for XorShiftRng {}
+
+#[test]
+fn independent_generations_give_distinct_keys() {
+    let mut rng = XorShiftRng(0x2545_f491_4f6c_dd1d);
+    let (sk_a, pk_a) = VrfDraft03::generate(&mut rng);
+    let (sk_b, pk_b) = VrfDraft03::generate(&mut rng);
+    assert_ne!(sk_a, sk_b);
+    assert_ne!(pk_a, pk_b);
+
+    let (sk_a, pk_a) = VrfDraft13::generate(&mut rng);
+    let (sk_b, pk_b) = VrfDraft13::generate(&mut rng);
+    assert_ne!(sk_a, sk_b);
+    assert_ne!(pk_a, pk_b);
+}
+
+#[test]
+fn generate_matches_keypair_from_drawn_seed() {
+    let mut seed = [0u8; 32];
+    XorShiftRng(99).fill_bytes(&mut seed);
+
+    assert_eq!(
+        VrfDraft03::generate(&mut XorShiftRng(99)),
+        VrfDraft03::keypair_from_seed(&seed)
+    );
+    assert_eq!(
+        VrfDraft13::generate(&mut XorShiftRng(99)),
+        VrfDraft13::keypair_from_seed(&seed)
+    );
+
+    // The generated key proves and verifies like any other
+    let (sk, pk) = VrfDraft03::generate(&mut XorShiftRng(5));
+    let proof = VrfDraft03::prove(&sk, b"slot").unwrap();
+    VrfDraft03::verify(&pk, &proof, b"slot").unwrap();
+}
@@ -1,7 +1,7 @@
 //! Golden test vectors for VRF implementations (Cardano-compatible)
 
-use cardano_crypto::common::Result;
-use cardano_crypto::vrf::{VrfDraft03, VrfDraft13};
+use cardano_crypto::common::{CryptoError, Result};
+use cardano_crypto::vrf::{VrfDraft03, VrfDraft13, VrfError};
 
 #[test]
 fn test_vrf_draft03_basic() -> Result<()> {
@@ -65,7 +65,10 @@ fn test_vrf_draft03_wrong_key() -> Result<()> {
     let (_, vk2) = VrfDraft03::keypair_from_seed(&[0x06u8; 32]);
 
     let proof = VrfDraft03::prove(&sk1, b"test")?;
-    assert!(VrfDraft03::verify(&vk2, &proof, b"test").is_err());
+    assert_eq!(
+        VrfDraft03::verify(&vk2, &proof, b"test"),
+        Err(CryptoError::VrfError(VrfError::VerificationFailed))
+    );
 
     Ok(())
 }
@@ -76,7 +79,10 @@ fn test_vrf_draft13_wrong_key() -> Result<()> {
     let (_, vk2) = VrfDraft13::keypair_from_seed(&[0x08u8; 32]);
 
     let proof = VrfDraft13::prove(&sk1, b"test")?;
-    assert!(VrfDraft13::verify(&vk2, &proof, b"test").is_err());
+    assert_eq!(
+        VrfDraft13::verify(&vk2, &proof, b"test"),
+        Err(CryptoError::VrfError(VrfError::VerificationFailed))
+    );
 
     Ok(())
 }
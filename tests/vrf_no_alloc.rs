@@ -0,0 +1,74 @@
+//! VRF Draft-03 proving and verification make no heap allocations
+//!
+//! A counting global allocator records every allocation made by the thread
+//! under test. The harness's main thread can allocate at any moment, so
+//! allocations on other threads are not counted.
+
+#![cfg(feature = "vrf")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cardano_crypto::vrf::draft03::{VrfDraft03, OUTPUT_SIZE, PROOF_SIZE};
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if COUNTING.with(Cell::get) {
+            ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        }
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAlloc = CountingAlloc;
+
+fn allocations_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    COUNTING.with(|counting| counting.set(true));
+    f();
+    COUNTING.with(|counting| counting.set(false));
+    ALLOCATIONS.load(Ordering::SeqCst) - before
+}
+
+#[test]
+fn prove_into_and_verify_into_do_not_allocate() {
+    let (secret_key, public_key) = VrfDraft03::keypair_from_seed(&[61u8; 32]);
+    let mut proof = [0u8; PROOF_SIZE];
+    let mut output = [0u8; OUTPUT_SIZE];
+
+    assert_eq!(
+        allocations_during(|| {
+            for slot in 0u64..8 {
+                let message = slot.to_be_bytes();
+                VrfDraft03::prove_into(&secret_key, &message, &mut proof).unwrap();
+                VrfDraft03::verify_into(&public_key, &proof, &message, &mut output).unwrap();
+            }
+        }),
+        0
+    );
+    assert_eq!(output, VrfDraft03::proof_to_hash(&proof).unwrap());
+
+    // A failed check allocates nothing either, and leaves the output alone
+    let before = output;
+    assert_eq!(
+        allocations_during(|| {
+            assert!(VrfDraft03::verify_into(&public_key, &proof, b"other", &mut output).is_err());
+        }),
+        0
+    );
+    assert_eq!(output, before);
+}